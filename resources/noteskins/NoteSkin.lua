@@ -0,0 +1,54 @@
+-- every button loads the "Down" sprites, the arrows are rotated into place here
+local ret = ... or {}
+
+ret.RedirTable = {
+	Up = "Down",
+	Down = "Down",
+	Left = "Down",
+	Right = "Down",
+}
+
+local OldRedir = ret.Redir
+ret.Redir = function(sButton, sElement)
+	sButton, sElement = OldRedir(sButton, sElement)
+	sButton = ret.RedirTable[sButton] or sButton
+	return sButton, sElement
+end
+
+ret.PartsToRotate = {
+	["Receptor"] = true,
+	["Tap Note"] = true,
+	["Tap Explosion Bright"] = true,
+	["Tap Explosion Dim"] = true,
+	["Hold Explosion"] = true,
+	["Hold Head Active"] = true,
+	["Hold Head Inactive"] = true,
+}
+
+ret.Rotate = {
+	Up = 180,
+	Down = 0,
+	Left = 90,
+	Right = -90,
+}
+
+local OldFunc = ret.Load
+function ret.Load()
+	local t = OldFunc()
+	local sElement = Var "Element"
+
+	if ret.PartsToRotate[sElement] then
+		t.BaseRotationZ = ret.Rotate[Var "Button"]
+	end
+
+	return t
+end
+
+ret.Blank = {
+	["Hold Topcap Active"] = true,
+	["Hold Topcap Inactive"] = true,
+	["Roll Topcap Active"] = true,
+	["Roll Topcap Inactive"] = true,
+}
+
+return ret
@@ -0,0 +1,57 @@
+use std::io::Cursor;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::error::SampleProcessError;
+use crate::sample::SampleFormat;
+
+/// what a sample's headers say, without decoding the audio
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SampleInfo {
+    pub format: SampleFormat,
+    pub channels: u16,
+    pub sample_rate: u32,
+    /// in seconds
+    pub duration: f32,
+}
+
+/// wav and ogg only, ogg's length comes from the last page's granule position
+pub fn read_sample_info(bytes: &[u8]) -> Result<SampleInfo, SampleProcessError> {
+    let decode_error = |e: &dyn std::error::Error| SampleProcessError::Decode { reason: e.to_string() };
+
+    match SampleFormat::detect(bytes) {
+        Some(SampleFormat::Wav) => {
+            let reader = hound::WavReader::new(Cursor::new(bytes)).map_err(|e| decode_error(&e))?;
+            let spec = reader.spec();
+            Ok(SampleInfo {
+                format: SampleFormat::Wav,
+                channels: spec.channels,
+                sample_rate: spec.sample_rate,
+                duration: reader.duration() as f32 / spec.sample_rate.max(1) as f32,
+            })
+        }
+        Some(SampleFormat::Ogg) => {
+            let reader = lewton::inside_ogg::OggStreamReader::new(Cursor::new(bytes)).map_err(|e| decode_error(&e))?;
+            let channels = reader.ident_hdr.audio_channels as u16;
+            let sample_rate = reader.ident_hdr.audio_sample_rate;
+
+            let mut packets = ogg::PacketReader::new(Cursor::new(bytes));
+            let mut last_granule = 0;
+            while let Some(packet) = packets.read_packet().map_err(|e| decode_error(&e))? {
+                // pages where no packet ends have all bits set
+                if packet.absgp_page() != u64::MAX {
+                    last_granule = last_granule.max(packet.absgp_page());
+                }
+            }
+
+            Ok(SampleInfo {
+                format: SampleFormat::Ogg,
+                channels,
+                sample_rate,
+                duration: last_granule as f32 / sample_rate.max(1) as f32,
+            })
+        }
+        format => Err(SampleProcessError::Unsupported { format }),
+    }
+}
@@ -0,0 +1,7 @@
+mod info;
+mod options;
+pub mod proc;
+
+pub use info::{SampleInfo, read_sample_info};
+pub use options::SampleProcessing;
+pub use proc::process_sample;
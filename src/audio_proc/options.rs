@@ -0,0 +1,42 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// what `process_sample` does to a sample, by default nothing
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SampleProcessing {
+    /// cuts everything before the first frame louder than `silence_threshold`
+    pub trim_silence: bool,
+    /// linear amplitude from 0 to 1, 0.001 is about -60dB
+    pub silence_threshold: f32,
+    /// scales the sample so its loudest point reaches `peak`
+    pub normalize: bool,
+    pub peak: f32,
+    /// averages every channel into one
+    pub downmix: bool,
+}
+
+impl Default for SampleProcessing {
+    fn default() -> Self {
+        Self {
+            trim_silence: false,
+            silence_threshold: 0.001,
+            normalize: false,
+            peak: 1.0,
+            downmix: false,
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl SampleProcessing {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = isNoop))]
+    pub fn is_noop(&self) -> bool {
+        !self.trim_silence && !self.normalize && !self.downmix
+    }
+}
@@ -0,0 +1,123 @@
+use std::io::Cursor;
+
+use crate::audio_proc::SampleProcessing;
+use crate::error::SampleProcessError;
+use crate::sample::SampleFormat;
+
+/// interleaved samples from -1 to 1
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pcm {
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub samples: Vec<f32>,
+}
+
+impl Pcm {
+    pub fn frames(&self) -> usize {
+        self.samples.len() / self.channels.max(1) as usize
+    }
+}
+
+/// decodes wav or ogg, applies `options` and encodes the result as 16 bit wav
+pub fn process_sample(bytes: &[u8], options: &SampleProcessing) -> Result<Vec<u8>, SampleProcessError> {
+    let mut pcm = decode(bytes)?;
+
+    if options.downmix {
+        downmix(&mut pcm);
+    }
+    if options.trim_silence {
+        trim_leading_silence(&mut pcm, options.silence_threshold);
+    }
+    if options.normalize {
+        normalize_peak(&mut pcm, options.peak);
+    }
+
+    encode_wav(&pcm)
+}
+
+pub fn decode(bytes: &[u8]) -> Result<Pcm, SampleProcessError> {
+    let decode_error = |e: &dyn std::error::Error| SampleProcessError::Decode { reason: e.to_string() };
+
+    match SampleFormat::detect(bytes) {
+        Some(SampleFormat::Wav) => {
+            let mut reader = hound::WavReader::new(Cursor::new(bytes)).map_err(|e| decode_error(&e))?;
+            let spec = reader.spec();
+            let samples = match spec.sample_format {
+                hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<Vec<_>, _>>(),
+                hound::SampleFormat::Int => {
+                    let scale = (1_i64 << (spec.bits_per_sample.max(1) - 1)) as f32;
+                    reader.samples::<i32>().map(|s| s.map(|s| s as f32 / scale)).collect()
+                }
+            }.map_err(|e| decode_error(&e))?;
+
+            Ok(Pcm { channels: spec.channels, sample_rate: spec.sample_rate, samples })
+        }
+        Some(SampleFormat::Ogg) => {
+            let mut reader = lewton::inside_ogg::OggStreamReader::new(Cursor::new(bytes)).map_err(|e| decode_error(&e))?;
+            let mut samples = Vec::new();
+            while let Some(packet) = reader.read_dec_packet_itl().map_err(|e| decode_error(&e))? {
+                samples.extend(packet.into_iter().map(|s| s as f32 / 32768.0));
+            }
+
+            Ok(Pcm {
+                channels: reader.ident_hdr.audio_channels as u16,
+                sample_rate: reader.ident_hdr.audio_sample_rate,
+                samples,
+            })
+        }
+        format => Err(SampleProcessError::Unsupported { format }),
+    }
+}
+
+pub fn encode_wav(pcm: &Pcm) -> Result<Vec<u8>, SampleProcessError> {
+    let encode_error = |e: hound::Error| SampleProcessError::Encode { reason: e.to_string() };
+    let spec = hound::WavSpec {
+        channels: pcm.channels.max(1),
+        sample_rate: pcm.sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let mut bytes = Vec::new();
+    let mut writer = hound::WavWriter::new(Cursor::new(&mut bytes), spec).map_err(encode_error)?;
+    for sample in &pcm.samples {
+        writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).map_err(encode_error)?;
+    }
+    writer.finalize().map_err(encode_error)?;
+
+    Ok(bytes)
+}
+
+/// drops every frame before the first one with a channel louder than `threshold`, a silent
+/// sample is left as is
+pub fn trim_leading_silence(pcm: &mut Pcm, threshold: f32) {
+    let channels = pcm.channels.max(1) as usize;
+    let first_loud = pcm.samples
+        .chunks(channels)
+        .position(|frame| frame.iter().any(|s| s.abs() > threshold));
+
+    if let Some(frame) = first_loud {
+        pcm.samples.drain(..frame * channels);
+    }
+}
+
+pub fn normalize_peak(pcm: &mut Pcm, peak: f32) {
+    let loudest = pcm.samples.iter().fold(0.0_f32, |max, s| max.max(s.abs()));
+    if loudest > 0.0 {
+        let gain = peak.clamp(0.0, 1.0) / loudest;
+        pcm.samples.iter_mut().for_each(|s| *s *= gain);
+    }
+}
+
+pub fn downmix(pcm: &mut Pcm) {
+    let channels = pcm.channels.max(1) as usize;
+    if channels == 1 {
+        return;
+    }
+
+    pcm.samples = pcm.samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+        .collect();
+    pcm.channels = 1;
+}
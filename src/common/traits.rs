@@ -4,7 +4,7 @@ use core::num;
 use std::{collections::HashSet, rc::Rc, str::FromStr};
 use merge::Merge;
 
-use crate::{GenericManiaSkin, StringPattern, utils::skin::get_lane_type};
+use crate::{GenericManiaSkin, StringPattern, error::ConvertError, options::ConversionOptions, report::ConversionReport, utils::skin::get_lane_type};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum LaneType {
@@ -18,14 +18,41 @@ pub trait ManiaSkin<'a>: Merge {
     type ToParams;
     type FromReturn;
     
-    fn to_generic_mania(&self, params: Self::ToParams) -> Result<GenericManiaSkin, Box<dyn std::error::Error>>;
-    fn from_generic_mania(skin: &GenericManiaSkin) -> Result<Self::FromReturn, Box<dyn std::error::Error>>;
+    /// also lists everything that was dropped, approximated or replaced with `blank` on the way
+    fn to_generic_mania_with(&self, params: Self::ToParams, options: &ConversionOptions) -> Result<(GenericManiaSkin, ConversionReport), ConvertError>;
+    fn from_generic_mania_with(skin: &GenericManiaSkin, options: &ConversionOptions) -> Result<(Self::FromReturn, ConversionReport), ConvertError>;
+
+    fn to_generic_mania_with_report(&self, params: Self::ToParams) -> Result<(GenericManiaSkin, ConversionReport), ConvertError> {
+        self.to_generic_mania_with(params, &ConversionOptions::default())
+    }
+
+    fn from_generic_mania_with_report(skin: &GenericManiaSkin) -> Result<(Self::FromReturn, ConversionReport), ConvertError> {
+        Self::from_generic_mania_with(skin, &ConversionOptions::default())
+    }
+
+    fn to_generic_mania_with_options(&self, params: Self::ToParams, options: &ConversionOptions) -> Result<GenericManiaSkin, ConvertError> {
+        self.to_generic_mania_with(params, options).map(|(skin, _)| skin)
+    }
+
+    fn from_generic_mania_with_options(skin: &GenericManiaSkin, options: &ConversionOptions) -> Result<Self::FromReturn, ConvertError> {
+        Self::from_generic_mania_with(skin, options).map(|(skin, _)| skin)
+    }
+
+    fn to_generic_mania(&self, params: Self::ToParams) -> Result<GenericManiaSkin, ConvertError> {
+        self.to_generic_mania_with_options(params, &ConversionOptions::default())
+    }
+
+    fn from_generic_mania(skin: &GenericManiaSkin) -> Result<Self::FromReturn, ConvertError> {
+        Self::from_generic_mania_with_options(skin, &ConversionOptions::default())
+    }
 
     fn get_keymode(&self, keymode: u8) -> Option<&Self::Keymode>;
     
     fn get_required_texture_paths(&self) -> Vec<StringPattern>;
     fn get_required_sample_paths(&self) -> Vec<StringPattern> { Vec::new() }
 
+    /// `other` wins everywhere it has something, `diff::ThreeWayMerge` keeps changes from both sides
+    /// and reports the ones that collide
     fn merge(&mut self, other: Self) where Self: Sized {
         <Self as Merge>::merge(self, other);
     }
@@ -0,0 +1,264 @@
+use std::sync::Arc;
+
+use crate::common::alignment::{Alignment, Anchor, Origin};
+use crate::common::color::Rgba;
+use crate::common::traits::LaneFallback;
+use crate::common::vector::Vector3;
+use crate::etterna::config::{Button, Keymode as EtternaKeymode};
+use crate::etterna::static_assets;
+use crate::etterna::{EtternaMetrics, EtternaNoteskin};
+use crate::generic::elements::{
+    ColumnLighting, Cursor, Healthbar, HitLightingHold, HitLightingNormal, Judgement, JudgementLine, LongNoteBody, LongNoteHead, LongNoteTail, NormalMine, NormalNote, ReceptorDown, ReceptorUp, Stage
+};
+use crate::generic::layout::{HUDLayout, KeymodeLayout};
+use crate::generic::sound::{GenericGameplaySounds, ManiaGameplaySounds, Sounds, UISounds};
+use crate::generic::{Gameplay, UI};
+use crate::io::texture::Texture;
+use crate::io::Store;
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::ConversionReport;
+use crate::skin::generic::{GenericManiaSkin, Keymode, Metadata};
+use crate::utils::etterna::{EtternaDimensions, SpriteResolver};
+use crate::utils::skin::{SheetNaming, StoreRelocator, cleanup_stores, join_sheet};
+use crate::{Binary, BinaryArcExt, StringPattern};
+
+pub fn to_generic_mania(skin: &EtternaNoteskin, options: &ConversionOptions, report: &mut ConversionReport) -> Result<GenericManiaSkin, ConvertError> {
+    let mut textures = skin.textures.clone();
+    let samples = skin.samples.clone();
+    let mut keymodes: Vec<Keymode> = Vec::new();
+
+    let blank_texture = textures.insert(Texture::from_blank("blank".to_string()));
+
+    let metadata = Metadata {
+        name: skin.name.clone(),
+        creator: String::new(),
+        version: String::new(),
+    };
+
+    for keymode in &skin.keymodes {
+        let key_count = keymode.keymode as usize;
+        let mut resolver = SpriteResolver::new(&mut textures);
+
+        let mut receptors = Vec::with_capacity(key_count);
+        let mut normal_notes = Vec::with_capacity(key_count);
+        let mut long_note_heads = Vec::with_capacity(key_count);
+        let mut long_note_bodies = Vec::with_capacity(key_count);
+        let mut long_note_tails = Vec::with_capacity(key_count);
+        let mut normal_mines = Vec::with_capacity(key_count);
+
+        for button in &keymode.buttons {
+            let note = resolver.get_rotated(&static_assets::Tap::NOTE, *button)?
+                .unwrap_or_else(|| Arc::clone(&blank_texture));
+
+            // noteskins without hold heads draw the tap note instead
+            let head = resolver.get_rotated(&static_assets::Hold::HEAD_ACTIVE, *button)?
+                .unwrap_or_else(|| Arc::clone(&note));
+
+            receptors.push(resolver.get_rotated(&static_assets::Receptor::RECEPTOR, *button)?
+                .unwrap_or_else(|| Arc::clone(&blank_texture)));
+            normal_notes.push(note);
+            long_note_heads.push(head);
+            long_note_bodies.push(resolver.get_unrotated(&static_assets::Hold::BODY_ACTIVE, *button)
+                .unwrap_or_else(|| Arc::clone(&blank_texture)));
+            long_note_tails.push(resolver.get_unrotated(&static_assets::Hold::BOTTOM_CAP_ACTIVE, *button)
+                .unwrap_or_else(|| Arc::clone(&blank_texture)));
+            normal_mines.push(resolver.get_unrotated(&static_assets::Tap::MINE, *button)
+                .unwrap_or_else(|| Arc::clone(&blank_texture)));
+        }
+
+        let hln = resolver.get_frames(&EtternaKeymode::get_button_asset(&static_assets::Tap::EXPLOSION_DIM, Button::Down));
+        let hlh = resolver.get_frames(&EtternaKeymode::get_button_asset(&static_assets::Hold::EXPLOSION, Button::Down));
+
+        let fallbacks: Vec<LaneFallback> = (0..receptors.len())
+            .map(|i| LaneFallback {
+                receptor: receptors[i].get_path(),
+                receptor_down: receptors[i].get_path(),
+                normal_note: normal_notes[i].get_path(),
+                long_note_head: long_note_heads[i].get_path(),
+                long_note_body: long_note_bodies[i].get_path(),
+                long_note_tail: long_note_tails[i].get_path(),
+                normal_mine: normal_mines[i].get_path(),
+            })
+            .collect();
+
+        let requested = |pattern: &StringPattern| -> Vec<String> {
+            keymode.buttons.iter()
+                .map(|button| EtternaKeymode::get_button_asset(pattern, *button).to_string())
+                .collect()
+        };
+
+        let receptor_up: Vec<ReceptorUp> = receptors.iter().map(|r| ReceptorUp::new(Some(Arc::clone(r)))).collect();
+        let normal_notes: Vec<NormalNote> = normal_notes.into_iter().map(|t| NormalNote::new(Some(t))).collect();
+        let long_note_bodies: Vec<LongNoteBody> = long_note_bodies.into_iter().map(|t| LongNoteBody::new(Some(t))).collect();
+        let long_note_tails: Vec<LongNoteTail> = long_note_tails.into_iter().map(|t| LongNoteTail::new(Some(t))).collect();
+        let normal_mines: Vec<NormalMine> = normal_mines.into_iter().map(|t| NormalMine::new(Some(t))).collect();
+
+        report.blank_columns(keymode.keymode, "receptor_up", &receptor_up, &requested(&static_assets::Receptor::RECEPTOR));
+        report.blank_columns(keymode.keymode, "normal_notes", &normal_notes, &requested(&static_assets::Tap::NOTE));
+        report.blank_columns(keymode.keymode, "long_note_bodies", &long_note_bodies, &requested(&static_assets::Hold::BODY_ACTIVE));
+        report.blank_columns(keymode.keymode, "long_note_tails", &long_note_tails, &requested(&static_assets::Hold::BOTTOM_CAP_ACTIVE));
+        report.blank_columns(keymode.keymode, "normal_mines", &normal_mines, &requested(&static_assets::Tap::MINE));
+
+        let layout = KeymodeLayout {
+            keymode: keymode.keymode,
+            receptor_above_notes: false,
+            show_judgement_line: false,
+            x_offset: 0.5,
+            hit_position: EtternaDimensions::HitPosition.as_f32() / EtternaDimensions::Y.as_f32(),
+            receptor_offset: 0,
+            column_widths: vec![EtternaDimensions::ArrowSize.as_f32() / EtternaDimensions::X.as_f32(); key_count],
+            column_spacing: vec![0.0; key_count],
+        };
+
+        keymodes.push(Keymode {
+            keymode: keymode.keymode,
+            layout,
+            use_snap_color: false,
+            snap_colors: Vec::new(),
+            // the receptor flashes through a lua effect instead of a separate pressed sprite
+            receptor_up,
+            receptor_down: receptors.iter().map(|r| ReceptorDown::new(Some(Arc::clone(r)))).collect(),
+            base_normal_note: None,
+            base_long_note: None,
+            base_normal_mine: None,
+            normal_notes,
+            long_note_heads: long_note_heads.into_iter().map(|t| LongNoteHead::new(Some(t))).collect(),
+            long_note_bodies,
+            long_note_tails,
+            normal_mines,
+            normal_notes_snap_colored: None,
+            long_note_heads_snap_colored: None,
+            normal_mines_snap_colored: None,
+            hit_lighting_normal: HitLightingNormal::new(hln.0, None, Some(hln.2), Some(hln.1)),
+            hit_lighting_hold: HitLightingHold::new(hlh.0, None, Some(hlh.2), Some(hlh.1)),
+            column_lighting: ColumnLighting { texture: Some(Arc::clone(&blank_texture)) },
+            judgement_line: JudgementLine { texture: Some(Arc::clone(&blank_texture)), color: Rgba::default() },
+            stage: Stage::new(None, None, None),
+            fallbacks,
+        });
+    }
+
+    // noteskins only cover the notefield, everything else belongs to the theme
+    let ui = UI {
+        cursor: Cursor { texture: None, centered: false, rotate: false },
+    };
+
+    let gameplay = Gameplay {
+        health_bar: Healthbar::new(None, None),
+        judgement: Judgement::new(None, None, None, None, None, None),
+        layout: HUDLayout {
+            combo: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: Anchor::Centre, origin: Origin::TopLeft }),
+            rating: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: Anchor::TopLeft, origin: Origin::TopLeft }),
+            accuracy: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: Anchor::TopRight, origin: Origin::TopLeft }),
+            score: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: Anchor::TopLeft, origin: Origin::TopLeft }),
+            judgement: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: Anchor::Centre, origin: Origin::TopLeft }),
+        },
+    };
+
+    let sounds = Sounds {
+        ui: UISounds {
+            menu_back_click: None,
+            ui_click: None,
+            ui_select: None,
+            ui_hover: None,
+        },
+        gameplay: GenericGameplaySounds {
+            miss: None,
+            fail: None,
+            restart: None,
+        },
+        mania: ManiaGameplaySounds {
+            hit: None,
+        },
+    };
+
+    let mut generic_skin = GenericManiaSkin {
+        resolution: skin.resolution,
+        sounds,
+        metadata,
+        ui,
+        gameplay,
+        keymodes,
+        textures,
+        samples,
+    };
+
+    generic_skin.ensure_textures();
+    options.limit_texture_size(&generic_skin.textures, report);
+
+    Ok(generic_skin)
+}
+
+pub fn from_generic_mania(skin: &GenericManiaSkin, options: &ConversionOptions, report: &mut ConversionReport) -> Result<EtternaNoteskin, ConvertError> {
+    let mut textures = skin.textures.clone();
+    let metrics = EtternaMetrics::default();
+
+    // a noteskin only has the `Down` sprites, so take them from the down column of 4K if there is one
+    let (keymode, lane) = match skin.keymodes.iter().find(|k| k.keymode == 4) {
+        Some(km) => (km, 1),
+        None => (skin.keymodes.first().ok_or(ConvertError::MissingKeymode { keymode: 4 })?, 0),
+    };
+
+    let down = |pattern: &StringPattern| EtternaKeymode::get_button_asset(pattern, Button::Down);
+
+    for other in skin.keymodes.iter().filter(|k| k.keymode != keymode.keymode) {
+        report.dropped(format!("{}K", other.keymode), "a noteskin is built from a single keymode");
+    }
+    report.approximated(format!("{}K", keymode.keymode), format!("every column uses the sprites of column {lane}"));
+    report.dropped_columns(keymode.keymode, "receptor_down", &keymode.receptor_down, "the receptor flashes through a lua effect instead");
+    report.dropped_snap_sheets(keymode, "not converted to etterna, the column notes are used");
+    report.dropped_element(format!("{}K.column_lighting", keymode.keymode), &keymode.column_lighting, "belongs to the etterna theme");
+    report.dropped_element(format!("{}K.judgement_line", keymode.keymode), &keymode.judgement_line, "belongs to the etterna theme");
+    report.dropped_element("gameplay.health_bar", &skin.gameplay.health_bar, "belongs to the etterna theme");
+    report.dropped_element("gameplay.judgement", &skin.gameplay.judgement, "belongs to the etterna theme");
+    report.dropped_element("ui.cursor", &skin.ui.cursor, "belongs to the etterna theme");
+
+    {
+        let mut tr = StoreRelocator::new(&mut textures);
+
+        if let Some(r) = keymode.receptor_up.get(lane) {
+            tr.reloc_arc_lock(&r.texture, down(&static_assets::Receptor::RECEPTOR));
+        }
+        if let Some(n) = keymode.normal_notes.get(lane) {
+            tr.reloc_arc_lock(&n.texture, down(&static_assets::Tap::NOTE));
+        }
+        if let Some(n) = keymode.long_note_heads.get(lane) {
+            tr.reloc_arc_lock(&n.texture, down(&static_assets::Hold::HEAD_ACTIVE));
+            tr.reloc_arc_lock(&n.texture, down(&static_assets::Hold::HEAD_INACTIVE));
+        }
+        if let Some(n) = keymode.long_note_bodies.get(lane) {
+            tr.reloc_arc_lock(&n.texture, down(&static_assets::Hold::BODY_ACTIVE));
+            tr.reloc_arc_lock(&n.texture, down(&static_assets::Hold::BODY_INACTIVE));
+        }
+        if let Some(n) = keymode.long_note_tails.get(lane) {
+            tr.reloc_arc_lock(&n.texture, down(&static_assets::Hold::BOTTOM_CAP_ACTIVE));
+            tr.reloc_arc_lock(&n.texture, down(&static_assets::Hold::BOTTOM_CAP_INACTIVE));
+        }
+        if let Some(n) = keymode.normal_mines.get(lane) {
+            tr.reloc_arc_lock(&n.texture, down(&static_assets::Tap::MINE));
+        }
+    }
+
+    if let Some(tex) = join_sheet(&keymode.hit_lighting_normal, &down(&static_assets::Tap::EXPLOSION_DIM), SheetNaming::ColumnsFirst) {
+        textures.insert(tex);
+    }
+    if let Some(tex) = join_sheet(&keymode.hit_lighting_normal, &down(&static_assets::Tap::EXPLOSION_BRIGHT), SheetNaming::ColumnsFirst) {
+        textures.insert(tex);
+    }
+    if let Some(tex) = join_sheet(&keymode.hit_lighting_hold, &down(&static_assets::Hold::EXPLOSION), SheetNaming::ColumnsFirst) {
+        textures.insert(tex);
+    }
+
+    cleanup_stores(&metrics, Some(&mut textures), None);
+
+    // the exported NoteSkin.lua redirects every button to `Down`, other buttons would never be loaded
+    let down_prefix = format!("{} ", Button::Down.as_str().to_lowercase());
+    textures.retain(|t| t.get_path().to_lowercase().starts_with(&down_prefix));
+    options.limit_texture_size(&textures, report);
+
+    let mut noteskin = EtternaNoteskin::new(metrics, Some(textures), None);
+    noteskin.name = skin.metadata.name.clone();
+
+    Ok(noteskin)
+}
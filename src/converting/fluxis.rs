@@ -14,6 +14,9 @@ use crate::image_proc::generate_fluxis_preview;
 use crate::image_proc::proc::{dist_from_bottom, get_dominant_color, overlay_image, resize_img, trim_image_vertical};
 use crate::io::Store;
 use crate::io::texture::{Texture, TextureProcessor};
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::{ConversionIssueKind, ConversionReport};
 use crate::skin::fluxis::layout_json::component::*;
 use crate::skin::fluxis::layout_json::gameplay::*;
 use crate::skin::fluxis::skin_json::colors::{JudgementColors, SnapColors};
@@ -25,20 +28,19 @@ use crate::skin::fluxis::{
     FluXisLayout,
     SkinJson
 };
-use crate::traits::{KeymodeInvariant, ManiaSkin};
+use crate::traits::{KeymodeInvariant, LaneFallback, ManiaSkin};
 use crate::utils::fluxis::FluXisDimensions;
 use crate::utils::skin::{StoreRelocator, cleanup_stores};
 use crate::{Binary, BinaryArcExt, BinaryArcExtOption, GenericManiaSkin, Resources};
 
-pub fn to_generic_mania(skin: &FluXisSkin, layout: Option<&FluXisLayout>) -> Result<GenericManiaSkin, Box<dyn std::error::Error>> {
+pub fn to_generic_mania(skin: &FluXisSkin, layout: Option<&FluXisLayout>, options: &ConversionOptions, report: &mut ConversionReport) -> Result<GenericManiaSkin, ConvertError> {
     let mut textures = skin.textures.clone();
     let samples = skin.samples.clone();
     let layout_d = FluXisLayout::default();
     let layout = layout.unwrap_or(&layout_d);
     let mut keymodes: Vec<Keymode> = Vec::new();
 
-    textures.insert(Texture::from_blank("blank".to_string()));
-    let blank_texture = textures.get_shared("blank").unwrap();
+    let blank_texture = textures.insert(Texture::from_blank("blank".to_string()));
 
     let metadata = Metadata {
         name: skin.skin_json.info.name.clone(),
@@ -72,12 +74,14 @@ pub fn to_generic_mania(skin: &FluXisSkin, layout: Option<&FluXisLayout>) -> Res
                 if !path.is_empty() {
                     if let Some(texture) = textures.get_shared(path) {
                         let offset = receptor_processor.process_once(&texture, |arc| {
-                            arc.with_image(|img| dist_from_bottom(img, 0.1)) as i32
+                            arc.image_ref(|img| dist_from_bottom(img, options.trim_tolerance)).unwrap_or(0) as i32
                         });
                         receptor_processor.process_once_void(&texture, |arc| {
-                            arc.data_mut(|img| {
-                                let trimmed = trim_image_vertical(img, 0.2);
-                                *img = trimmed;
+                            report.track_size(arc, ConversionIssueKind::Trimmed, |arc| {
+                                arc.data_mut(|img| {
+                                    let trimmed = trim_image_vertical(img, 0.2);
+                                    *img = trimmed;
+                                });
                             });
                         });
                         max_additional_offset = max_additional_offset.max(offset);
@@ -102,12 +106,14 @@ pub fn to_generic_mania(skin: &FluXisSkin, layout: Option<&FluXisLayout>) -> Res
                 if !path.is_empty() {
                     if let Some(texture) = textures.get_shared(path) {
                         let offset = receptor_processor.process_once(&texture, |tex| {
-                            tex.with_image(|img| dist_from_bottom(img, 0.1)) as i32
+                            tex.image_ref(|img| dist_from_bottom(img, options.trim_tolerance)).unwrap_or(0) as i32
                         });
                         receptor_processor.process_once_void(&texture, |arc| {
-                            arc.data_mut(|img| {
-                                let trimmed = trim_image_vertical(img, 0.2);
-                                *img = trimmed;
+                            report.track_size(arc, ConversionIssueKind::Trimmed, |arc| {
+                                arc.data_mut(|img| {
+                                    let trimmed = trim_image_vertical(img, 0.2);
+                                    *img = trimmed;
+                                });
                             });
                         });
                         max_additional_offset = max_additional_offset.max(offset);
@@ -249,6 +255,20 @@ pub fn to_generic_mania(skin: &FluXisSkin, layout: Option<&FluXisLayout>) -> Res
             })
             .collect();
 
+        let requested = |paths: &[String], fallback: fn(&LaneFallback) -> &String| -> Vec<String> {
+            paths.iter()
+                .zip(fallbacks.iter())
+                .map(|(path, f)| if path.is_empty() { fallback(f).clone() } else { path.clone() })
+                .collect()
+        };
+
+        report.blank_columns(keymode.keymode, "receptor_up", &receptor_up_elements, &requested(&keymode.receptor_images, |f| &f.receptor));
+        report.blank_columns(keymode.keymode, "receptor_down", &receptor_down_elements, &requested(&keymode.receptor_images_down, |f| &f.receptor_down));
+        report.blank_columns(keymode.keymode, "normal_notes", &normal_note_elements, &requested(&keymode.normal_note_images, |f| &f.normal_note));
+        report.blank_columns(keymode.keymode, "long_note_bodies", &long_note_body_elements, &requested(&keymode.long_note_body_images, |f| &f.long_note_body));
+        report.blank_columns(keymode.keymode, "long_note_tails", &long_note_tail_elements, &requested(&keymode.long_note_tail_images, |f| &f.long_note_tail));
+        report.blank_columns(keymode.keymode, "normal_mines", &normal_mine_elements, &requested(&keymode.normal_mine_images, |f| &f.normal_mine));
+
         let show_judgement_line = !skin.skin_json.overrides.stage.hitline.trim().is_empty();
 
         let new_layout = KeymodeLayout {
@@ -309,10 +329,13 @@ pub fn to_generic_mania(skin: &FluXisSkin, layout: Option<&FluXisLayout>) -> Res
     let fluxis_x = FluXisDimensions::X.as_f32();
     let fluxis_y = FluXisDimensions::Y.as_f32();
 
-    let combo_hud = layout.gameplay.components.get("Combo").unwrap();
-    let rating_hud = layout.gameplay.components.get("PerformanceRating").unwrap();
-    let accuracy_hud = layout.gameplay.components.get("Accuracy").unwrap();
-    let judgement_hud = layout.gameplay.components.get("Judgement").unwrap();
+    let component = |name: &str| layout.gameplay.components.get(name).ok_or_else(|| ConvertError::InvalidLayout {
+        reason: format!("gameplay has no {name} component"),
+    });
+    let combo_hud = component("Combo")?;
+    let rating_hud = component("PerformanceRating")?;
+    let accuracy_hud = component("Accuracy")?;
+    let judgement_hud = component("Judgement")?;
 
     let gameplay = Gameplay {
         health_bar: Healthbar::new(
@@ -393,13 +416,9 @@ pub fn to_generic_mania(skin: &FluXisSkin, layout: Option<&FluXisLayout>) -> Res
 
     let ui = UI {
         cursor: Cursor {
-            texture: Some(Arc::new(RwLock::new(
-                Texture::from_bytes(
-                    "Cursor/fluxis_cursor".to_string(),
-                    &Resources::cursor("fluxis_cursor.png")
-                        .expect("Cursor resource not found")
-                ).expect("Failed to load cursor texture")
-            ))),
+            texture: Resources::cursor("fluxis_cursor.png")
+                .and_then(|bytes| Texture::from_bytes("Cursor/fluxis_cursor".to_string(), &bytes).ok())
+                .map(|tex| Arc::new(RwLock::new(tex))),
             centered: false,
             rotate: false
         }
@@ -434,11 +453,12 @@ pub fn to_generic_mania(skin: &FluXisSkin, layout: Option<&FluXisLayout>) -> Res
     };
 
     generic_skin.ensure_textures();
+    options.limit_texture_size(&generic_skin.textures, report);
 
     Ok(generic_skin)
 }
 
-pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<(FluXisSkin, FluXisLayout), Box<dyn std::error::Error>> {
+pub fn from_generic_mania(skin: &GenericManiaSkin, options: &ConversionOptions, report: &mut ConversionReport) -> Result<(FluXisSkin, FluXisLayout), ConvertError> {
     let mut textures = skin.textures.clone();
     let mut samples = skin.samples.clone();
     let mut fluxis_keymodes: Vec<skin_json::Keymode> = Vec::new();
@@ -521,18 +541,20 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<(FluXisSkin, FluXis
                 if let Some(texture_arc) = &note.texture {
                     if !Arc::ptr_eq(texture_arc, &blank_texture) {
                         body_processor.process_once_void(texture_arc, |arc_texture| {
-                            arc_texture.data_mut(|img| {
-                                let (width, height) = img.dimensions();
-                                let max_res = FluXisDimensions::MaxResolution.as_u32();
-                                
-                                if width > max_res || height > max_res {
-                                    *img = resize_img(
-                                        img,
-                                        width.min(max_res),
-                                        height.min(max_res),
-                                        FilterType::Hamming
-                                    );
-                                }
+                            report.track_size(arc_texture, ConversionIssueKind::Resized, |arc_texture| {
+                                arc_texture.data_mut(|img| {
+                                    let (width, height) = img.dimensions();
+                                    let max_res = FluXisDimensions::MaxResolution.as_u32();
+                                    
+                                    if width > max_res || height > max_res {
+                                        *img = resize_img(
+                                            img,
+                                            width.min(max_res),
+                                            height.min(max_res),
+                                            options.filter_type()
+                                        );
+                                    }
+                                });
                             });
                         });
                     }
@@ -547,7 +569,8 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<(FluXisSkin, FluXis
                 if let Some(texture_arc) = &note.texture {
                     if !Arc::ptr_eq(texture_arc, &blank_texture) {
                         tail_processor.process_once_void(texture_arc, |arc_texture| {
-                            arc_texture.data_mut(|img| {
+                            report.trimmed(&arc_texture.get_path(), "trimmed and backed with the stage colour");
+                            report.track_size(arc_texture, ConversionIssueKind::Resized, |arc_texture| arc_texture.data_mut(|img| {
                                 let (width, height) = img.dimensions();
                                 let max_res = FluXisDimensions::MaxResolution.as_u32();
                                 
@@ -556,7 +579,7 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<(FluXisSkin, FluXis
                                         img,
                                         width.min(max_res),
                                         height.min(max_res),
-                                        FilterType::Hamming
+                                        options.filter_type()
                                     );
                                 }
                                 
@@ -578,7 +601,7 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<(FluXisSkin, FluXis
                                 overlay_image(&mut bg, &trimmed, 0, bg_height.saturating_sub(trimmed.height()) + 1);
 
                                 *img = bg;
-                            });
+                            }));
                         });
                     }
                 }
@@ -599,6 +622,20 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<(FluXisSkin, FluXis
             }
         };
 
+        let widths = &keymode.layout.column_widths;
+        if widths.iter().any(|w| (w - widths[0]).abs() > f32::EPSILON) {
+            report.approximated(format!("{key_count}K.column_widths"), "fluXis has one column width, the average is used");
+        }
+        if keymode.layout.column_spacing.iter().any(|s| *s != 0.0) {
+            report.dropped(format!("{key_count}K.column_spacing"), "fluXis has no column spacing");
+        }
+        if keymode.layout.x_offset != 0.5 {
+            report.dropped(format!("{key_count}K.x_offset"), "fluXis always centres the playfield");
+        }
+        report.dropped_element(format!("{key_count}K.hit_lighting_normal"), &keymode.hit_lighting_normal, "not converted to fluXis");
+        report.dropped_element(format!("{key_count}K.hit_lighting_hold"), &keymode.hit_lighting_hold, "not converted to fluXis");
+        report.dropped_snap_sheets(keymode, "fluXis tints notes by snap instead, the per column notes are used");
+
         fluxis_keymodes.push(skin_json::Keymode {
             keymode: key_count,
             receptor_images,
@@ -639,7 +676,9 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<(FluXisSkin, FluXis
         snap_colors: SnapColors::default(),
     };
 
-    let default_keymode = skin.get_keymode(4).unwrap_or(skin.keymodes.first().unwrap());
+    let default_keymode = skin.get_keymode(4)
+        .or(skin.keymodes.first())
+        .ok_or(ConvertError::MissingKeymode { keymode: 4 })?;
 
     let mut tr = StoreRelocator::new(&mut textures);
     let mut sr = StoreRelocator::new(&mut samples);
@@ -690,11 +729,17 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<(FluXisSkin, FluXis
     }
 
     cleanup_stores(&skin_json, Some(&mut textures), Some(&mut samples));
+    options.limit_texture_size(&textures, report);
+    options.process_hit_sound(&mut samples, &static_assets::Samples::GAMEPLAY_HIT, report);
 
     let fluxis_skin = FluXisSkin::new(skin_json, Some(textures), Some(samples));
 
     let mut layout = FluXisLayout::new(skin.metadata.name.clone(), skin.metadata.creator.clone());
 
+    report.dropped("hud.score", "the fluXis layout has no score component");
+    report.dropped("hud.judgement", "fluXis places it on its own");
+    report.approximated("hud.keys_per_second", "placed at the rating position");
+
     let fluxis_x = FluXisDimensions::X.as_f32();
     let fluxis_y = FluXisDimensions::Y.as_f32();
 
@@ -0,0 +1,345 @@
+use std::sync::Arc;
+
+use crate::common::alignment::{Alignment, Anchor, Origin};
+use crate::common::color::Rgba;
+use crate::common::skin::AssetAttribute;
+use crate::common::vector::Vector3;
+use crate::extensions::VecExtensions;
+use crate::generic::elements::{
+    ColumnLighting, Cursor, Healthbar, HitLightingHold, HitLightingNormal, Judgement, JudgementLine, LongNoteBody, LongNoteHead, LongNoteTail, NormalMine, NormalNote, ReceptorDown, ReceptorUp, Stage
+};
+use crate::generic::layout::{HUDLayout, KeymodeLayout};
+use crate::generic::sound::{GenericGameplaySounds, ManiaGameplaySounds, Sounds, UISounds};
+use crate::generic::{Gameplay, UI};
+use crate::io::texture::Texture;
+use crate::io::Store;
+use crate::malody::conf_json::SUPPORTED_KEYMODES;
+use crate::malody::{dynamic_assets, static_assets, ConfJson, MalodySkin};
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::ConversionReport;
+use crate::skin::generic::{GenericManiaSkin, Keymode, Metadata};
+use crate::traits::{KeymodeInvariant, LaneFallback};
+use crate::utils::malody::{get_frames, MalodyDimensions};
+use crate::utils::skin::{SheetNaming, StoreRelocator, cleanup_stores, join_sheet};
+use crate::{malody, BinaryArcExtOption, ConstTypeEnum, StringPattern};
+
+pub fn to_generic_mania(skin: &MalodySkin, options: &ConversionOptions, report: &mut ConversionReport) -> Result<GenericManiaSkin, ConvertError> {
+    let mut textures = skin.textures.clone();
+    let samples = skin.samples.clone();
+    let mut keymodes: Vec<Keymode> = Vec::new();
+
+    let blank_texture = textures.insert(Texture::from_blank("blank".to_string()));
+
+    let metadata = Metadata {
+        name: skin.conf_json.meta.name.clone(),
+        creator: skin.conf_json.meta.creator.clone(),
+        version: skin.conf_json.meta.version.clone(),
+    };
+
+    for keymode in &skin.conf_json.keymodes {
+        let key_count = keymode.keymode as usize;
+        let fallbacks = keymode.get_fallbacks();
+
+        // a lane's own texture, then the one shared by its lane type
+        let resolve = |paths: Vec<String>, fallback: fn(&LaneFallback) -> &String| -> Vec<Option<Arc<_>>> {
+            paths.iter()
+                .zip(fallbacks.iter())
+                .map(|(path, lane_fallback)| {
+                    textures.get_shared(path).or_else(|| textures.get_shared(fallback(lane_fallback)))
+                })
+                .collect()
+        };
+
+        let receptors = resolve(keymode.get_receptors(), |f| &f.receptor);
+        let receptors_down = resolve(keymode.get_receptors_down(), |f| &f.receptor_down);
+        let normal_notes = resolve(keymode.get_normal_notes(), |f| &f.normal_note);
+        let long_note_heads = resolve(keymode.get_long_note_heads(), |f| &f.long_note_head);
+        let long_note_bodies = resolve(keymode.get_long_note_bodies(), |f| &f.long_note_body);
+        let long_note_tails = resolve(keymode.get_long_note_tails(), |f| &f.long_note_tail);
+
+        let or_blank = |tex: &Option<Arc<_>>| Some(tex.clone().unwrap_or_else(|| Arc::clone(&blank_texture)));
+
+        let receptor_up_elements: Vec<ReceptorUp> = receptors.iter()
+            .map(|r| ReceptorUp::new(or_blank(r)))
+            .collect();
+
+        // pressed keys are optional, the game keeps showing the normal key without them
+        let receptor_down_elements: Vec<ReceptorDown> = receptors_down.iter()
+            .zip(receptors.iter())
+            .map(|(down, up)| ReceptorDown::new(or_blank(&down.clone().or_else(|| up.clone()))))
+            .collect();
+
+        let normal_note_elements: Vec<NormalNote> = normal_notes.iter()
+            .map(|n| NormalNote::new(or_blank(n)))
+            .collect();
+
+        let long_note_head_elements: Vec<LongNoteHead> = long_note_heads.iter()
+            .zip(normal_notes.iter())
+            .map(|(head, note)| LongNoteHead::new(or_blank(&head.clone().or_else(|| note.clone()))))
+            .collect();
+
+        let long_note_body_elements: Vec<LongNoteBody> = long_note_bodies.iter()
+            .map(|b| LongNoteBody::new(or_blank(b)))
+            .collect();
+
+        let long_note_tail_elements: Vec<LongNoteTail> = long_note_tails.iter()
+            .map(|t| LongNoteTail::new(or_blank(t)))
+            .collect();
+
+        // malody has no mines
+        let normal_mine_elements: Vec<NormalMine> = normal_notes.iter()
+            .map(|n| NormalMine::new(or_blank(n)))
+            .collect();
+
+        report.blank_columns(keymode.keymode, "receptor_up", &receptor_up_elements, &keymode.get_receptors());
+        report.blank_columns(keymode.keymode, "normal_notes", &normal_note_elements, &keymode.get_normal_notes());
+        report.blank_columns(keymode.keymode, "long_note_bodies", &long_note_body_elements, &keymode.get_long_note_bodies());
+        report.blank_columns(keymode.keymode, "long_note_tails", &long_note_tail_elements, &keymode.get_long_note_tails());
+
+        let hln = get_frames(&textures, &keymode.get_generic(dynamic_assets::Lighting::HIT, 0));
+        let hlh = get_frames(&textures, &keymode.get_generic(dynamic_assets::Lighting::HOLD, 0));
+
+        let judge_line = textures.get_shared(&keymode.get_generic(dynamic_assets::Stage::JUDGE_LINE, 0));
+        let show_judgement_line = keymode.show_judge_line && judge_line.is_some();
+
+        let layout = KeymodeLayout {
+            keymode: keymode.keymode,
+            receptor_above_notes: keymode.receptor_above_notes,
+            show_judgement_line,
+            x_offset: 0.5,
+            hit_position: keymode.judge_line as f32 / MalodyDimensions::Y.as_f32(),
+            receptor_offset: keymode.receptor_offset,
+            column_widths: vec![keymode.column_width as f32 / MalodyDimensions::X.as_f32(); key_count],
+            column_spacing: vec![0.0; key_count],
+        };
+
+        keymodes.push(Keymode {
+            keymode: keymode.keymode,
+            layout,
+            use_snap_color: false,
+            snap_colors: Vec::new(),
+            receptor_up: receptor_up_elements,
+            receptor_down: receptor_down_elements,
+            base_normal_note: None,
+            base_long_note: None,
+            base_normal_mine: None,
+            normal_notes: normal_note_elements,
+            long_note_heads: long_note_head_elements,
+            long_note_bodies: long_note_body_elements,
+            long_note_tails: long_note_tail_elements,
+            normal_mines: normal_mine_elements,
+            normal_notes_snap_colored: None,
+            long_note_heads_snap_colored: None,
+            normal_mines_snap_colored: None,
+            hit_lighting_normal: HitLightingNormal::new(hln.0, Some(keymode.effect_fps as f32), Some(hln.2), Some(hln.1)),
+            hit_lighting_hold: HitLightingHold::new(hlh.0, Some(keymode.effect_fps as f32), Some(hlh.2), Some(hlh.1)),
+            column_lighting: ColumnLighting { texture: Some(Arc::clone(&blank_texture)) },
+            judgement_line: JudgementLine {
+                texture: Some(judge_line.filter(|_| show_judgement_line).unwrap_or_else(|| Arc::clone(&blank_texture))),
+                color: Rgba::default(),
+            },
+            stage: Stage::new(
+                textures.get_shared(&keymode.get_generic(dynamic_assets::Stage::BACKGROUND, 0)),
+                textures.get_shared(&keymode.get_generic(dynamic_assets::Stage::BORDER_RIGHT, 0)),
+                textures.get_shared(&keymode.get_generic(dynamic_assets::Stage::BORDER_LEFT, 0)),
+            ),
+            fallbacks,
+        });
+    }
+
+    let ui = UI {
+        cursor: Cursor { texture: None, centered: false, rotate: false },
+    };
+
+    fn get_anchor<T: ConstTypeEnum<Attribute = AssetAttribute>>(pattern: StringPattern) -> Anchor {
+        T::find_attribute(&pattern, |a| a.as_anchor().is_some())
+            .and_then(AssetAttribute::as_anchor)
+            .unwrap_or(Anchor::TopLeft)
+    }
+
+    // malody only judges best/cool/good/miss so the in-between judgements reuse the closest one
+    let best = textures.get_shared(&static_assets::Judgement::BEST);
+    let cool = textures.get_shared(&static_assets::Judgement::COOL);
+    let good = textures.get_shared(&static_assets::Judgement::GOOD);
+
+    let gameplay = Gameplay {
+        health_bar: Healthbar::new(
+            textures.get_shared(&static_assets::Health::FOREGROUND),
+            textures.get_shared(&static_assets::Health::BACKGROUND),
+        ),
+        judgement: Judgement::new(
+            best.clone(),
+            best,
+            cool,
+            good.clone(),
+            good,
+            textures.get_shared(&static_assets::Judgement::MISS),
+        ),
+        layout: HUDLayout {
+            combo: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: Anchor::Centre, origin: Origin::TopLeft }),
+            rating: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: Anchor::TopLeft, origin: Origin::TopLeft }),
+            accuracy: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: Anchor::TopRight, origin: Origin::TopLeft }),
+            score: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: Anchor::TopLeft, origin: Origin::TopLeft }),
+            judgement: (Vector3::new(0.0, 0.0, 1.0), Alignment { anchor: get_anchor::<static_assets::Judgement>(static_assets::Judgement::BEST), origin: Origin::TopLeft }),
+        },
+    };
+
+    let sounds = Sounds {
+        ui: UISounds {
+            menu_back_click: samples.get_shared(&static_assets::Samples::UI_BACK).get_path(),
+            ui_click: samples.get_shared(&static_assets::Samples::UI_CLICK).get_path(),
+            ui_select: None,
+            ui_hover: None,
+        },
+        gameplay: GenericGameplaySounds {
+            miss: samples.get_shared(&static_assets::Samples::MISS).get_path(),
+            fail: samples.get_shared(&static_assets::Samples::FAIL).get_path(),
+            restart: samples.get_shared(&static_assets::Samples::RESTART).get_path(),
+        },
+        mania: ManiaGameplaySounds {
+            hit: samples.get_shared(&static_assets::Samples::HIT).get_path(),
+        },
+    };
+
+    let mut generic_skin = GenericManiaSkin {
+        resolution: skin.resolution,
+        sounds,
+        metadata,
+        ui,
+        gameplay,
+        keymodes,
+        textures,
+        samples,
+    };
+
+    generic_skin.ensure_textures();
+    options.limit_texture_size(&generic_skin.textures, report);
+
+    Ok(generic_skin)
+}
+
+pub fn from_generic_mania(skin: &GenericManiaSkin, options: &ConversionOptions, report: &mut ConversionReport) -> Result<MalodySkin, ConvertError> {
+    let mut textures = skin.textures.clone();
+    let mut samples = skin.samples.clone();
+
+    let mut conf_json = ConfJson::default();
+    conf_json.meta.name = skin.metadata.name.clone();
+    conf_json.meta.creator = skin.metadata.creator.clone();
+    conf_json.meta.version = skin.metadata.version.clone();
+
+    for keymode in skin.keymodes.iter().filter(|k| !SUPPORTED_KEYMODES.contains(&k.keymode)) {
+        report.dropped(format!("{}K", keymode.keymode), "malody has no skin for this keymode");
+    }
+
+    for keymode in skin.keymodes.iter().filter(|k| SUPPORTED_KEYMODES.contains(&k.keymode)) {
+        let mut malody_km = malody::Keymode::new(keymode.keymode);
+        let key_count = keymode.keymode;
+
+        let widths = &keymode.layout.column_widths;
+        if widths.iter().any(|w| (w - widths[0]).abs() > f32::EPSILON) {
+            report.approximated(format!("{key_count}K.column_widths"), "malody has one column width, the average is used");
+        }
+        if keymode.layout.column_spacing.iter().any(|s| *s != 0.0) {
+            report.dropped(format!("{key_count}K.column_spacing"), "malody has no column spacing");
+        }
+        report.dropped_element(format!("{key_count}K.column_lighting"), &keymode.column_lighting, "malody has no column lighting");
+        report.dropped_columns(key_count, "normal_mines", &keymode.normal_mines, "malody has no mines");
+        report.dropped_snap_sheets(keymode, "malody has no snap colouring, the per column notes are used");
+
+        malody_km.column_width = (keymode.layout.column_widths.average().unwrap_or(0.0) * MalodyDimensions::X.as_f32()).round() as u32;
+        malody_km.judge_line = ((keymode.layout.hit_position * MalodyDimensions::Y.as_f32()).round() as i32)
+            .clamp(-MalodyDimensions::Y.as_i32(), MalodyDimensions::Y.as_i32());
+        malody_km.receptor_offset = keymode.layout.receptor_offset;
+        malody_km.receptor_above_notes = keymode.layout.receptor_above_notes;
+        malody_km.show_judge_line = keymode.layout.show_judgement_line;
+        if let Some(fps) = keymode.hit_lighting_normal.fps {
+            malody_km.effect_fps = fps.round() as u32;
+        }
+
+        let m_receptors = malody_km.get_receptors();
+        let m_receptors_down = malody_km.get_receptors_down();
+        let m_normal_notes = malody_km.get_normal_notes();
+        let m_ln_heads = malody_km.get_long_note_heads();
+        let m_ln_bodies = malody_km.get_long_note_bodies();
+        let m_ln_tails = malody_km.get_long_note_tails();
+
+        {
+            let mut tr = StoreRelocator::new(&mut textures);
+
+            for i in 0..(keymode.keymode as usize) {
+                if let Some(r) = keymode.receptor_up.get(i) {
+                    tr.reloc_arc_lock(&r.texture, StringPattern::from(&m_receptors[i]));
+                }
+                if let Some(r) = keymode.receptor_down.get(i) {
+                    tr.reloc_arc_lock(&r.texture, StringPattern::from(&m_receptors_down[i]));
+                }
+                if let Some(n) = keymode.normal_notes.get(i) {
+                    tr.reloc_arc_lock(&n.texture, StringPattern::from(&m_normal_notes[i]));
+                }
+                if let Some(n) = keymode.long_note_heads.get(i) {
+                    tr.reloc_arc_lock(&n.texture, StringPattern::from(&m_ln_heads[i]));
+                }
+                if let Some(n) = keymode.long_note_bodies.get(i) {
+                    tr.reloc_arc_lock(&n.texture, StringPattern::from(&m_ln_bodies[i]));
+                }
+                if let Some(n) = keymode.long_note_tails.get(i) {
+                    tr.reloc_arc_lock(&n.texture, StringPattern::from(&m_ln_tails[i]));
+                }
+            }
+
+            tr.reloc_arc_lock(&keymode.stage.background, malody_km.get_generic(dynamic_assets::Stage::BACKGROUND, 0));
+            tr.reloc_arc_lock(&keymode.stage.border_right, malody_km.get_generic(dynamic_assets::Stage::BORDER_RIGHT, 0));
+            tr.reloc_arc_lock(&keymode.stage.border_left, malody_km.get_generic(dynamic_assets::Stage::BORDER_LEFT, 0));
+            if keymode.layout.show_judgement_line {
+                tr.reloc_arc_lock(&keymode.judgement_line.texture, malody_km.get_generic(dynamic_assets::Stage::JUDGE_LINE, 0));
+            }
+        }
+
+        if let Some(tex) = join_sheet(&keymode.hit_lighting_normal, &malody_km.get_generic(dynamic_assets::Lighting::HIT, 0), SheetNaming::RowsFirst) {
+            textures.insert(tex);
+        }
+        if let Some(tex) = join_sheet(&keymode.hit_lighting_hold, &malody_km.get_generic(dynamic_assets::Lighting::HOLD, 0), SheetNaming::RowsFirst) {
+            textures.insert(tex);
+        }
+
+        // keymodes the generic skin doesn't have keep their defaults
+        if let Some(existing) = conf_json.keymodes.iter_mut().find(|k| k.keymode == malody_km.keymode) {
+            *existing = malody_km;
+        }
+    }
+
+    let judgement = &skin.gameplay.judgement;
+    for (element, texture) in [("judgement.perfect", &judgement.perfect), ("judgement.bad", &judgement.bad)] {
+        if texture.get_path().is_some_and(|path| path != "blank") {
+            report.dropped(element, "malody only judges best, cool, good and miss");
+        }
+    }
+    for element in ["combo", "rating", "accuracy", "score", "judgement"] {
+        report.dropped(format!("hud.{element}"), "malody positions are not converted");
+    }
+
+    let mut tr = StoreRelocator::new(&mut textures);
+    let mut sr = StoreRelocator::new(&mut samples);
+
+    tr.reloc_arc_lock(&skin.gameplay.health_bar.background, static_assets::Health::BACKGROUND);
+    tr.reloc_arc_lock(&skin.gameplay.health_bar.fill, static_assets::Health::FOREGROUND);
+
+    tr.reloc_arc_lock(&skin.gameplay.judgement.flawless, static_assets::Judgement::BEST);
+    tr.reloc_arc_lock(&skin.gameplay.judgement.great, static_assets::Judgement::COOL);
+    tr.reloc_arc_lock(&skin.gameplay.judgement.good, static_assets::Judgement::GOOD);
+    tr.reloc_arc_lock(&skin.gameplay.judgement.miss, static_assets::Judgement::MISS);
+
+    sr.reloc_str(&skin.sounds.ui.menu_back_click, static_assets::Samples::UI_BACK);
+    sr.reloc_str(&skin.sounds.ui.ui_click, static_assets::Samples::UI_CLICK);
+    sr.reloc_str(&skin.sounds.gameplay.miss, static_assets::Samples::MISS);
+    sr.reloc_str(&skin.sounds.gameplay.fail, static_assets::Samples::FAIL);
+    sr.reloc_str(&skin.sounds.gameplay.restart, static_assets::Samples::RESTART);
+    sr.reloc_str(&skin.sounds.mania.hit, static_assets::Samples::HIT);
+
+    cleanup_stores(&conf_json, Some(&mut textures), Some(&mut samples));
+    options.limit_texture_size(&textures, report);
+    options.process_hit_sound(&mut samples, &static_assets::Samples::HIT, report);
+
+    Ok(MalodySkin::new(conf_json, Some(textures), Some(samples)))
+}
@@ -1,3 +1,7 @@
 pub mod osu;
 pub mod fluxis;
-pub mod quaver;
\ No newline at end of file
+pub mod quaver;
+pub mod etterna;
+pub mod malody;
+pub mod report;
+pub mod options;
@@ -0,0 +1,94 @@
+use fast_image_resize::FilterType;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::audio_proc::SampleProcessing;
+use crate::extensions::TextureArcExt;
+use crate::image_proc::ResizeFilter;
+use crate::image_proc::proc::resize_img;
+use crate::io::Store;
+use crate::report::{ConversionIssueKind, ConversionReport};
+use crate::sample::SampleStore;
+use crate::texture::TextureStore;
+
+/// tunes what the converters do to textures and layout, the defaults are what they always did
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConversionOptions {
+    /// width / height of the screen the osu!mania playfield is placed for
+    pub aspect_ratio: f32,
+    /// used wherever a texture is resized
+    pub filter: ResizeFilter,
+    /// rows with less alpha than this count as empty when measuring how far receptors are from the bottom
+    pub trim_tolerance: f32,
+    /// textures with a side longer than this are downscaled, keeping their ratio
+    pub max_texture_size: Option<u32>,
+    /// osu! draws long note tails upside down
+    pub flip_ln_tails: bool,
+    /// games without snap colouring get the 1/1 colour drawn into the notes instead of the grey base
+    pub bake_snap_colors: bool,
+    /// done to the hit sound of the converted skin
+    pub hit_sound: SampleProcessing,
+}
+
+impl Default for ConversionOptions {
+    fn default() -> Self {
+        Self {
+            aspect_ratio: 16.0 / 9.0,
+            filter: ResizeFilter::Hamming,
+            trim_tolerance: 0.1,
+            max_texture_size: None,
+            flip_ln_tails: true,
+            bake_snap_colors: false,
+            hit_sound: SampleProcessing::default(),
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl ConversionOptions {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ConversionOptions {
+    pub(crate) fn filter_type(&self) -> FilterType {
+        self.filter.into()
+    }
+
+    /// leaves the sample as it was if it can't be processed
+    pub(crate) fn process_hit_sound(&self, samples: &mut SampleStore, path: &str, report: &mut ConversionReport) {
+        if self.hit_sound.is_noop() {
+            return;
+        }
+
+        if let Err(e) = samples.process_sample(path, &self.hit_sound) {
+            report.unprocessed(path, e.to_string());
+        }
+    }
+
+    /// sorted by path so the report comes out the same every time
+    pub(crate) fn limit_texture_size(&self, textures: &TextureStore, report: &mut ConversionReport) {
+        let Some(max) = self.max_texture_size.filter(|max| *max > 0) else { return };
+
+        let mut entries = textures.iter();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        for (_, texture) in entries {
+            let Some((width, height)) = texture.image_ref(|img| img.dimensions()) else { continue };
+            if width <= max && height <= max {
+                continue;
+            }
+
+            let scale = max as f32 / width.max(height) as f32;
+            let new_width = ((width as f32 * scale).round() as u32).clamp(1, max);
+            let new_height = ((height as f32 * scale).round() as u32).clamp(1, max);
+
+            report.track_size(&texture, ConversionIssueKind::Resized, |texture| {
+                texture.image_mut(|img| *img = resize_img(img, new_width, new_height, self.filter_type()));
+            });
+        }
+    }
+}
@@ -1,5 +1,4 @@
 use std::sync::{Arc, RwLock};
-use fast_image_resize::FilterType;
 
 use crate::common::alignment::*;
 use crate::common::color::Rgba;
@@ -11,22 +10,24 @@ use crate::osu::static_assets;
 use crate::generic::Gameplay;
 use crate::image_proc::proc::{dist_from_bottom, flip_vertical, resize_width, rotate_90_deg_ccw, rotate_90_deg_cw};
 use crate::io::Store;
-use crate::io::texture::{Texture, TextureProcessor};
+use crate::io::texture::{Texture, TextureProcessor, TextureStore};
 use crate::osu::{self, General, OsuSkin, OsuSkinIni};
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::{ConversionIssueKind, ConversionReport};
 use crate::skin::generic::layout::{HUDLayout, KeymodeLayout};
 use crate::skin::generic::{elements::*, Keymode, Metadata, GenericManiaSkin};
-use crate::traits::{KeymodeInvariant, ManiaSkinConfig};
-use crate::utils::osu::OsuDimensions;
+use crate::traits::{KeymodeInvariant, LaneFallback, ManiaSkinConfig};
+use crate::utils::osu::{OsuDimensions, get_animation, store_animation};
 use crate::utils::skin::{cleanup_stores, StoreRelocator};
 use crate::{Binary, BinaryArcExt, BinaryArcExtOption, BinaryState, Resources, StringPattern};
 
-pub fn to_generic_mania(skin: &OsuSkin) -> Result<GenericManiaSkin, Box<dyn std::error::Error>> {
+pub fn to_generic_mania(skin: &OsuSkin, options: &ConversionOptions, report: &mut ConversionReport) -> Result<GenericManiaSkin, ConvertError> {
     let mut textures = skin.textures.clone();
     let samples = skin.samples.clone();
     let mut keymodes: Vec<Keymode> = Vec::new();
 
-    textures.insert(Texture::from_blank("blank".to_string()));
-    let blank_texture = textures.get_shared("blank").unwrap();
+    let blank_texture = textures.insert(Texture::from_blank("blank".to_string()));
 
     let metadata = Metadata {
         name: skin.skin_ini.general.name.clone(),
@@ -60,27 +61,28 @@ pub fn to_generic_mania(skin: &OsuSkin) -> Result<GenericManiaSkin, Box<dyn std:
             .map(|(path, fallback_path)| {
                 if !path.is_empty() {
                     if let Some(texture) = textures.get_shared(path) {
-                        let offset = receptor_processor.process_once(&texture, |arc_texture| {
-                            let offset = arc_texture.with_image(|img| dist_from_bottom(img, 0.1));
-                            if let Err(e) = to_osu_column_draw(arc_texture, average_column_width as u32) {
-                                eprintln!("Failed to process receptor texture: {}", e);
-                            }
-                            offset.try_into().unwrap() // TODO: potential panic check later
-                        });
+                        let offset = receptor_processor.try_process_once(&texture, |arc_texture| {
+                            let offset = arc_texture.image_ref(|img| dist_from_bottom(img, options.trim_tolerance))
+                                .ok_or_else(|| not_decoded(arc_texture))?;
+                            report.track_size(arc_texture, ConversionIssueKind::Resized, |arc_texture| {
+                                to_osu_column_draw(arc_texture, average_column_width as u32, options.filter_type())
+                            })?;
+                            receptor_offset(arc_texture, offset)
+                        })?;
                         max_receptor_offset = max_receptor_offset.max(offset);
-                        ReceptorUp::new(Some(texture))
+                        Ok(ReceptorUp::new(Some(texture)))
                     } else {
-                        ReceptorUp::new(Some(Arc::clone(&blank_texture)))
+                        Ok(ReceptorUp::new(Some(Arc::clone(&blank_texture))))
                     }
                 } else {
                     if let Some(fallback) = textures.get_shared(fallback_path) {
-                        ReceptorUp::new(Some(fallback))
+                        Ok(ReceptorUp::new(Some(fallback)))
                     } else { 
-                        ReceptorUp::new(Some(Arc::clone(&blank_texture)))
+                        Ok(ReceptorUp::new(Some(Arc::clone(&blank_texture))))
                     }
                 }
             })
-            .collect();
+            .collect::<Result<_, ConvertError>>()?;
 
         let receptor_down_elements: Vec<ReceptorDown> = keymode.receptor_images_down
             .iter()
@@ -88,27 +90,28 @@ pub fn to_generic_mania(skin: &OsuSkin) -> Result<GenericManiaSkin, Box<dyn std:
             .map(|(path, fallback_path)| {
                 if !path.is_empty() {
                     if let Some(texture) = textures.get_shared(path) {
-                        let offset = receptor_processor.process_once(&texture, |arc_texture| {
-                            let offset = arc_texture.with_image(|img| dist_from_bottom(img, 0.1));
-                            if let Err(e) = to_osu_column_draw(arc_texture, average_column_width as u32) {
-                                eprintln!("Failed to process receptor texture: {}", e);
-                            }
-                            offset.try_into().unwrap()
-                        });
+                        let offset = receptor_processor.try_process_once(&texture, |arc_texture| {
+                            let offset = arc_texture.image_ref(|img| dist_from_bottom(img, options.trim_tolerance))
+                                .ok_or_else(|| not_decoded(arc_texture))?;
+                            report.track_size(arc_texture, ConversionIssueKind::Resized, |arc_texture| {
+                                to_osu_column_draw(arc_texture, average_column_width as u32, options.filter_type())
+                            })?;
+                            receptor_offset(arc_texture, offset)
+                        })?;
                         max_receptor_offset = max_receptor_offset.max(offset);
-                        ReceptorDown::new(Some(texture))
+                        Ok(ReceptorDown::new(Some(texture)))
                     } else {
-                        ReceptorDown::new(Some(Arc::clone(&blank_texture)))
+                        Ok(ReceptorDown::new(Some(Arc::clone(&blank_texture))))
                     }
                 } else {
                     if let Some(fallback) = textures.get_shared(fallback_path) {
-                        ReceptorDown::new(Some(fallback))
+                        Ok(ReceptorDown::new(Some(fallback)))
                     } else {
-                        ReceptorDown::new(Some(Arc::clone(&blank_texture)))
+                        Ok(ReceptorDown::new(Some(Arc::clone(&blank_texture))))
                     }
                 }
             })
-            .collect();
+            .collect::<Result<_, ConvertError>>()?;
 
         let normal_note_elements: Vec<NormalNote> = keymode.normal_note_images
             .iter()
@@ -176,25 +179,49 @@ pub fn to_generic_mania(skin: &OsuSkin) -> Result<GenericManiaSkin, Box<dyn std:
             .map(|(path, fallback_path)| {
                 if !path.is_empty() {
                     if let Some(texture) = textures.get_shared(path) {
-                        tail_processor.process_once_void(&texture, |arc_texture| {
-                            flip_vertical(arc_texture);
-                        });
-                        LongNoteTail::new(Some(texture))
+                        if options.flip_ln_tails {
+                            tail_processor.try_process_once_void(&texture, flip_vertical)?;
+                        }
+                        Ok(LongNoteTail::new(Some(texture)))
                     } else {
-                        LongNoteTail::new(Some(Arc::clone(&blank_texture)))
+                        Ok(LongNoteTail::new(Some(Arc::clone(&blank_texture))))
                     }
                 } else {
                     if let Some(fallback) = textures.get_shared(fallback_path) {
-                        tail_processor.process_once_void(&fallback, |arc_texture| {
-                            flip_vertical(arc_texture);
-                        });
-                        LongNoteTail::new(Some(fallback))
+                        if options.flip_ln_tails {
+                            tail_processor.try_process_once_void(&fallback, flip_vertical)?;
+                        }
+                        Ok(LongNoteTail::new(Some(fallback)))
                     } else {
-                        LongNoteTail::new(Some(Arc::clone(&blank_texture)))
+                        Ok(LongNoteTail::new(Some(Arc::clone(&blank_texture))))
                     }
                 }
             })
-            .collect();
+            .collect::<Result<_, ConvertError>>()?;
+
+        let requested = |paths: &[String], fallback: fn(&LaneFallback) -> &String| -> Vec<String> {
+            paths.iter()
+                .zip(fallbacks.iter())
+                .map(|(path, f)| if path.is_empty() { fallback(f).clone() } else { path.clone() })
+                .collect()
+        };
+
+        report.blank_columns(keymode.keymode, "receptor_up", &receptor_up_elements, &requested(&keymode.receptor_images, |f| &f.receptor));
+        report.blank_columns(keymode.keymode, "receptor_down", &receptor_down_elements, &requested(&keymode.receptor_images_down, |f| &f.receptor_down));
+        report.blank_columns(keymode.keymode, "normal_notes", &normal_note_elements, &requested(&keymode.normal_note_images, |f| &f.normal_note));
+        report.blank_columns(keymode.keymode, "long_note_heads", &long_note_head_elements, &requested(&keymode.long_note_head_images, |f| &f.long_note_head));
+        report.blank_columns(keymode.keymode, "long_note_bodies", &long_note_body_elements, &requested(&keymode.long_note_body_images, |f| &f.long_note_body));
+        report.blank_columns(keymode.keymode, "long_note_tails", &long_note_tail_elements, &requested(&keymode.long_note_tail_images, |f| &f.long_note_tail));
+
+        if keymode.column_line_width.iter().any(|w| *w > 0.0) {
+            report.dropped(format!("{key_count}K.column_line_width"), "the generic skin has no column lines");
+        }
+        if keymode.upside_down {
+            report.dropped(format!("{key_count}K.upside_down"), "the generic skin has no upside down layout");
+        }
+        if keymode.split_stages == Some(true) {
+            report.dropped(format!("{key_count}K.split_stages"), "the generic skin has a single stage");
+        }
 
         let show_judgement_line = keymode.judgement_line;
 
@@ -216,27 +243,9 @@ pub fn to_generic_mania(skin: &OsuSkin) -> Result<GenericManiaSkin, Box<dyn std:
 
         let texture_or_blank = |path: &str| textures.get_shared(path).unwrap_or(blank_texture.clone());
 
-        let get_frames = |fallback_key: &str, prefix: StringPattern| -> Vec<Arc<RwLock<Texture>>> {
-            if let Some(tex) = textures.get_shared(fallback_key) {
-                vec![tex]
-            } else {
-                let prefix_string = prefix.to_string();
-                let (_, mut all_textures): (Vec<String>, Vec<Arc<RwLock<Texture>>>) = textures
-                    .get_shared_all(|t| t.get_path().starts_with(&prefix_string))
-                    .into_iter()
-                    .unzip();
-
-                all_textures.sort_by_key(|arc| {
-                    let path = arc.get_path();
-
-                    path.split('-')
-                        .last()
-                        .and_then(|s| s.parse::<u32>().ok())
-                        .unwrap_or(0)
-                });
-
-                all_textures
-            }
+        let get_frames = |custom: &str, default: StringPattern| {
+            let base = if custom.is_empty() { default.to_string() } else { custom.to_string() };
+            get_animation(&textures, &base)
         };
 
         keymodes.push(Keymode { 
@@ -341,8 +350,7 @@ pub fn to_generic_mania(skin: &OsuSkin) -> Result<GenericManiaSkin, Box<dyn std:
         cursor: Cursor {
             texture: textures.get_shared(&static_assets::Interface::CURSOR)
             .or_else(|| {
-                let bytes = Resources::cursor("lazer_cursor.png") // osu!stable cursor sucks
-                    .expect("Failed to load cursor texture");
+                let bytes = Resources::cursor("lazer_cursor.png")?; // osu!stable cursor sucks
                 let tex = Texture::from_bytes(
                     static_assets::Interface::CURSOR.to_string(),
                     &bytes
@@ -383,11 +391,12 @@ pub fn to_generic_mania(skin: &OsuSkin) -> Result<GenericManiaSkin, Box<dyn std:
     };
 
     generic_skin.ensure_textures();
+    options.limit_texture_size(&generic_skin.textures, report);
     
     Ok(generic_skin)
 }
 
-pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn std::error::Error>> {
+pub fn from_generic_mania(skin: &GenericManiaSkin, options: &ConversionOptions, report: &mut ConversionReport) -> Result<OsuSkin, ConvertError> {
     let mut textures = skin.textures.clone();
     let mut samples = skin.samples.clone();
     let mut osu_keymodes: Vec<osu::Keymode> = Vec::new();
@@ -428,40 +437,50 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn st
             .map(|receptor| {
                 if let Some(texture_arc) = &receptor.texture {
                     if !Arc::ptr_eq(texture_arc, &blank_texture) {
-                        receptor_processor.process_once_void(texture_arc, |arc_texture| {
-                            if let Err(e) = to_osu_column(
-                                arc_texture,
-                                (average_column_width * OsuDimensions::X.as_f32()) as u32,
-                                receptor_offset.clamp(0, OsuDimensions::Y.as_i32()) as u32
-                            ) {
-                                eprintln!("Failed to process receptor up texture: {}", e);
-                            }
-                        });
+                        receptor_processor.try_process_once_void(texture_arc, |arc_texture| {
+                            report.track_size(arc_texture, ConversionIssueKind::Resized, |arc_texture| {
+                                to_osu_column(
+                                    arc_texture,
+                                    (average_column_width * OsuDimensions::X.as_f32()) as u32,
+                                    receptor_offset.clamp(0, OsuDimensions::Y.as_i32()) as u32,
+                                    options.filter_type()
+                                )
+                            })
+                        })?;
                     }
                 }
-                receptor.get_path().unwrap_or_default()
+                Ok(receptor.get_path().unwrap_or_default())
             })
-            .collect();
+            .collect::<Result<_, ConvertError>>()?;
 
         let receptor_images_down: Vec<String> = keymode.receptor_down
             .iter()
             .map(|receptor| {
                 if let Some(texture_arc) = &receptor.texture {
                     if !Arc::ptr_eq(texture_arc, &blank_texture) {
-                        receptor_processor.process_once_void(texture_arc, |arc_texture| {
-                            if let Err(e) = to_osu_column(
-                                arc_texture,
-                                (average_column_width * OsuDimensions::X.as_f32()) as u32,
-                                receptor_offset.clamp(0, OsuDimensions::Y.as_i32()) as u32
-                            ) {
-                                eprintln!("Failed to process receptor down texture: {}", e);
-                            }
-                        });
+                        receptor_processor.try_process_once_void(texture_arc, |arc_texture| {
+                            report.track_size(arc_texture, ConversionIssueKind::Resized, |arc_texture| {
+                                to_osu_column(
+                                    arc_texture,
+                                    (average_column_width * OsuDimensions::X.as_f32()) as u32,
+                                    receptor_offset.clamp(0, OsuDimensions::Y.as_i32()) as u32,
+                                    options.filter_type()
+                                )
+                            })
+                        })?;
                     }
                 }
-                receptor.get_path().unwrap_or_default()
+                Ok(receptor.get_path().unwrap_or_default())
             })
-            .collect();
+            .collect::<Result<_, ConvertError>>()?;
+
+        let baked_note = keymode.normal_notes_snap_colored.as_ref()
+            .filter(|_| options.bake_snap_colors)
+            .and_then(|sheet| bake_first_snap(&mut textures, &sheet.snaps, format!("baked_snap_n_{key_count}k")));
+        let baked_long_head = keymode.long_note_heads_snap_colored.as_ref()
+            .filter(|_| options.bake_snap_colors)
+            .and_then(|sheet| bake_first_snap(&mut textures, &sheet.snaps, format!("baked_snap_h_{key_count}k")))
+            .or(baked_note.clone());
 
         let normal_note_images: Vec<String> = {
             let per_key = keymode.normal_notes
@@ -469,7 +488,9 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn st
                 .map(|n| n.get_path().unwrap_or_default())
                 .collect();
 
-            if use_snap_color && base_note_images.first().is_some_and(|p| p.is_some()) {
+            if let Some(baked) = &baked_note {
+                vec![baked.clone(); key_count as usize]
+            } else if use_snap_color && base_note_images.first().is_some_and(|p| p.is_some()) {
                 base_note_images.iter().map(|p| p.clone().unwrap_or_default()).collect()
             } else {
                 per_key
@@ -491,7 +512,9 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn st
                 })
                 .collect();
 
-            if use_snap_color && base_long_head_images.first().is_some_and(|p| p.is_some()) {
+            if let Some(baked) = &baked_long_head {
+                vec![baked.clone(); key_count as usize]
+            } else if use_snap_color && base_long_head_images.first().is_some_and(|p| p.is_some()) {
                 base_long_head_images.iter().map(|p| p.clone().unwrap_or_default()).collect()
             
             } else if use_snap_color && base_note_images.first().is_some_and(|p| p.is_some()) {
@@ -506,19 +529,34 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn st
             .map(|note| note.get_path().unwrap_or_default())
             .collect();
 
+        report.dropped_columns(key_count, "normal_mines", &keymode.normal_mines, "osu!mania has no mines");
+        report.dropped_snap_sheets(keymode, "osu!mania has no snap colouring, the per column notes are used");
+        report.dropped_element(format!("{key_count}K.judgement_line"), &keymode.judgement_line, "osu!mania only has a toggle for its own judgement line");
+
+        let lighting_n = store_animation(&mut textures, &keymode.hit_lighting_normal.frames).unwrap_or_default();
+        let lighting_l = store_animation(&mut textures, &keymode.hit_lighting_hold.frames).unwrap_or_default();
+
+        let light_fps = keymode.hit_lighting_normal.fps.or(keymode.hit_lighting_hold.fps);
+        if let (Some(normal_fps), Some(hold_fps)) = (keymode.hit_lighting_normal.fps, keymode.hit_lighting_hold.fps)
+            && normal_fps != hold_fps
+            && keymode.hit_lighting_hold.frames.len() > 1
+        {
+            report.approximated(format!("{key_count}K.hit_lighting_hold"), format!("osu!mania has one frame rate for both lightings, {normal_fps} fps is used"));
+        }
+
         let long_note_tail_images: Vec<String> = keymode.long_note_tails
             .iter()
             .map(|note| {
                 if let Some(texture_arc) = &note.texture {
                     if !Arc::ptr_eq(texture_arc, &blank_texture) {
-                        tail_processor.process_once_void(texture_arc, |arc_texture| {
-                            flip_vertical(arc_texture);
-                        });
+                        if options.flip_ln_tails {
+                            tail_processor.try_process_once_void(texture_arc, flip_vertical)?;
+                        }
                     }
                 }
-                note.get_path().unwrap_or_default()
+                Ok(note.get_path().unwrap_or_default())
             })
-            .collect();
+            .collect::<Result<_, ConvertError>>()?;
 
         if let Some(bg_arc) = &skin.gameplay.health_bar.background {
             if let Some(health_bar_bg) = bg_arc.get_image() {
@@ -563,7 +601,7 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn st
 
         if !textures.contains(&static_assets::Interface::CURSOR) {
             if let Some(cursor_arc) = &skin.ui.cursor.texture {
-                resize_width(cursor_arc, 24, FilterType::Hamming)?;
+                resize_width(cursor_arc, 24, options.filter_type())?;
             }
 
             if let Some(cursor_image) = skin.ui.cursor.texture.clone_data() {
@@ -584,16 +622,16 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn st
         sr.reloc_str(&skin.sounds.gameplay.restart, static_assets::Samples::PAUSE_RETRY_CLICK);
         sr.reloc_str(&skin.sounds.mania.hit, static_assets::Samples::DRUM_HITNORMAL);
 
-        // we'll assume that the size of the screen is 16:9 since that's most common
-        // osu!mania playfield positions depends on your screen ratio
-        let reference_size = Vector2::new(1920f32, 1080f32);
-        let aspect_ratio = reference_size.x / reference_size.y;
+        // osu!mania playfield positions depends on your screen ratio, 16:9 unless told otherwise
+        let aspect_ratio = options.aspect_ratio;
         let stage_width = (keymode.layout.column_widths.iter().sum::<f32>() + (keymode.layout.column_spacing.iter().sum::<f32>())) * OsuDimensions::X.as_f32() * OsuDimensions::ColumnScaleFromGeneric.as_f32();
         let playfield_pos = (OsuDimensions::Y.as_f32() * aspect_ratio - stage_width.round()) * keymode.layout.x_offset;
+        // a centred stage stays centred, any other offset only lands right on the targeted screen
+        if keymode.layout.x_offset != 0.5 {
+            report.approximated(format!("{key_count}K.x_offset"), format!("column start is calculated for a {aspect_ratio:.2}:1 screen"));
+        }
 
-        // TODO: add animated assets to osu
-
-        let osu_keymode = osu::Keymode {
+        let mut osu_keymode = osu::Keymode {
             keymode: key_count,
             keys_under_notes: !keymode.layout.receptor_above_notes,
             hit_position: ((1.0 - keymode.layout.hit_position) * OsuDimensions::Y.as_f32()) as u32,
@@ -610,8 +648,8 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn st
             long_note_head_images,
             long_note_body_images,
             long_note_tail_images,
-            lighting_n: keymode.hit_lighting_normal.get_path().unwrap_or_default(),
-            lighting_l: keymode.hit_lighting_hold.get_path().unwrap_or_default(),
+            lighting_n,
+            lighting_l,
             stage_light: keymode.column_lighting.texture.get_path().unwrap_or_default(),
             stage_right: keymode.stage.border_right.get_path().unwrap_or_default(),
             stage_left: keymode.stage.border_left.get_path().unwrap_or_default(),
@@ -625,16 +663,33 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn st
             ..Default::default()
         };
 
+        if let Some(fps) = light_fps {
+            osu_keymode.light_frame_per_second = fps.round() as u32;
+        }
+
         osu_keymodes.push(osu_keymode);
     }
     
     let mut skin_ini = OsuSkinIni {
         general,
         keymodes: osu_keymodes,
+        ..Default::default()
     };
 
     let osu_dimensions = Vector2::new(OsuDimensions::X.into(), OsuDimensions::Y.into());
 
+    let hud = &skin.gameplay.layout;
+    for (element, placement) in [("score", &hud.score), ("accuracy", &hud.accuracy), ("rating", &hud.rating)] {
+        if HUDLayout::is_placed(placement) {
+            report.dropped(format!("hud.{element}"), "osu!mania places it on its own");
+        }
+    }
+    for (element, placement) in [("combo", &hud.combo), ("judgement", &hud.judgement)] {
+        if HUDLayout::is_placed(placement) {
+            report.approximated(format!("hud.{element}"), "only the vertical position is kept");
+        }
+    }
+
     for keymode in &mut skin_ini.keymodes {
 
         let score_size = Vector2::new(100.0, 50.0);
@@ -657,6 +712,25 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<OsuSkin, Box<dyn st
     }
 
     cleanup_stores(&skin_ini, Some(&mut textures), Some(&mut samples));
+    options.limit_texture_size(&textures, report);
+    options.process_hit_sound(&mut samples, &static_assets::Samples::DRUM_HITNORMAL, report);
     
     Ok(OsuSkin::new(skin_ini, Some(textures), Some(samples)))
+}
+
+/// the 1/1 frame of a snap coloured sheet, osu!mania has no snap colouring so it is used for every column
+fn bake_first_snap(textures: &mut TextureStore, snaps: &[Arc<RwLock<Texture>>], name: String) -> Option<String> {
+    let image = snaps.first()?.clone_image()?;
+    Some(textures.copy_from_data(&name, BinaryState::Loaded(image)))
+}
+
+fn not_decoded(texture: &Arc<RwLock<Texture>>) -> ConvertError {
+    ConvertError::ImageProcessing { path: texture.get_path(), reason: "texture could not be decoded".to_string() }
+}
+
+/// the transparent rows under a receptor as an osu!mania offset
+fn receptor_offset(texture: &Arc<RwLock<Texture>>, offset: u32) -> Result<i32, ConvertError> {
+    i32::try_from(offset).map_err(|_| ConvertError::InvalidLayout {
+        reason: format!("'{}' has {offset} blank rows, too many to offset", texture.get_path()),
+    })
 }
\ No newline at end of file
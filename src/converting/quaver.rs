@@ -1,6 +1,5 @@
 use std::sync::{Arc, RwLock};
 
-use fast_image_resize::FilterType;
 use image::RgbaImage;
 
 use crate::common::skin::AssetAttribute;
@@ -23,13 +22,16 @@ use crate::generic::{Gameplay, UI};
 use crate::image_proc::proc::{concat_into_sheet, dist_from_bottom, resize_img, rotate_90_deg_ccw, trim_image_vertical};
 use crate::io::texture::TextureProcessor;
 use crate::io::Store;
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::{ConversionIssueKind, ConversionReport};
 use crate::skin::generic::{GenericManiaSkin, Keymode, Metadata};
 use crate::skin::quaver::skin::QuaSkin;
 use crate::skin::quaver::QuaSkinIni;
 use crate::traits::{KeymodeInvariant, ManiaSkin};
 use crate::utils::skin::{StoreRelocator, cleanup_stores};
 
-pub fn to_generic_mania(skin: &QuaSkin) -> Result<GenericManiaSkin, Box<dyn std::error::Error>> {
+pub fn to_generic_mania(skin: &QuaSkin, options: &ConversionOptions, report: &mut ConversionReport) -> Result<GenericManiaSkin, ConvertError> {
     let mut textures = skin.textures.clone();
     let samples = skin.samples.clone();
     let mut keymodes: Vec<Keymode> = Vec::new();
@@ -38,8 +40,7 @@ pub fn to_generic_mania(skin: &QuaSkin) -> Result<GenericManiaSkin, Box<dyn std:
     let mut skin_ini  = skin.skin_ini.clone();
     skin_ini.sync_from_shared();
 
-    textures.insert(Texture::from_blank("blank".to_string()));
-    let blank_texture = textures.get_shared("blank").unwrap();
+    let blank_texture = textures.insert(Texture::from_blank("blank".to_string()));
 
     let metadata = Metadata {
         name: skin_ini.general.name.clone(),
@@ -152,13 +153,15 @@ pub fn to_generic_mania(skin: &QuaSkin) -> Result<GenericManiaSkin, Box<dyn std:
                 let fallback_path = receptor_up_fallbacks.get(i).and_then(|f| f.as_deref());
                 if let Some(texture) = resolver.get_texture_opt(path, fallback_path) {
                     let offset = receptor_processor.process_once(&texture, |arc| {
-                        let off = arc.with_image(|img| {
-                            dist_from_bottom(img, 0.1)
-                        }).try_into().unwrap_or(0);
-
-                        arc.data_mut(|img| {
-                            let trimmed = trim_image_vertical(img, 0.2);
-                            *img = trimmed;
+                        let off = arc.image_ref(|img| dist_from_bottom(img, options.trim_tolerance))
+                            .and_then(|off| off.try_into().ok())
+                            .unwrap_or(0);
+
+                        report.track_size(arc, ConversionIssueKind::Trimmed, |arc| {
+                            arc.data_mut(|img| {
+                                let trimmed = trim_image_vertical(img, 0.2);
+                                *img = trimmed;
+                            });
                         });
 
                         off
@@ -179,13 +182,15 @@ pub fn to_generic_mania(skin: &QuaSkin) -> Result<GenericManiaSkin, Box<dyn std:
                 let fallback_path = receptor_down_fallbacks.get(i).and_then(|f| f.as_deref());
                 if let Some(texture) = resolver.get_texture_opt(path, fallback_path) {
                     let offset = receptor_processor.process_once(&texture, |arc| {
-                        let off = arc.with_image(|img| {
-                            dist_from_bottom(img, 0.1)
-                        }).try_into().unwrap_or(0);
-
-                        arc.data_mut(|img| {
-                            let trimmed = trim_image_vertical(img, 0.2);
-                            *img = trimmed;
+                        let off = arc.image_ref(|img| dist_from_bottom(img, options.trim_tolerance))
+                            .and_then(|off| off.try_into().ok())
+                            .unwrap_or(0);
+
+                        report.track_size(arc, ConversionIssueKind::Trimmed, |arc| {
+                            arc.data_mut(|img| {
+                                let trimmed = trim_image_vertical(img, 0.2);
+                                *img = trimmed;
+                            });
                         });
 
                         off
@@ -250,6 +255,12 @@ pub fn to_generic_mania(skin: &QuaSkin) -> Result<GenericManiaSkin, Box<dyn std:
             })
             .collect();
 
+        report.blank_columns(keymode.keymode, "receptor_up", &receptor_up_elements, &receptors);
+        report.blank_columns(keymode.keymode, "receptor_down", &receptor_down_elements, &receptors_down);
+        report.blank_columns(keymode.keymode, "normal_notes", &normal_note_elements, &normal_notes);
+        report.blank_columns(keymode.keymode, "long_note_bodies", &long_note_body_elements, &long_note_bodies);
+        report.blank_columns(keymode.keymode, "long_note_tails", &long_note_tail_elements, &long_note_tails);
+
         let layout = KeymodeLayout {
             keymode: key_count as u8,
             receptor_above_notes: keymode.receptors_over_hit_objects,
@@ -320,14 +331,15 @@ pub fn to_generic_mania(skin: &QuaSkin) -> Result<GenericManiaSkin, Box<dyn std:
         });
     }
 
-    let default_keymode = skin.get_keymode(4).unwrap_or(skin.skin_ini.keymodes.first().unwrap());
+    let default_keymode = skin.get_keymode(4)
+        .or(skin.skin_ini.keymodes.first())
+        .ok_or(ConvertError::MissingKeymode { keymode: 4 })?;
 
     let ui = UI {
         cursor: Cursor {
             texture: textures.get_shared(&static_assets::Cursor::MAIN_CURSOR)
             .or_else(|| {
-                let bytes = Resources::cursor("qua_cursor.png") // osu!stable cursor sucks
-                    .expect("Failed to load cursor texture");
+                let bytes = Resources::cursor("qua_cursor.png")?; // osu!stable cursor sucks
                 let tex = Texture::from_bytes(
                     static_assets::Cursor::MAIN_CURSOR.to_string(),
                     &bytes
@@ -405,11 +417,12 @@ pub fn to_generic_mania(skin: &QuaSkin) -> Result<GenericManiaSkin, Box<dyn std:
     };
 
     generic_skin.ensure_textures();
+    options.limit_texture_size(&generic_skin.textures, report);
 
     Ok(generic_skin)
 }
 
-pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<QuaSkin, Box<dyn std::error::Error>> {
+pub fn from_generic_mania(skin: &GenericManiaSkin, options: &ConversionOptions, report: &mut ConversionReport) -> Result<QuaSkin, ConvertError> {
     let mut textures = skin.textures.clone();
     let mut samples = skin.samples.clone();
     
@@ -432,6 +445,20 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<QuaSkin, Box<dyn st
         qua_km.hit_pos_offset_y = (qua_km.column_size as f32 - (keymode.layout.hit_position * QuaDimensions::Y.as_f32())).abs() as i32;
         qua_km.health_bar_type = HealthBarType::Vertical;
 
+        let key_count = keymode.keymode;
+        let widths = &keymode.layout.column_widths;
+        if widths.iter().any(|w| (w - widths[0]).abs() > f32::EPSILON) {
+            report.approximated(format!("{key_count}K.column_widths"), format!("quaver has one column size, {} is used for every column", qua_km.column_size));
+        }
+        if keymode.layout.column_spacing.iter().any(|s| *s != 0.0) {
+            report.dropped(format!("{key_count}K.column_spacing"), "quaver has no column spacing");
+        }
+        report.dropped_element(format!("{key_count}K.column_lighting"), &keymode.column_lighting, "not converted to quaver");
+        report.dropped_element(format!("{key_count}K.judgement_line"), &keymode.judgement_line, "quaver has no judgement line");
+        report.dropped_element(format!("{key_count}K.hit_lighting_hold"), &keymode.hit_lighting_hold, "not converted to quaver");
+        report.dropped_columns(key_count, "normal_mines", &keymode.normal_mines, "not converted to quaver");
+        report.dropped_snap_sheets(keymode, "not converted to quaver, the per column notes are used");
+
         let q_receptors = qua_km.get_receptors();
         let q_receptors_down = qua_km.get_receptors_down();
         let q_normal_notes = qua_km.get_normal_notes();
@@ -462,18 +489,20 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<QuaSkin, Box<dyn st
                     if let Some(texture_arc) = &n.texture
                     {
                         body_processor.process_once_void(texture_arc, |arc_texture| {
-                            arc_texture.data_mut(|img| {
-                                let (width, height) = img.dimensions();
-                                let max_res = QuaDimensions::MaxResolution.as_u32();
-                                
-                                if width > max_res || height > max_res {
-                                    *img = resize_img(
-                                        img,
-                                        width.min(max_res),
-                                        height.min(max_res),
-                                        FilterType::Hamming
-                                    );
-                                }
+                            report.track_size(arc_texture, ConversionIssueKind::Resized, |arc_texture| {
+                                arc_texture.data_mut(|img| {
+                                    let (width, height) = img.dimensions();
+                                    let max_res = QuaDimensions::MaxResolution.as_u32();
+                                    
+                                    if width > max_res || height > max_res {
+                                        *img = resize_img(
+                                            img,
+                                            width.min(max_res),
+                                            height.min(max_res),
+                                            options.filter_type()
+                                        );
+                                    }
+                                });
                             });
                         });
                     }
@@ -490,7 +519,7 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<QuaSkin, Box<dyn st
 
                 let mut sprites: Vec<&RgbaImage> = sprites_vec
                     .iter()
-                    .map(|g| g.state().as_loaded().expect("Sprite not loaded"))
+                    .filter_map(|g| g.state().as_loaded())
                     .collect();
 
                 if sprites.len() == 1 {
@@ -522,6 +551,10 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<QuaSkin, Box<dyn st
         qua_keymodes.push(qua_km);
     }
 
+    for element in ["combo", "rating", "accuracy", "score", "judgement"] {
+        report.dropped(format!("hud.{element}"), "quaver positions are not converted");
+    }
+
     let mut tr = StoreRelocator::new(&mut textures);
     let mut sr = StoreRelocator::new(&mut samples);
 
@@ -549,6 +582,8 @@ pub fn from_generic_mania(skin: &GenericManiaSkin) -> Result<QuaSkin, Box<dyn st
     skin_ini.keymodes = qua_keymodes;
 
     cleanup_stores(&skin_ini, Some(&mut textures), Some(&mut samples));
+    options.limit_texture_size(&textures, report);
+    options.process_hit_sound(&mut samples, &static_assets::Sfx::HIT, report);
 
     Ok(QuaSkin::new(
         skin_ini,
@@ -0,0 +1,209 @@
+use std::fmt;
+use std::sync::{Arc, RwLock};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::BinaryArcExt;
+use crate::extensions::TextureArcExt;
+use crate::generic::elements::SkinElement;
+use crate::skin::generic::Keymode;
+use crate::texture::Texture;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConversionIssueKind {
+    /// the target game has nowhere to put it
+    Dropped,
+    /// the texture was not found and `blank` is used instead
+    BlankSubstituted,
+    /// a layout value the target game only has a rougher version of
+    Approximated,
+    Resized,
+    Trimmed,
+    /// processing was asked for but the sample couldn't be decoded, so it's left as it was
+    Unprocessed,
+}
+
+impl ConversionIssueKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConversionIssueKind::Dropped => "dropped",
+            ConversionIssueKind::BlankSubstituted => "blank",
+            ConversionIssueKind::Approximated => "approximated",
+            ConversionIssueKind::Resized => "resized",
+            ConversionIssueKind::Trimmed => "trimmed",
+            ConversionIssueKind::Unprocessed => "unprocessed",
+        }
+    }
+}
+
+/// one thing that did not survive the conversion as it was
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConversionIssue {
+    pub kind: ConversionIssueKind,
+    /// e.g. `7K.normal_mines` or `textures/mania-key1`
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub element: String,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub detail: String,
+}
+
+impl fmt::Display for ConversionIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.kind.as_str(), self.element, self.detail)
+    }
+}
+
+/// everything a converter lost or changed, in the order it happened
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConversionReport {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub issues: Vec<ConversionIssue>,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl ConversionReport {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = isEmpty))]
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = ofKind))]
+    pub fn of_kind(&self, kind: ConversionIssueKind) -> Vec<ConversionIssue> {
+        self.issues.iter().filter(|i| i.kind == kind).cloned().collect()
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
+    pub fn to_string_wasm(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ConversionReport {
+    pub fn len(&self) -> usize {
+        self.issues.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, ConversionIssue> {
+        self.issues.iter()
+    }
+
+    pub(crate) fn push(&mut self, kind: ConversionIssueKind, element: impl Into<String>, detail: impl Into<String>) {
+        self.issues.push(ConversionIssue { kind, element: element.into(), detail: detail.into() });
+    }
+
+    pub(crate) fn dropped(&mut self, element: impl Into<String>, detail: impl Into<String>) {
+        self.push(ConversionIssueKind::Dropped, element, detail);
+    }
+
+    /// `path` is what the skin asked for
+    pub(crate) fn blank(&mut self, element: impl Into<String>, path: &str) {
+        self.push(ConversionIssueKind::BlankSubstituted, element, format!("'{path}' not found"));
+    }
+
+    pub(crate) fn approximated(&mut self, element: impl Into<String>, detail: impl Into<String>) {
+        self.push(ConversionIssueKind::Approximated, element, detail);
+    }
+
+    pub(crate) fn trimmed(&mut self, path: &str, detail: impl Into<String>) {
+        self.push(ConversionIssueKind::Trimmed, format!("textures/{path}"), detail);
+    }
+
+    pub(crate) fn unprocessed(&mut self, path: &str, detail: impl Into<String>) {
+        self.push(ConversionIssueKind::Unprocessed, format!("samples/{path}"), detail);
+    }
+}
+
+/// helpers for the converters, they only record something when it actually changes the skin
+impl ConversionReport {
+    /// `element` is dropped if it draws anything other than `blank`
+    pub(crate) fn dropped_element<T: SkinElement>(&mut self, element: impl Into<String>, value: &T, why: &str) {
+        if is_drawn(value) {
+            self.dropped(element, why);
+        }
+    }
+
+    /// one entry for a whole per column list, only if any of its columns draws something
+    pub(crate) fn dropped_columns<T: SkinElement>(&mut self, keymode: u8, field: &str, values: &[T], why: &str) {
+        if values.iter().any(is_drawn) {
+            self.dropped(format!("{keymode}K.{field}"), why);
+        }
+    }
+
+    /// the snap coloured note, hold and mine sheets
+    pub(crate) fn dropped_snap_sheets(&mut self, keymode: &Keymode, why: &str) {
+        let n = keymode.keymode;
+        if let Some(sheet) = &keymode.normal_notes_snap_colored {
+            self.dropped_element(format!("{n}K.normal_notes_snap_colored"), sheet, why);
+        }
+        if let Some(sheet) = &keymode.long_note_heads_snap_colored {
+            self.dropped_element(format!("{n}K.long_note_heads_snap_colored"), sheet, why);
+        }
+        if let Some(sheet) = &keymode.normal_mines_snap_colored {
+            self.dropped_element(format!("{n}K.normal_mines_snap_colored"), sheet, why);
+        }
+    }
+
+    /// columns that ended up as `blank`, `requested` is the path each column asked for
+    pub(crate) fn blank_columns<T: SkinElement>(&mut self, keymode: u8, field: &str, values: &[T], requested: &[String]) {
+        for (i, value) in values.iter().enumerate() {
+            if !is_drawn(value) {
+                let path = requested.get(i).map(String::as_str).unwrap_or_default();
+                self.blank(format!("{keymode}K.{field}[{i}]"), path);
+            }
+        }
+    }
+
+    /// runs `process` and records the texture as `kind` if that changed its size
+    pub(crate) fn track_size<R>(
+        &mut self,
+        texture: &Arc<RwLock<Texture>>,
+        kind: ConversionIssueKind,
+        process: impl FnOnce(&Arc<RwLock<Texture>>) -> R,
+    ) -> R {
+        let before = texture.image_ref(|img| img.dimensions());
+        let result = process(texture);
+        let after = texture.image_ref(|img| img.dimensions());
+
+        if let (Some((width, height)), Some((new_width, new_height))) = (before, after)
+            && (width, height) != (new_width, new_height)
+        {
+            self.push(
+                kind,
+                format!("textures/{}", texture.get_path()),
+                format!("{width}x{height} to {new_width}x{new_height}"),
+            );
+        }
+        result
+    }
+}
+
+/// `blank` and missing textures draw nothing
+pub(crate) fn is_drawn<T: SkinElement>(element: &T) -> bool {
+    element.get_path().is_some_and(|path| !path.is_empty() && path != "blank")
+}
+
+impl fmt::Display for ConversionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a ConversionReport {
+    type Item = &'a ConversionIssue;
+    type IntoIter = std::slice::Iter<'a, ConversionIssue>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.issues.iter()
+    }
+}
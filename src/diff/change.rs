@@ -0,0 +1,133 @@
+use std::fmt;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl ChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Modified => "modified",
+        }
+    }
+}
+
+/// one field, texture or sample that is different in the second value
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// e.g. `General.Name`, `4K.normal_notes[2]` or `textures/mania-key1`
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub element: String,
+    /// `None` when it was added, or for a config key that wasn't written because it was at its default
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub before: Option<String>,
+    /// `None` when it was removed or went back to its default
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub after: Option<String>,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quoted = |value: &Option<String>| value.as_ref().map_or_else(|| "default".to_string(), |v| format!("'{v}'"));
+
+        write!(f, "{} {}", self.kind.as_str(), self.element)?;
+        match (self.kind, &self.before, &self.after) {
+            (ChangeKind::Modified, before, after) => write!(f, ": {} -> {}", quoted(before), quoted(after)),
+            (_, Some(value), _) | (_, None, Some(value)) => write!(f, ": '{value}'"),
+            (_, None, None) => Ok(()),
+        }
+    }
+}
+
+/// everything that is different between two values, in the order the fields appear
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SkinDiff {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub changes: Vec<Change>,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl SkinDiff {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = isEmpty))]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = ofKind))]
+    pub fn of_kind(&self, kind: ChangeKind) -> Vec<Change> {
+        self.changes.iter().filter(|c| c.kind == kind).cloned().collect()
+    }
+
+    /// the change for `element` if there is one
+    pub fn get(&self, element: &str) -> Option<Change> {
+        self.changes.iter().find(|c| c.element == element).cloned()
+    }
+
+    /// `[{ "kind": "modified", "element": ..., "before": ..., "after": ... }, ...]`
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toJson))]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.changes).unwrap_or_default()
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
+    pub fn to_string_wasm(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl SkinDiff {
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Change> {
+        self.changes.iter()
+    }
+
+    pub(crate) fn push(&mut self, kind: ChangeKind, element: impl Into<String>, before: Option<String>, after: Option<String>) {
+        self.changes.push(Change { kind, element: element.into(), before, after });
+    }
+
+    pub(crate) fn extend(&mut self, other: SkinDiff) {
+        self.changes.extend(other.changes);
+    }
+}
+
+impl fmt::Display for SkinDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, change) in self.changes.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a SkinDiff {
+    type Item = &'a Change;
+    type IntoIter = std::slice::Iter<'a, Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.iter()
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::diff::fields::{Field, Fields, diff_fields};
+use crate::diff::merge::{Merged, Side, merge_fields, merged_fields, pick};
+use crate::diff::{ChangeKind, Diff, MergeConflict, SkinDiff, ThreeWayMerge};
+use crate::error::ThreeWayMergeError;
+use crate::fluxis::SkinJson;
+use crate::ini::{IniDocument, IniSection};
+use crate::malody::ConfJson;
+use crate::osu::OsuSkinIni;
+use crate::quaver::QuaSkinIni;
+
+/// `Section.Key`, osu's `[Mania]` sections are named after their key count instead, e.g. `4K.ColumnWidth`
+pub(crate) fn osu_ini_fields(skin_ini: &OsuSkinIni) -> Fields {
+    // written without the file it came from, so formatting, comments and unknown keys don't count
+    let mut clean = skin_ini.clone();
+    clean.source = None;
+
+    ini_fields(&clean.to_string(), |section| match section.get("Keys") {
+        Some(keys) if section.name.eq_ignore_ascii_case("Mania") => format!("{}K", keys.trim()),
+        _ => section.name.clone(),
+    })
+}
+
+/// `Section.Key`, the keymode sections are already called `4K` and so on
+pub(crate) fn quaver_ini_fields(skin_ini: &QuaSkinIni) -> Fields {
+    let mut clean = skin_ini.clone();
+    clean.source = None;
+
+    ini_fields(&clean.to_string(), |section| section.name.clone())
+}
+
+/// object keys joined with `.` and array items as `[i]`, e.g. `4k.receptors[0]`
+pub(crate) fn json_fields(json: &str) -> Fields {
+    let mut fields = Fields::new();
+    if let Ok(value) = serde_json::from_str::<Value>(json) {
+        flatten_json(&value, String::new(), &mut fields);
+    }
+    fields
+}
+
+/// the typed configs leave out keys at their default, so a key only one side has in a section both
+/// have is a change from or to the default rather than something added or removed
+pub(crate) fn diff_config_fields(before: &Fields, after: &Fields) -> SkinDiff {
+    let sections = |fields: &Fields| -> HashSet<String> { fields.keys().map(|e| section_of(e).to_string()).collect() };
+    let (before_sections, after_sections) = (sections(before), sections(after));
+
+    let mut diff = diff_fields(before, after);
+    for change in &mut diff.changes {
+        let other_sections = match change.kind {
+            ChangeKind::Added => &before_sections,
+            ChangeKind::Removed => &after_sections,
+            ChangeKind::Modified => continue,
+        };
+        if other_sections.contains(section_of(&change.element)) {
+            change.kind = ChangeKind::Modified;
+        }
+    }
+    diff
+}
+
+fn section_of(element: &str) -> &str {
+    element.split(['.', '[']).next().unwrap_or(element)
+}
+
+fn ini_fields(ini: &str, section_name: impl Fn(&IniSection) -> String) -> Fields {
+    let mut fields = Fields::new();
+
+    for section in &IniDocument::parse(ini).sections {
+        let name = section_name(section);
+        for entry in section.entries() {
+            fields.insert(format!("{name}.{}", entry.key), Field::value(&entry.value));
+        }
+    }
+
+    fields
+}
+
+/// `fields` written as an ini again, keys of the same section end up together under `header(section)`
+fn render_ini<'a>(fields: &'a Fields, separator: &str, header: impl Fn(&'a str) -> &'a str) -> String {
+    let mut sections: IndexMap<&str, Vec<(&str, &str)>> = IndexMap::new();
+    for (element, field) in fields {
+        let Some((section, key)) = element.split_once('.') else { continue };
+        sections.entry(section).or_default().push((key, &field.shown));
+    }
+
+    let mut ini = String::new();
+    for (section, entries) in sections {
+        ini.push_str(&format!("[{}]\n", header(section)));
+        for (key, value) in entries {
+            ini.push_str(&format!("{key}{separator}{value}\n"));
+        }
+        ini.push('\n');
+    }
+    ini
+}
+
+fn merge_ini_fields(base: &Fields, ours: &Fields, theirs: &Fields, take_theirs: &[String]) -> (Fields, Vec<MergeConflict>) {
+    let (sides, conflicts) = merge_fields(base, ours, theirs, take_theirs);
+    (merged_fields(&sides, ours, theirs), conflicts)
+}
+
+fn json_str(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// objects and equally long arrays that both sides changed are merged member by member, so the
+/// conflicts are named like the fields in `json_fields`
+fn merge_json(
+    path: &str,
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    take_theirs: &[String],
+    conflicts: &mut Vec<MergeConflict>,
+) -> Option<Value> {
+    match pick(base, ours, theirs) {
+        Some(Side::Ours) => return ours.cloned(),
+        Some(Side::Theirs) => return theirs.cloned(),
+        None => {}
+    }
+
+    let child = |key: &str| if path.is_empty() { key.to_string() } else { format!("{path}.{key}") };
+
+    match (base, ours, theirs) {
+        (None | Some(Value::Object(_)), Some(Value::Object(o)), Some(Value::Object(t))) => {
+            let b = base.and_then(Value::as_object);
+            let mut merged = serde_json::Map::new();
+
+            for key in o.keys().chain(t.keys()).chain(b.into_iter().flat_map(|b| b.keys())) {
+                if merged.contains_key(key) {
+                    continue;
+                }
+                let value = merge_json(&child(key), b.and_then(|b| b.get(key)), o.get(key), t.get(key), take_theirs, conflicts);
+                if let Some(value) = value {
+                    merged.insert(key.clone(), value);
+                }
+            }
+            Some(Value::Object(merged))
+        }
+        (None | Some(Value::Array(_)), Some(Value::Array(o)), Some(Value::Array(t)))
+            if o.len() == t.len() && base.and_then(Value::as_array).is_none_or(|b| b.len() == o.len()) =>
+        {
+            let b = base.and_then(Value::as_array);
+            let merged = o.iter().zip(t)
+                .enumerate()
+                .filter_map(|(i, (o, t))| merge_json(&format!("{path}[{i}]"), b.map(|b| &b[i]), Some(o), Some(t), take_theirs, conflicts))
+                .collect();
+            Some(Value::Array(merged))
+        }
+        _ => {
+            conflicts.push(MergeConflict {
+                element: path.to_string(),
+                base: base.map(json_str),
+                ours: ours.map(json_str),
+                theirs: theirs.map(json_str),
+            });
+            if take_theirs.iter().any(|e| e == path) { theirs.cloned() } else { ours.cloned() }
+        }
+    }
+}
+
+/// merges the configs as json and reads the result back
+fn merge_json_config<T>(ours: &T, base: &T, theirs: &T, take_theirs: &[String]) -> Merged<T>
+where
+    T: ToString + FromStr,
+    T::Err: fmt::Display,
+{
+    let parse = |config: &T, side: &'static str| {
+        serde_json::from_str::<Value>(&config.to_string())
+            .map_err(|e| ThreeWayMergeError::InvalidJson { side, reason: e.to_string() })
+    };
+    let (base, ours, theirs) = (parse(base, "base")?, parse(ours, "ours")?, parse(theirs, "theirs")?);
+    let mut conflicts = Vec::new();
+
+    let merged = merge_json("", Some(&base), Some(&ours), Some(&theirs), take_theirs, &mut conflicts).unwrap_or_default();
+    let merged = T::from_str(&merged.to_string()).map_err(|e| ThreeWayMergeError::Reparse { reason: e.to_string() })?;
+    Ok((merged, conflicts))
+}
+
+fn flatten_json(value: &Value, path: String, fields: &mut Fields) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+                flatten_json(value, path, fields);
+            }
+        }
+        Value::Array(items) => {
+            for (i, value) in items.iter().enumerate() {
+                flatten_json(value, format!("{path}[{i}]"), fields);
+            }
+        }
+        Value::String(s) => { fields.insert(path, Field::value(s)); }
+        other => { fields.insert(path, Field::value(other)); }
+    }
+}
+
+impl Diff for OsuSkinIni {
+    fn diff(&self, other: &Self) -> SkinDiff {
+        diff_config_fields(&osu_ini_fields(self), &osu_ini_fields(other))
+    }
+}
+
+impl Diff for QuaSkinIni {
+    fn diff(&self, other: &Self) -> SkinDiff {
+        diff_config_fields(&quaver_ini_fields(self), &quaver_ini_fields(other))
+    }
+}
+
+impl Diff for SkinJson {
+    fn diff(&self, other: &Self) -> SkinDiff {
+        diff_config_fields(&json_fields(&self.to_string()), &json_fields(&other.to_string()))
+    }
+}
+
+impl Diff for ConfJson {
+    fn diff(&self, other: &Self) -> SkinDiff {
+        diff_config_fields(&json_fields(&self.to_string()), &json_fields(&other.to_string()))
+    }
+}
+
+// the merged fields are written out and parsed again, the result keeps ours' source so only the
+// lines that differ from ours are rewritten when it's saved
+impl ThreeWayMerge for OsuSkinIni {
+    fn three_way_merge_with(&self, base: &Self, theirs: &Self, take_theirs: &[String]) -> Merged<Self> {
+        let (fields, conflicts) = merge_ini_fields(&osu_ini_fields(base), &osu_ini_fields(self), &osu_ini_fields(theirs), take_theirs);
+        let ini = render_ini(&fields, ": ", |section| {
+            let is_keymode = section.strip_suffix('K').is_some_and(|keys| keys.parse::<u8>().is_ok());
+            if is_keymode { "Mania" } else { section }
+        });
+
+        let mut merged = OsuSkinIni::from_str(&ini).map_err(|e| ThreeWayMergeError::Reparse { reason: e.to_string() })?;
+        merged.source = self.source.clone();
+        Ok((merged, conflicts))
+    }
+}
+
+impl ThreeWayMerge for QuaSkinIni {
+    fn three_way_merge_with(&self, base: &Self, theirs: &Self, take_theirs: &[String]) -> Merged<Self> {
+        let (fields, conflicts) = merge_ini_fields(&quaver_ini_fields(base), &quaver_ini_fields(self), &quaver_ini_fields(theirs), take_theirs);
+        let ini = render_ini(&fields, " = ", |section| section);
+
+        let mut merged = QuaSkinIni::from_str(&ini).map_err(|e| ThreeWayMergeError::Reparse { reason: e.to_string() })?;
+        merged.source = self.source.clone();
+        Ok((merged, conflicts))
+    }
+}
+
+impl ThreeWayMerge for SkinJson {
+    fn three_way_merge_with(&self, base: &Self, theirs: &Self, take_theirs: &[String]) -> Merged<Self> {
+        merge_json_config(self, base, theirs, take_theirs)
+    }
+}
+
+impl ThreeWayMerge for ConfJson {
+    fn three_way_merge_with(&self, base: &Self, theirs: &Self, take_theirs: &[String]) -> Merged<Self> {
+        merge_json_config(self, base, theirs, take_theirs)
+    }
+}
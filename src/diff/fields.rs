@@ -0,0 +1,80 @@
+use std::sync::{Arc, PoisonError, RwLock};
+use indexmap::IndexMap;
+
+use crate::diff::{ChangeKind, SkinDiff};
+use crate::texture::Texture;
+
+/// a value flattened to text, `compared` decides whether it changed and `shown` is what the change says
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Field {
+    pub shown: String,
+    pub compared: String,
+}
+
+pub(crate) type Fields = IndexMap<String, Field>;
+
+impl Field {
+    pub(crate) fn value(value: impl ToString) -> Self {
+        let value = value.to_string();
+        Field { shown: value.clone(), compared: value }
+    }
+
+    /// textures are compared by content so the same image under another name is not a change,
+    /// ones without data fall back to their path
+    pub(crate) fn texture(texture: &Arc<RwLock<Texture>>) -> Self {
+        let texture = texture.read().unwrap_or_else(PoisonError::into_inner);
+        Field {
+            shown: texture.path.clone(),
+            compared: content_key(&texture),
+        }
+    }
+
+    pub(crate) fn frames(frames: &[Arc<RwLock<Texture>>]) -> Self {
+        let (shown, compared): (Vec<String>, Vec<String>) = frames.iter()
+            .map(|frame| {
+                let field = Field::texture(frame);
+                (field.shown, field.compared)
+            })
+            .unzip();
+        Field { shown: shown.join(", "), compared: compared.join(",") }
+    }
+}
+
+fn content_key(texture: &Texture) -> String {
+    match texture.content_hash() {
+        Some(hash) => format!("{hash:016x}"),
+        None => format!("path:{}", texture.path),
+    }
+}
+
+/// `shown` alone doesn't tell two different textures with the same path apart
+fn shown(field: &Field, other: &Field) -> String {
+    if field.shown == other.shown && field.shown != field.compared {
+        format!("{} ({})", field.shown, field.compared)
+    } else {
+        field.shown.clone()
+    }
+}
+
+/// removed and modified fields in `before`'s order, then the added ones in `after`'s
+pub(crate) fn diff_fields(before: &Fields, after: &Fields) -> SkinDiff {
+    let mut diff = SkinDiff::new();
+
+    for (element, old) in before {
+        match after.get(element) {
+            None => diff.push(ChangeKind::Removed, element, Some(old.shown.clone()), None),
+            Some(new) if new.compared != old.compared => {
+                diff.push(ChangeKind::Modified, element, Some(shown(old, new)), Some(shown(new, old)));
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (element, new) in after {
+        if !before.contains_key(element) {
+            diff.push(ChangeKind::Added, element, None, Some(new.shown.clone()));
+        }
+    }
+
+    diff
+}
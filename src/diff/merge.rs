@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+use std::fmt;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::diff::fields::{Field, Fields};
+use crate::error::ThreeWayMergeError;
+
+/// an element ours and theirs both changed, each in its own way
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MergeConflict {
+    /// named like the changes in a `SkinDiff`, e.g. `4K.ColumnWidth` or `textures/mania-key1`
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub element: String,
+    /// `None` where it doesn't exist or is at its default
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub base: Option<String>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub ours: Option<String>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub theirs: Option<String>,
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quoted = |value: &Option<String>| value.as_ref().map_or_else(|| "none".to_string(), |v| format!("'{v}'"));
+        write!(
+            f,
+            "conflict {}: base {}, ours {}, theirs {}",
+            self.element,
+            quoted(&self.base),
+            quoted(&self.ours),
+            quoted(&self.theirs)
+        )
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl MergeConflict {
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_wasm(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// the merged value and its conflicts
+pub type Merged<T> = Result<(T, Vec<MergeConflict>), ThreeWayMergeError>;
+
+/// merges what changed from `base` to `theirs` into `self` (ours). changes only one side made are
+/// applied as they are, elements both sides changed differently keep ours unless they're in
+/// `take_theirs` and are returned as conflicts either way
+pub trait ThreeWayMerge: Sized {
+    fn three_way_merge_with(&self, base: &Self, theirs: &Self, take_theirs: &[String]) -> Merged<Self>;
+
+    fn three_way_merge(&self, base: &Self, theirs: &Self) -> Merged<Self> {
+        self.three_way_merge_with(base, theirs, &[])
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Side {
+    Ours,
+    Theirs,
+}
+
+/// which side every element in any of them comes from, ours' order first
+pub(crate) fn merge_fields(base: &Fields, ours: &Fields, theirs: &Fields, take_theirs: &[String]) -> (IndexMap<String, Side>, Vec<MergeConflict>) {
+    let take_theirs: HashSet<&str> = take_theirs.iter().map(String::as_str).collect();
+    let mut sides = IndexMap::new();
+    let mut conflicts = Vec::new();
+
+    for element in ours.keys().chain(theirs.keys()).chain(base.keys()) {
+        if sides.contains_key(element) {
+            continue;
+        }
+
+        let (b, o, t) = (base.get(element), ours.get(element), theirs.get(element));
+        let side = match pick(compared(b), compared(o), compared(t)) {
+            Some(side) => side,
+            None => {
+                let shown = |field: Option<&Field>| field.map(|f| f.shown.clone());
+                conflicts.push(MergeConflict { element: element.clone(), base: shown(b), ours: shown(o), theirs: shown(t) });
+                if take_theirs.contains(element.as_str()) { Side::Theirs } else { Side::Ours }
+            }
+        };
+        sides.insert(element.clone(), side);
+    }
+
+    (sides, conflicts)
+}
+
+fn compared(field: Option<&Field>) -> Option<&str> {
+    field.map(|f| f.compared.as_str())
+}
+
+/// `None` when both sides changed it differently
+pub(crate) fn pick<T: PartialEq>(base: T, ours: T, theirs: T) -> Option<Side> {
+    if ours == theirs || theirs == base {
+        Some(Side::Ours)
+    } else if ours == base {
+        Some(Side::Theirs)
+    } else {
+        None
+    }
+}
+
+/// the fields of whichever side each element was taken from
+pub(crate) fn merged_fields(sides: &IndexMap<String, Side>, ours: &Fields, theirs: &Fields) -> Fields {
+    sides.iter()
+        .filter_map(|(element, side)| {
+            let fields = match side {
+                Side::Ours => ours,
+                Side::Theirs => theirs,
+            };
+            fields.get(element).map(|field| (element.clone(), field.clone()))
+        })
+        .collect()
+}
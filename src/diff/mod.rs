@@ -0,0 +1,58 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+mod change;
+mod fields;
+mod configs;
+mod stores;
+mod skins;
+mod merge;
+
+pub use change::{Change, ChangeKind, SkinDiff};
+pub use merge::{MergeConflict, Merged, ThreeWayMerge};
+
+#[cfg(target_arch = "wasm32")]
+use crate::{FluXisSkin, MalodySkin, OsuSkin, QuaSkin, fluxis::SkinJson, generic::GenericManiaSkin, io::texture::TextureStore, malody::ConfJson, osu::OsuSkinIni, quaver::QuaSkinIni, sample::SampleStore};
+
+/// what changed going from one value to another of the same type
+pub trait Diff {
+    fn diff(&self, other: &Self) -> SkinDiff;
+}
+
+macro_rules! impl_diff_wasm {
+    ($($type:ty),*) => {
+        $(
+            #[cfg(target_arch = "wasm32")]
+            #[wasm_bindgen]
+            impl $type {
+                /// what changed going from this one to `other`
+                #[wasm_bindgen(js_name = diff)]
+                pub fn diff_wasm(&self, other: &$type) -> SkinDiff {
+                    self.diff(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_diff_wasm!(OsuSkinIni, QuaSkinIni, SkinJson, ConfJson, TextureStore, SampleStore, GenericManiaSkin);
+
+macro_rules! impl_three_way_merge_wasm {
+    ($($type:ty),*) => {
+        $(
+            #[cfg(target_arch = "wasm32")]
+            #[wasm_bindgen]
+            impl $type {
+                /// `[merged, conflicts]`, `this` is ours and conflicts listed in `takeTheirs` use theirs
+                #[wasm_bindgen(js_name = threeWayMerge)]
+                pub fn three_way_merge_wasm(&self, base: &$type, theirs: &$type, take_theirs: Option<Vec<String>>) -> Result<js_sys::Array, JsError> {
+                    let (merged, conflicts) = self.three_way_merge_with(base, theirs, &take_theirs.unwrap_or_default())
+                        .map_err(|e| JsError::new(&e.to_string()))?;
+                    Ok(crate::utils::wasm::with_conflicts(merged, conflicts))
+                }
+            }
+        )*
+    };
+}
+
+impl_three_way_merge_wasm!(OsuSkinIni, QuaSkinIni, SkinJson, ConfJson, TextureStore, SampleStore, OsuSkin, QuaSkin, FluXisSkin, MalodySkin);
@@ -0,0 +1,179 @@
+use std::sync::{Arc, RwLock};
+
+use crate::diff::fields::{Field, Fields, diff_fields};
+use crate::diff::{ChangeKind, Diff, Merged, SkinDiff, ThreeWayMerge};
+use crate::generic::{GenericManiaSkin, Keymode};
+use crate::{FluXisSkin, MalodySkin, OsuSkin, QuaSkin};
+use crate::texture::Texture;
+
+fn insert_texture(fields: &mut Fields, element: String, texture: Option<&Arc<RwLock<Texture>>>) {
+    if let Some(texture) = texture {
+        fields.insert(element, Field::texture(texture));
+    }
+}
+
+fn insert_option(fields: &mut Fields, element: &str, value: Option<&String>) {
+    if let Some(value) = value {
+        fields.insert(element.to_string(), Field::value(value));
+    }
+}
+
+fn insert_frames(fields: &mut Fields, element: String, frames: &[Arc<RwLock<Texture>>], fps: Option<f32>) {
+    if frames.is_empty() {
+        return;
+    }
+    if let Some(fps) = fps {
+        fields.insert(format!("{element}.fps"), Field::value(fps));
+    }
+    fields.insert(element, Field::frames(frames));
+}
+
+/// everything that isn't part of a keymode
+pub(crate) fn skin_fields(skin: &GenericManiaSkin) -> Fields {
+    let mut fields = Fields::new();
+
+    fields.insert("metadata.name".to_string(), Field::value(&skin.metadata.name));
+    fields.insert("metadata.creator".to_string(), Field::value(&skin.metadata.creator));
+    fields.insert("metadata.version".to_string(), Field::value(&skin.metadata.version));
+    fields.insert("resolution".to_string(), Field::value(format!("{}x{}", skin.resolution.x, skin.resolution.y)));
+
+    let sounds = &skin.sounds;
+    insert_option(&mut fields, "sounds.ui.menu_back_click", sounds.ui.menu_back_click.as_ref());
+    insert_option(&mut fields, "sounds.ui.ui_click", sounds.ui.ui_click.as_ref());
+    insert_option(&mut fields, "sounds.ui.ui_select", sounds.ui.ui_select.as_ref());
+    insert_option(&mut fields, "sounds.ui.ui_hover", sounds.ui.ui_hover.as_ref());
+    insert_option(&mut fields, "sounds.gameplay.miss", sounds.gameplay.miss.as_ref());
+    insert_option(&mut fields, "sounds.gameplay.fail", sounds.gameplay.fail.as_ref());
+    insert_option(&mut fields, "sounds.gameplay.restart", sounds.gameplay.restart.as_ref());
+    insert_option(&mut fields, "sounds.mania.hit", sounds.mania.hit.as_ref());
+
+    let cursor = &skin.ui.cursor;
+    insert_texture(&mut fields, "ui.cursor".to_string(), cursor.texture.as_ref());
+    fields.insert("ui.cursor.centered".to_string(), Field::value(cursor.centered));
+    fields.insert("ui.cursor.rotate".to_string(), Field::value(cursor.rotate));
+
+    let health_bar = &skin.gameplay.health_bar;
+    insert_texture(&mut fields, "gameplay.health_bar.fill".to_string(), health_bar.fill.as_ref());
+    insert_texture(&mut fields, "gameplay.health_bar.background".to_string(), health_bar.background.as_ref());
+
+    let judgement = &skin.gameplay.judgement;
+    for (name, texture) in [
+        ("flawless", &judgement.flawless),
+        ("perfect", &judgement.perfect),
+        ("great", &judgement.great),
+        ("good", &judgement.good),
+        ("bad", &judgement.bad),
+        ("miss", &judgement.miss),
+    ] {
+        insert_texture(&mut fields, format!("gameplay.judgement.{name}"), texture.as_ref());
+    }
+
+    fields
+}
+
+/// named like the conversion report, e.g. `4K.column_widths[0]` or `4K.normal_notes[2]`
+pub(crate) fn keymode_fields(keymode: &Keymode) -> Fields {
+    let mut fields = Fields::new();
+    let k = keymode.keymode;
+    let layout = &keymode.layout;
+
+    fields.insert(format!("{k}K.receptor_above_notes"), Field::value(layout.receptor_above_notes));
+    fields.insert(format!("{k}K.show_judgement_line"), Field::value(layout.show_judgement_line));
+    fields.insert(format!("{k}K.x_offset"), Field::value(layout.x_offset));
+    fields.insert(format!("{k}K.hit_position"), Field::value(layout.hit_position));
+    fields.insert(format!("{k}K.receptor_offset"), Field::value(layout.receptor_offset));
+    for (i, width) in layout.column_widths.iter().enumerate() {
+        fields.insert(format!("{k}K.column_widths[{i}]"), Field::value(width));
+    }
+    for (i, spacing) in layout.column_spacing.iter().enumerate() {
+        fields.insert(format!("{k}K.column_spacing[{i}]"), Field::value(spacing));
+    }
+
+    fields.insert(format!("{k}K.use_snap_color"), Field::value(keymode.use_snap_color));
+    for (i, color) in keymode.snap_colors.iter().enumerate() {
+        fields.insert(format!("{k}K.snap_colors[{i}]"), Field::value(color.to_hex()));
+    }
+
+    macro_rules! lanes {
+        ($($lanes:ident),*) => {
+            $(
+                for (i, lane) in keymode.$lanes.iter().enumerate() {
+                    insert_texture(&mut fields, format!("{k}K.{}[{i}]", stringify!($lanes)), lane.texture.as_ref());
+                }
+            )*
+        };
+    }
+    lanes!(receptor_up, receptor_down, normal_notes, long_note_heads, long_note_bodies, long_note_tails, normal_mines);
+
+    insert_texture(&mut fields, format!("{k}K.base_normal_note"), keymode.base_normal_note.as_ref().and_then(|e| e.texture.as_ref()));
+    insert_texture(&mut fields, format!("{k}K.base_long_note"), keymode.base_long_note.as_ref().and_then(|e| e.texture.as_ref()));
+    insert_texture(&mut fields, format!("{k}K.base_normal_mine"), keymode.base_normal_mine.as_ref().and_then(|e| e.texture.as_ref()));
+
+    if let Some(sheet) = &keymode.normal_notes_snap_colored {
+        insert_frames(&mut fields, format!("{k}K.normal_notes_snap_colored"), &sheet.snaps, sheet.fps);
+    }
+    if let Some(sheet) = &keymode.long_note_heads_snap_colored {
+        insert_frames(&mut fields, format!("{k}K.long_note_heads_snap_colored"), &sheet.snaps, sheet.fps);
+    }
+    if let Some(sheet) = &keymode.normal_mines_snap_colored {
+        insert_frames(&mut fields, format!("{k}K.normal_mines_snap_colored"), &sheet.snaps, sheet.fps);
+    }
+
+    insert_frames(&mut fields, format!("{k}K.hit_lighting_normal"), &keymode.hit_lighting_normal.frames, keymode.hit_lighting_normal.fps);
+    insert_frames(&mut fields, format!("{k}K.hit_lighting_hold"), &keymode.hit_lighting_hold.frames, keymode.hit_lighting_hold.fps);
+    insert_texture(&mut fields, format!("{k}K.column_lighting"), keymode.column_lighting.texture.as_ref());
+
+    insert_texture(&mut fields, format!("{k}K.judgement_line"), keymode.judgement_line.texture.as_ref());
+    fields.insert(format!("{k}K.judgement_line.color"), Field::value(keymode.judgement_line.color.to_hex()));
+
+    let stage = &keymode.stage;
+    insert_texture(&mut fields, format!("{k}K.stage.background"), stage.background.as_ref());
+    insert_texture(&mut fields, format!("{k}K.stage.border_left"), stage.border_left.as_ref());
+    insert_texture(&mut fields, format!("{k}K.stage.border_right"), stage.border_right.as_ref());
+
+    fields
+}
+
+/// elements are compared by what they draw, so skins converted from different games can be diffed too.
+/// a keymode only one of them has is a single change named after it, e.g. `7K`
+impl Diff for GenericManiaSkin {
+    fn diff(&self, other: &Self) -> SkinDiff {
+        let mut diff = diff_fields(&skin_fields(self), &skin_fields(other));
+
+        for keymode in &self.keymodes {
+            match other.keymodes.iter().find(|k| k.keymode == keymode.keymode) {
+                Some(theirs) => diff.extend(diff_fields(&keymode_fields(keymode), &keymode_fields(theirs))),
+                None => diff.push(ChangeKind::Removed, format!("{}K", keymode.keymode), None, None),
+            }
+        }
+
+        for keymode in &other.keymodes {
+            if !self.keymodes.iter().any(|k| k.keymode == keymode.keymode) {
+                diff.push(ChangeKind::Added, format!("{}K", keymode.keymode), None, None);
+            }
+        }
+
+        diff
+    }
+}
+
+macro_rules! impl_three_way_merge {
+    ($($skin:ty => $config:ident),*) => {
+        $(
+            /// the config, textures and samples are merged one after another, the resolution is ours
+            impl ThreeWayMerge for $skin {
+                fn three_way_merge_with(&self, base: &Self, theirs: &Self, take_theirs: &[String]) -> Merged<Self> {
+                    let ($config, mut conflicts) = self.$config.three_way_merge_with(&base.$config, &theirs.$config, take_theirs)?;
+                    let (textures, texture_conflicts) = self.textures.three_way_merge_with(&base.textures, &theirs.textures, take_theirs)?;
+                    let (samples, sample_conflicts) = self.samples.three_way_merge_with(&base.samples, &theirs.samples, take_theirs)?;
+                    conflicts.extend(texture_conflicts);
+                    conflicts.extend(sample_conflicts);
+
+                    Ok((Self { resolution: self.resolution, $config, textures, samples }, conflicts))
+                }
+            }
+        )*
+    };
+}
+
+impl_three_way_merge!(OsuSkin => skin_ini, QuaSkin => skin_ini, FluXisSkin => skin_json, MalodySkin => conf_json);
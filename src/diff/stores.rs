@@ -0,0 +1,89 @@
+use std::sync::PoisonError;
+
+use crate::diff::fields::{Field, Fields, diff_fields};
+use crate::diff::merge::{Side, merge_fields};
+use crate::diff::{Diff, Merged, SkinDiff, ThreeWayMerge};
+use crate::io::texture::TextureStore;
+use crate::sample::SampleStore;
+use crate::Store;
+
+/// `textures/<path>` to the texture's content hash, sorted by path
+pub(crate) fn texture_fields(textures: &TextureStore) -> Fields {
+    let mut entries = textures.iter();
+    entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    entries.into_iter()
+        .map(|(path, texture)| {
+            let hash = texture.read().unwrap_or_else(PoisonError::into_inner).content_hash();
+            (format!("textures/{path}"), Field::value(hash_str(hash)))
+        })
+        .collect()
+}
+
+/// `samples/<path>` to the xxh3 hash of the sample's bytes, sorted by path
+pub(crate) fn sample_fields(samples: &SampleStore) -> Fields {
+    let mut entries = samples.iter();
+    entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    entries.into_iter()
+        .map(|(path, sample)| {
+            let hash = sample.read().unwrap_or_else(PoisonError::into_inner).content_hash();
+            (format!("samples/{path}"), Field::value(hash_str(hash)))
+        })
+        .collect()
+}
+
+fn hash_str(hash: Option<u64>) -> String {
+    hash.map_or_else(|| "empty".to_string(), |hash| format!("{hash:016x}"))
+}
+
+impl Diff for TextureStore {
+    fn diff(&self, other: &Self) -> SkinDiff {
+        diff_fields(&texture_fields(self), &texture_fields(other))
+    }
+}
+
+impl Diff for SampleStore {
+    fn diff(&self, other: &Self) -> SkinDiff {
+        diff_fields(&sample_fields(self), &sample_fields(other))
+    }
+}
+
+// starts from a copy of ours and swaps in theirs for the paths only they changed, the textures and
+// samples themselves are shared with the stores they came from
+impl ThreeWayMerge for TextureStore {
+    fn three_way_merge_with(&self, base: &Self, theirs: &Self, take_theirs: &[String]) -> Merged<Self> {
+        let (sides, conflicts) = merge_fields(&texture_fields(base), &texture_fields(self), &texture_fields(theirs), take_theirs);
+        let mut merged = self.clone();
+
+        for (element, side) in sides {
+            let Some(path) = element.strip_prefix("textures/").filter(|_| side == Side::Theirs) else { continue };
+            merged.remove(path);
+            if let Some(texture) = theirs.textures.get(path) {
+                merged.insert_shared(texture.clone());
+            }
+            if let Some(mips) = theirs.mipmaps.get(path) {
+                merged.set_mipmaps(path, mips.clone());
+            }
+        }
+
+        Ok((merged, conflicts))
+    }
+}
+
+impl ThreeWayMerge for SampleStore {
+    fn three_way_merge_with(&self, base: &Self, theirs: &Self, take_theirs: &[String]) -> Merged<Self> {
+        let (sides, conflicts) = merge_fields(&sample_fields(base), &sample_fields(self), &sample_fields(theirs), take_theirs);
+        let mut merged = self.clone();
+
+        for (element, side) in sides {
+            let Some(path) = element.strip_prefix("samples/").filter(|_| side == Side::Theirs) else { continue };
+            Store::remove(&mut merged, path);
+            if let Some(sample) = theirs.get(path) {
+                merged.insert_shared(sample);
+            }
+        }
+
+        Ok((merged, conflicts))
+    }
+}
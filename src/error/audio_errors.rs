@@ -0,0 +1,32 @@
+use std::fmt;
+
+use crate::sample::SampleFormat;
+
+#[derive(Debug)]
+pub enum SampleProcessError {
+    /// only wav and ogg can be decoded
+    Unsupported { format: Option<SampleFormat> },
+    Decode { reason: String },
+    Encode { reason: String },
+}
+
+impl fmt::Display for SampleProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SampleProcessError::Unsupported { format: Some(format) } => {
+                write!(f, "can't decode {} samples", format.extension())
+            }
+            SampleProcessError::Unsupported { format: None } => {
+                write!(f, "sample is not in a known audio format")
+            }
+            SampleProcessError::Decode { reason } => {
+                write!(f, "failed to decode sample: {reason}")
+            }
+            SampleProcessError::Encode { reason } => {
+                write!(f, "failed to encode sample: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SampleProcessError {}
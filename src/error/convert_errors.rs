@@ -0,0 +1,31 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ConvertError {
+    MissingKeymode { keymode: u8 },
+    ImageProcessing { path: String, reason: String },
+    /// a thread panicked while it held the texture's lock
+    PoisonedLock { path: String },
+    InvalidLayout { reason: String },
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConvertError::MissingKeymode { keymode } => {
+                write!(f, "skin has no {keymode}K keymode")
+            }
+            ConvertError::ImageProcessing { path, reason } => {
+                write!(f, "failed to process texture '{path}': {reason}")
+            }
+            ConvertError::PoisonedLock { path } => {
+                write!(f, "texture '{path}' is poisoned, a thread panicked while writing it")
+            }
+            ConvertError::InvalidLayout { reason } => {
+                write!(f, "invalid layout: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConvertError {}
@@ -1,13 +1,16 @@
 use std::fmt;
 
-use crate::error::TextureLoadError;
+use crate::error::{ParseError, TextureLoadError};
 
 #[derive(Debug)]
 pub enum ImportError {
     ReadConfig { path: String, source: std::io::Error },
+    ReadArchive { source: zip::result::ZipError },
+    /// the entries unpack to more than `limit` bytes
+    ArchiveTooLarge { limit: u64 },
     ParseConfig {
         path: String,
-        source: Box<dyn std::error::Error>,
+        source: Box<ParseError>,
     },
     Texture(TextureLoadError),
     Sample {
@@ -23,6 +26,12 @@ impl fmt::Display for ImportError {
             ImportError::ReadConfig { path, source } => {
                 write!(f, "failed to read config file '{path}': {source}")
             }
+            ImportError::ReadArchive { source } => {
+                write!(f, "failed to read skin archive: {source}")
+            }
+            ImportError::ArchiveTooLarge { limit } => {
+                write!(f, "skin archive unpacks to more than {limit} bytes")
+            }
             ImportError::ParseConfig { path, source } => {
                 write!(f, "failed to parse config file '{path}': {source}")
             }
@@ -41,6 +50,8 @@ impl std::error::Error for ImportError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             ImportError::ReadConfig { source, .. } => Some(source),
+            ImportError::ReadArchive { source } => Some(source),
+            ImportError::ArchiveTooLarge { .. } => None,
             ImportError::ParseConfig { source, .. } => Some(source.as_ref()),
             ImportError::Texture(e) => Some(e),
             ImportError::Sample { source, .. } => Some(source.as_ref()),
@@ -0,0 +1,64 @@
+use std::fmt;
+
+use crate::error::ConvertError;
+use crate::generic::merge_plan::ElementGroup;
+
+#[derive(Debug)]
+pub enum MergePlanError {
+    /// the plan points at a source that wasn't passed in
+    MissingSource { index: usize, sources: usize },
+    MissingKeymode { source: usize, keymode: u8 },
+    /// only notes, receptors, lighting and stage can be picked per keymode
+    NotPerKeymode { group: ElementGroup },
+    /// converting a game specific skin to or from a generic one failed
+    Convert(ConvertError),
+}
+
+impl fmt::Display for MergePlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergePlanError::MissingSource { index, sources } => {
+                write!(f, "merge plan uses source {index} but only {sources} were given")
+            }
+            MergePlanError::MissingKeymode { source, keymode } => {
+                write!(f, "source {source} has no {keymode}K keymode")
+            }
+            MergePlanError::NotPerKeymode { group } => {
+                write!(f, "{} can't be picked per keymode", group.as_str())
+            }
+            MergePlanError::Convert(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for MergePlanError {}
+
+impl From<ConvertError> for MergePlanError {
+    fn from(e: ConvertError) -> Self {
+        MergePlanError::Convert(e)
+    }
+}
+
+/// a three way merge couldn't keep both sides' changes, so it returns nothing instead of only ours
+#[derive(Debug)]
+pub enum ThreeWayMergeError {
+    /// one of the configs isn't written out as valid json, `side` is `base`, `ours` or `theirs`
+    InvalidJson { side: &'static str, reason: String },
+    /// the merged config was rejected when it was parsed back
+    Reparse { reason: String },
+}
+
+impl fmt::Display for ThreeWayMergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThreeWayMergeError::InvalidJson { side, reason } => {
+                write!(f, "{side} isn't valid json: {reason}")
+            }
+            ThreeWayMergeError::Reparse { reason } => {
+                write!(f, "merged config couldn't be parsed again: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThreeWayMergeError {}
@@ -1,5 +1,13 @@
+pub mod audio_errors;
+pub mod convert_errors;
 pub mod decode_errors;
 pub mod import_errors;
+pub mod merge_errors;
+pub mod parse_errors;
 
+pub use audio_errors::*;
+pub use convert_errors::*;
 pub use decode_errors::*;
-pub use import_errors::*;
\ No newline at end of file
+pub use import_errors::*;
+pub use merge_errors::*;
+pub use parse_errors::*;
@@ -0,0 +1,286 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::common::color::Rgba;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// the file itself could not be read, e.g. broken json
+    #[default]
+    Syntax,
+    InvalidValue,
+    UnknownKey,
+    DuplicateKey,
+    OutOfRange,
+    /// a per column list that does not have one item per column
+    WrongListLength,
+}
+
+/// something lenient parsing stepped over, the value that was kept instead is the default or the
+/// previous one
+pub type ParseWarning = ParseError;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// anything malformed or unknown is an error
+    Strict,
+    /// keeps going and collects what was ignored as warnings
+    #[default]
+    Lenient,
+}
+
+/// a config value that could not be parsed, with as much of its location as is known.
+/// section parsers only know the key and value, `from_ini` fills in the section and line afterwards
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParseError {
+    /// 1 based
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub line: Option<u32>,
+    /// 1 based
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub column: Option<u32>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub section: Option<String>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub keymode: Option<u8>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub key: Option<String>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub value: Option<String>,
+    /// what went wrong without the location, e.g. `expected number`
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub message: String,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into(), ..Default::default() }
+    }
+
+    pub fn invalid_value(key: &str, value: &str, expected: &str) -> Self {
+        Self {
+            key: Some(key.to_string()),
+            value: Some(value.to_string()),
+            message: format!("expected {expected}"),
+            kind: ParseErrorKind::InvalidValue,
+            ..Default::default()
+        }
+    }
+
+    pub fn unknown_key(key: &str, value: &str) -> Self {
+        Self {
+            key: Some(key.to_string()),
+            value: Some(value.to_string()),
+            message: "unknown key".to_string(),
+            kind: ParseErrorKind::UnknownKey,
+            ..Default::default()
+        }
+    }
+
+    pub fn duplicate_key(key: &str, value: &str) -> Self {
+        Self {
+            key: Some(key.to_string()),
+            value: Some(value.to_string()),
+            message: "duplicate key, the last one is used".to_string(),
+            kind: ParseErrorKind::DuplicateKey,
+            ..Default::default()
+        }
+    }
+
+    /// `range` is only used for the message, e.g. `1-18`
+    pub fn out_of_range(key: &str, value: &str, range: &str) -> Self {
+        Self {
+            key: Some(key.to_string()),
+            value: Some(value.to_string()),
+            message: format!("expected {range}"),
+            kind: ParseErrorKind::OutOfRange,
+            ..Default::default()
+        }
+    }
+
+    pub fn wrong_list_length(key: &str, value: &str, expected: usize, keymode: u8) -> Self {
+        Self {
+            key: Some(key.to_string()),
+            value: Some(value.to_string()),
+            message: format!("expected {expected} values for {keymode} keys"),
+            kind: ParseErrorKind::WrongListLength,
+            ..Default::default()
+        }
+    }
+
+    /// only fills in what is still unknown, the innermost parser knows best
+    pub fn in_section(mut self, section: &str) -> Self {
+        self.section.get_or_insert_with(|| section.to_string());
+        self
+    }
+
+    pub fn in_keymode(mut self, keymode: u8) -> Self {
+        self.keymode.get_or_insert(keymode);
+        self
+    }
+
+    pub fn at_line(mut self, line: u32) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut location = Vec::new();
+
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => location.push(format!("line {line}, column {column}")),
+            (Some(line), None) => location.push(format!("line {line}")),
+            _ => {}
+        }
+        match (&self.section, self.keymode) {
+            (Some(section), Some(keymode)) => location.push(format!("[{section}] Keys={keymode}")),
+            (Some(section), None) => location.push(format!("[{section}]")),
+            (None, Some(keymode)) => location.push(format!("Keys={keymode}")),
+            (None, None) => {}
+        }
+
+        for part in &location {
+            write!(f, "{part}, ")?;
+        }
+        if let Some(key) = &self.key {
+            write!(f, "{key}: ")?;
+        }
+        f.write_str(&self.message)?;
+        if let Some(value) = &self.value {
+            write!(f, ", got '{value}'")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// what a parser does with the things it cannot make sense of, see [`ParseMode`]
+#[derive(Clone, Debug, Default)]
+pub struct ParseDiagnostics {
+    pub mode: ParseMode,
+    pub warnings: Vec<ParseWarning>,
+}
+
+impl ParseDiagnostics {
+    pub fn new(mode: ParseMode) -> Self {
+        Self { mode, warnings: Vec::new() }
+    }
+
+    /// an error in strict mode, a warning otherwise
+    pub fn report(&mut self, issue: ParseError) -> Result<(), ParseError> {
+        match self.mode {
+            ParseMode::Strict => Err(issue),
+            ParseMode::Lenient => {
+                self.warnings.push(issue);
+                Ok(())
+            }
+        }
+    }
+
+    /// lines without a key at all are left to whoever split the file into sections
+    pub fn unknown_key(&mut self, key: &str, value: &str) -> Result<(), ParseError> {
+        if key.is_empty() {
+            return Ok(());
+        }
+        self.report(ParseError::unknown_key(key, value))
+    }
+
+    /// `parsed` if it worked, `fallback` and a warning if it did not
+    pub fn recover<T>(&mut self, parsed: Result<T, ParseError>, fallback: impl FnOnce() -> T) -> Result<T, ParseError> {
+        match parsed {
+            Ok(parsed) => Ok(parsed),
+            Err(issue) => {
+                self.report(issue)?;
+                Ok(fallback())
+            }
+        }
+    }
+
+    pub fn accept<T, E>(&mut self, key: &str, value: &str, parsed: Result<T, E>, expected: &str, fallback: T) -> Result<T, ParseError> {
+        self.recover(parsed.map_err(|_| ParseError::invalid_value(key, value, expected)), || fallback)
+    }
+
+    pub fn number<T: FromStr>(&mut self, key: &str, value: &str, fallback: T) -> Result<T, ParseError> {
+        self.accept(key, value, value.parse(), "number", fallback)
+    }
+
+    pub fn number_in<T>(&mut self, key: &str, value: &str, range: RangeInclusive<T>, fallback: T) -> Result<T, ParseError>
+    where
+        T: FromStr + PartialOrd + fmt::Display + Copy,
+    {
+        let number = self.number(key, value, fallback)?;
+        if range.contains(&number) {
+            return Ok(number);
+        }
+
+        self.report(ParseError::out_of_range(key, value, &format!("{}-{}", range.start(), range.end())))?;
+        Ok(fallback)
+    }
+
+    /// `0`/`1` and `true`/`false`, ini files use either
+    pub fn bool(&mut self, key: &str, value: &str, fallback: bool) -> Result<bool, ParseError> {
+        let parsed = match value.trim().to_lowercase().as_str() {
+            "1" | "true" => Ok(true),
+            "0" | "false" => Ok(false),
+            _ => Err(()),
+        };
+        self.accept(key, value, parsed, "0 or 1", fallback)
+    }
+
+    pub fn colour(&mut self, key: &str, value: &str, fallback: Rgba) -> Result<Rgba, ParseError> {
+        self.accept(key, value, Rgba::from_str(value), "colour", fallback)
+    }
+
+    /// applies `f` to everything reported since there were `start` warnings,
+    /// used to add the location once the caller knows it
+    pub(crate) fn map_since(&mut self, start: usize, f: impl Fn(ParseWarning) -> ParseWarning) {
+        for warning in &mut self.warnings[start..] {
+            *warning = f(std::mem::take(warning));
+        }
+    }
+}
+
+impl From<serde_json::Error> for ParseError {
+    fn from(e: serde_json::Error) -> Self {
+        // serde_json appends the location to its message, it is kept separately here
+        let message = e.to_string();
+        let message = match message.rfind(" at line ") {
+            Some(pos) => message[..pos].to_string(),
+            None => message,
+        };
+
+        Self {
+            line: u32::try_from(e.line()).ok().filter(|l| *l > 0),
+            column: u32::try_from(e.column()).ok().filter(|c| *c > 0),
+            message,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<std::string::FromUtf8Error> for ParseError {
+    fn from(e: std::string::FromUtf8Error) -> Self {
+        Self::new(format!("file is not valid utf-8: {e}"))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl ParseError {
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_wasm(&self) -> String {
+        self.to_string()
+    }
+}
+
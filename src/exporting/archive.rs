@@ -0,0 +1,35 @@
+use std::io;
+
+use crate::io::archive::{archive_file_name, write_archive};
+use crate::exporting::incremental::{ArchiveUpdate, update_archive};
+use crate::exporting::common::{osu_skin_to_files, osu_skin_to_files_with, quaver_skin_to_files, fluxis_skin_to_files, malody_skin_to_files, etterna_noteskin_to_files};
+use crate::osu::OsuExportOptions;
+use crate::{osu, quaver, fluxis, malody, etterna};
+
+macro_rules! impl_skin_archive_exporter {
+    ($fn_name:ident, $update_fn:ident, $name_fn:ident, $to_files:ident, $skin_type:ty, $extension:expr, $($name:ident).+) => {
+        pub fn $fn_name(skin: &$skin_type) -> io::Result<Vec<u8>> {
+            let files = $to_files(skin)?;
+            write_archive(&files).map_err(io::Error::from)
+        }
+
+        pub fn $update_fn(skin: &$skin_type, previous: &[u8], remove_stale: bool) -> io::Result<ArchiveUpdate> {
+            update_archive(previous, $to_files(skin)?, remove_stale)
+        }
+
+        pub fn $name_fn(skin: &$skin_type) -> String {
+            archive_file_name(&skin.$($name).+, $extension)
+        }
+    };
+}
+
+impl_skin_archive_exporter!(export_osu_skin_to_archive, update_osu_skin_archive, osu_archive_name, osu_skin_to_files, osu::OsuSkin, "osk", skin_ini.general.name);
+impl_skin_archive_exporter!(export_quaver_skin_to_archive, update_quaver_skin_archive, quaver_archive_name, quaver_skin_to_files, quaver::QuaSkin, "qsk", skin_ini.general.name);
+impl_skin_archive_exporter!(export_fluxis_skin_to_archive, update_fluxis_skin_archive, fluxis_archive_name, fluxis_skin_to_files, fluxis::FluXisSkin, "zip", skin_json.info.name);
+impl_skin_archive_exporter!(export_malody_skin_to_archive, update_malody_skin_archive, malody_archive_name, malody_skin_to_files, malody::MalodySkin, "mks", conf_json.meta.name);
+impl_skin_archive_exporter!(export_etterna_noteskin_to_archive, update_etterna_noteskin_archive, etterna_archive_name, etterna_noteskin_to_files, etterna::EtternaNoteskin, "zip", name);
+
+pub fn export_osu_skin_to_archive_with(skin: &osu::OsuSkin, options: &OsuExportOptions) -> io::Result<Vec<u8>> {
+    let files = osu_skin_to_files_with(skin, options)?;
+    write_archive(&files).map_err(io::Error::from)
+}
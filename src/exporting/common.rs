@@ -0,0 +1,210 @@
+use std::io;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock, RwLockReadGuard};
+use image::RgbaImage;
+use rayon::prelude::*;
+
+use crate::image_proc::proc::resize_img;
+use crate::osu::{MipmapMode, OsuExportOptions};
+use crate::sample::SampleStore;
+use crate::utils::io::change_extension;
+use crate::io::texture::{Texture, TextureFormat, TextureStore, decode_texture};
+use crate::{osu, quaver, fluxis, etterna, malody, Binary, Resources, Store};
+
+pub fn encode_textures(textures: &TextureStore) -> io::Result<Vec<(String, Vec<u8>)>> {
+    let arcs: Vec<Arc<RwLock<Texture>>> = textures
+        .textures
+        .iter()
+        .map(|entry| Arc::clone(entry.value()))
+        .collect();
+
+    let encoded: Vec<io::Result<Option<(String, Vec<u8>)>>> = arcs
+        .par_iter()
+        .map(|arc| {
+            let texture = arc.read().map_err(|e| io::Error::other(e.to_string()))?;
+            Ok(encode_texture(&texture, textures.encode_format)?
+                .map(|(format, bytes)| (change_extension(texture.get_path(), format.extension()), bytes)))
+        })
+        .collect();
+
+    let mut files = Vec::with_capacity(encoded.len());
+    for result in encoded {
+        if let Some(file) = result? {
+            files.push(file);
+        }
+    }
+    Ok(files)
+}
+
+/// textures that weren't changed since they were read keep their original bytes, the rest are encoded as `format`
+fn encode_texture(texture: &Texture, format: TextureFormat) -> io::Result<Option<(TextureFormat, Vec<u8>)>> {
+    if let Some(raw) = texture.original_bytes() {
+        let original = texture.format.or_else(|| TextureFormat::detect(raw)).unwrap_or_default();
+        Ok(Some((original, raw.to_vec())))
+    } else if let Some(img) = texture.state().as_loaded() {
+        Ok(Some((format, format.encode(img).map_err(io::Error::other)?)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// HD textures, `name@2x` ones and those imported from `@2x` files, are written as `name@2x` plus a half size `name`,
+/// SD ones as `name` plus a doubled `name@2x`. an SD texture next to its `name@2x` is written as is
+pub fn encode_osu_textures(textures: &TextureStore, options: &OsuExportOptions) -> io::Result<Vec<(String, Vec<u8>)>> {
+    if options.mipmaps == MipmapMode::Off {
+        return encode_textures(textures);
+    }
+
+    let mut pairs: HashMap<String, OsuPair> = HashMap::new();
+    for entry in textures.textures.iter() {
+        let arc = Some(Arc::clone(entry.value()));
+        match entry.key().strip_suffix("@2x") {
+            Some(base) => pairs.entry(base.to_string()).or_default().hd = arc,
+            None => pairs.entry(entry.key().clone()).or_default().sd = arc,
+        }
+    }
+    let pairs: Vec<OsuPair> = pairs.into_values().collect();
+
+    let encoded: Vec<Vec<(String, Vec<u8>)>> = pairs
+        .par_iter()
+        .map(|pair| pair.encode(textures, options))
+        .collect::<io::Result<_>>()?;
+
+    Ok(encoded.into_iter().flatten().collect())
+}
+
+/// the `name` and `name@2x` textures of one osu texture
+#[derive(Default)]
+struct OsuPair {
+    sd: Option<Arc<RwLock<Texture>>>,
+    hd: Option<Arc<RwLock<Texture>>>,
+}
+
+impl OsuPair {
+    fn encode(&self, textures: &TextureStore, options: &OsuExportOptions) -> io::Result<Vec<(String, Vec<u8>)>> {
+        let (sd, hd) = match (&self.sd, &self.hd) {
+            (Some(sd), None) if textures.has_mipmaps(read(sd)?.get_path()) => (None, Some(sd)),
+            (sd, hd) => (sd.as_ref(), hd.as_ref()),
+        };
+
+        let Some(hd) = hd else {
+            return match sd {
+                Some(sd) => encode_sd_only(&*read(sd)?, textures.encode_format, options),
+                None => Ok(Vec::new()),
+            };
+        };
+
+        let texture = read(hd)?;
+        let path = texture.get_path();
+        let base = path.strip_suffix("@2x").unwrap_or(path);
+        let Some((hd_format, hd_bytes)) = encode_texture(&texture, textures.encode_format)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut files = vec![(change_extension(&format!("{base}@2x"), hd_format.extension()), hd_bytes)];
+        if options.mipmaps == MipmapMode::HdOnly {
+            return Ok(files);
+        }
+
+        if let Some(sd) = sd {
+            let sd = read(sd)?;
+            if let Some((sd_format, sd_bytes)) = encode_texture(&sd, textures.encode_format)? {
+                files.push((change_extension(base, sd_format.extension()), sd_bytes));
+            }
+            return Ok(files);
+        }
+
+        let sd = textures.get_mipmap(path, 0).or_else(|| {
+            decoded(&texture).map(|img| {
+                let (width, height) = img.dimensions();
+                resize_img(&img, width.div_ceil(2), height.div_ceil(2), options.filter.into())
+            })
+        });
+        if let Some(sd) = sd {
+            let sd_bytes = hd_format.encode(&sd).map_err(io::Error::other)?;
+            files.push((change_extension(base, hd_format.extension()), sd_bytes));
+        }
+        Ok(files)
+    }
+}
+
+/// the texture as `name` and doubled in the same format as `name@2x`
+fn encode_sd_only(texture: &Texture, format: TextureFormat, options: &OsuExportOptions) -> io::Result<Vec<(String, Vec<u8>)>> {
+    let path = texture.get_path();
+    let Some((sd_format, sd_bytes)) = encode_texture(texture, format)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut files = Vec::with_capacity(2);
+    if let Some(img) = decoded(texture) {
+        let hd = resize_img(&img, img.width() * 2, img.height() * 2, options.filter.into());
+        let hd_bytes = sd_format.encode(&hd).map_err(io::Error::other)?;
+        files.push((change_extension(&format!("{path}@2x"), sd_format.extension()), hd_bytes));
+    }
+    if options.mipmaps != MipmapMode::HdOnly {
+        files.push((change_extension(path, sd_format.extension()), sd_bytes));
+    }
+    Ok(files)
+}
+
+fn read(arc: &Arc<RwLock<Texture>>) -> io::Result<RwLockReadGuard<'_, Texture>> {
+    arc.read().map_err(|e| io::Error::other(e.to_string()))
+}
+
+fn decoded(texture: &Texture) -> Option<RgbaImage> {
+    match texture.state().as_loaded() {
+        Some(img) => Some(img.clone()),
+        None => texture.state().as_unloaded().and_then(|raw| decode_texture(raw).ok()).map(|(img, _)| img),
+    }
+}
+
+pub fn encode_samples(samples: &SampleStore) -> Vec<(String, Vec<u8>)> {
+    let mut files = Vec::with_capacity(samples.len());
+    samples.for_each(|sample| {
+        // anything that isn't recognised audio keeps the old .wav
+        let extension = sample.format().map_or("wav", |format| format.extension());
+        let sample_path_with_ext = change_extension(sample.get_path(), extension);
+
+        if let Some(bytes) = sample.get_data() {
+            files.push((sample_path_with_ext, bytes.to_vec()));
+        }
+    });
+    files
+}
+
+macro_rules! impl_skin_to_files {
+    ($fn_name:ident, $skin_type:ty, $config:ident, $config_file:expr) => {
+        pub fn $fn_name(skin: &$skin_type) -> io::Result<HashMap<String, Vec<u8>>> {
+            let mut files = HashMap::new();
+            files.insert($config_file.to_string(), skin.$config.to_string().into_bytes());
+            files.extend(encode_textures(&skin.textures)?);
+            files.extend(encode_samples(&skin.samples));
+            Ok(files)
+        }
+    };
+}
+
+impl_skin_to_files!(osu_skin_to_files, osu::OsuSkin, skin_ini, "skin.ini");
+
+pub fn osu_skin_to_files_with(skin: &osu::OsuSkin, options: &OsuExportOptions) -> io::Result<HashMap<String, Vec<u8>>> {
+    let mut files = HashMap::new();
+    files.insert("skin.ini".to_string(), skin.skin_ini.to_string().into_bytes());
+    files.extend(encode_osu_textures(&skin.textures, options)?);
+    files.extend(encode_samples(&skin.samples));
+    Ok(files)
+}
+impl_skin_to_files!(quaver_skin_to_files, quaver::QuaSkin, skin_ini, "skin.ini");
+impl_skin_to_files!(fluxis_skin_to_files, fluxis::FluXisSkin, skin_json, "skin.json");
+impl_skin_to_files!(malody_skin_to_files, malody::MalodySkin, conf_json, "conf.json");
+
+/// noteskins need a `NoteSkin.lua` next to `metrics.ini` that rotates the `Down` sprites for the other buttons
+pub fn etterna_noteskin_to_files(skin: &etterna::EtternaNoteskin) -> io::Result<HashMap<String, Vec<u8>>> {
+    let mut files = HashMap::new();
+    files.insert("metrics.ini".to_string(), skin.metrics.to_string().into_bytes());
+    if let Some(lua) = Resources::noteskin("NoteSkin.lua") {
+        files.insert("NoteSkin.lua".to_string(), lua.into_owned());
+    }
+    files.extend(encode_textures(&skin.textures)?);
+    files.extend(encode_samples(&skin.samples));
+    Ok(files)
+}
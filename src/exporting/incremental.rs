@@ -0,0 +1,107 @@
+use std::io;
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::HashSet;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+use xxhash_rust::xxh3::xxh3_64;
+
+use crate::io::archive::{read_archive, write_archive};
+use crate::utils::io::path_to_unix;
+
+/// what an incremental export changed, paths are relative to the skin and sorted
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExportSummary {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub added: Vec<String>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub modified: Vec<String>,
+    /// already there with the same content, so they weren't written
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub unchanged: Vec<String>,
+    /// only filled when stale files are removed
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub removed: Vec<String>,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl ExportSummary {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = hasChanges))]
+    pub fn has_changes(&self) -> bool {
+        !self.added.is_empty() || !self.modified.is_empty() || !self.removed.is_empty()
+    }
+}
+
+/// a rebuilt archive and how it differs from the one it replaces
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Default)]
+pub struct ArchiveUpdate {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub archive: Vec<u8>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub summary: ExportSummary,
+}
+
+/// splits `files` into the ones that have to be written and the summary, `existing` maps the
+/// destination's paths to their xxh3 hash and only paths in `exported` are removed as stale.
+/// a stale path that only differs in case from one being written is the same file on windows and macOS, so it is kept
+#[cfg(not(target_arch = "wasm32"))]
+pub fn plan_export(
+    files: HashMap<String, Vec<u8>>,
+    existing: &HashMap<String, u64>,
+    exported: &HashSet<String>,
+    remove_stale: bool,
+) -> (HashMap<String, Vec<u8>>, ExportSummary) {
+    let files = unix_paths(files);
+    let mut summary = summarize(&files, existing, remove_stale);
+    let written: HashSet<String> = files.keys().map(|path| path.to_lowercase()).collect();
+    summary.removed.retain(|path| exported.contains(path) && !written.contains(&path.to_lowercase()));
+
+    let mut to_write = files;
+    to_write.retain(|path, _| summary.unchanged.binary_search(path).is_err());
+    (to_write, summary)
+}
+
+/// archives can't be patched in place, so the whole archive is rebuilt; files that are only in
+/// `previous` are carried over unless stale files are removed
+pub fn update_archive(previous: &[u8], files: HashMap<String, Vec<u8>>, remove_stale: bool) -> io::Result<ArchiveUpdate> {
+    let mut old_files = read_archive(previous).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    let existing: HashMap<String, u64> = old_files.iter().map(|(path, bytes)| (path.clone(), xxh3_64(bytes))).collect();
+
+    let mut files = unix_paths(files);
+    let summary = summarize(&files, &existing, remove_stale);
+
+    if !remove_stale {
+        old_files.retain(|path, _| !files.contains_key(path));
+        files.extend(old_files);
+    }
+
+    let archive = write_archive(&files).map_err(io::Error::from)?;
+    Ok(ArchiveUpdate { archive, summary })
+}
+
+fn summarize(files: &HashMap<String, Vec<u8>>, existing: &HashMap<String, u64>, remove_stale: bool) -> ExportSummary {
+    let mut summary = ExportSummary::default();
+    for (path, bytes) in files {
+        match existing.get(path) {
+            Some(hash) if *hash == xxh3_64(bytes) => summary.unchanged.push(path.clone()),
+            Some(_) => summary.modified.push(path.clone()),
+            None => summary.added.push(path.clone()),
+        }
+    }
+
+    if remove_stale {
+        summary.removed = existing.keys().filter(|path| !files.contains_key(*path)).cloned().collect();
+    }
+
+    summary.added.sort_unstable();
+    summary.modified.sort_unstable();
+    summary.unchanged.sort_unstable();
+    summary.removed.sort_unstable();
+    summary
+}
+
+fn unix_paths(files: HashMap<String, Vec<u8>>) -> HashMap<String, Vec<u8>> {
+    files.into_iter().map(|(path, bytes)| (path_to_unix(&path), bytes)).collect()
+}
@@ -1,3 +1,6 @@
 pub mod native;
 pub mod browser;
-pub mod node;
\ No newline at end of file
+pub mod node;
+pub mod common;
+pub mod archive;
+pub mod incremental;
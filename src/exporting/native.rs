@@ -2,102 +2,105 @@
 
 use std::io;
 use std::fs;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::Path;
+use xxhash_rust::xxh3::xxh3_64;
 use crate::sample::SampleStore;
-use crate::utils::io::change_extension;
-use crate::Binary;
+use crate::exporting::archive::*;
+use crate::exporting::common::*;
+use crate::exporting::incremental::{ExportSummary, plan_export};
 use crate::FluXisSkin;
 use crate::OsuSkin;
-use crate::Store;
-use crate::{osu, fluxis, texture::TextureStore};
-
-pub fn export_binaries<T, S, F>(
-    store: &S,
-    path: &str,
-    mut exporter: F,
-) -> io::Result<()>
+use crate::osu::OsuExportOptions;
+use crate::utils::io::safe_file_name;
+use crate::{osu, fluxis, etterna, malody, texture::TextureStore};
+
+pub fn export_files<I>(files: I, path: impl AsRef<Path>) -> io::Result<()>
 where
-    S: Store<T>,
-    T: Binary + 'static,
-    F: FnMut(&T, &str) -> io::Result<()>,
+    I: IntoIterator<Item = (String, Vec<u8>)>,
 {
-    fs::create_dir_all(path)?;
-    
-    let mut result: io::Result<()> = Ok(());
-    store.for_each(|item| {
-        if result.is_err() { return; }
-        
-        if let Err(e) = exporter(item, path) {
-            result = Err(e);
+    let base = path.as_ref();
+    fs::create_dir_all(base)?;
+
+    for (rel_path, bytes) in files {
+        let out = base.join(&rel_path);
+        if let Some(parent) = out.parent() {
+            fs::create_dir_all(parent)?;
         }
-    });
-    result?;
-    
+        fs::write(&out, &bytes)?;
+    }
     Ok(())
 }
 
-pub fn export_textures(textures: &TextureStore, path: &str) -> io::Result<()> {
-    use rayon::prelude::*;
-    use std::sync::Arc;
+/// lists what the last incremental export wrote into a folder, only those files are ever removed as stale
+const EXPORT_MANIFEST: &str = ".rgskin-export";
+
+/// only writes what isn't already in `path` with the same content, stale files are the ones an earlier
+/// export into `path` wrote that aren't part of `files`
+pub fn export_files_incremental(files: HashMap<String, Vec<u8>>, path: impl AsRef<Path>, remove_stale: bool) -> io::Result<ExportSummary> {
+    let base = path.as_ref();
+    let mut existing = HashMap::new();
+    if base.is_dir() {
+        hash_dir(base, "", &mut existing)?;
+    }
+    existing.remove(EXPORT_MANIFEST);
 
-    fs::create_dir_all(path)?;
+    let exported: HashSet<String> = match fs::read_to_string(base.join(EXPORT_MANIFEST)) {
+        Ok(manifest) => manifest.lines().filter(|line| !line.is_empty()).map(str::to_string).collect(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => HashSet::new(),
+        Err(e) => return Err(e),
+    };
 
-    let arcs: Vec<Arc<std::sync::RwLock<crate::io::texture::Texture>>> = textures
-        .textures
-        .iter()
-        .map(|entry| Arc::clone(entry.value()))
-        .collect();
+    // removing first, so nothing that was just written can be removed after it
+    let (to_write, summary) = plan_export(files, &existing, &exported, remove_stale);
+    for rel_path in &summary.removed {
+        fs::remove_file(base.join(rel_path))?;
+    }
+    export_files(to_write, base)?;
 
-    let encoded: Vec<io::Result<Option<(String, Vec<u8>)>>> = arcs
-        .par_iter()
-        .map(|arc| {
-            let texture = arc.read().unwrap();
-            let out_path = change_extension(texture.get_path(), "png");
-
-            if let Some(img) = texture.state().as_loaded() {
-                let mut bytes = Vec::new();
-                img.write_to(
-                    &mut std::io::Cursor::new(&mut bytes),
-                    image::ImageFormat::Png,
-                )
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-                Ok(Some((out_path, bytes)))
-            } else if let Some(raw) = texture.state().as_unloaded() {
-                Ok(Some((out_path, raw.clone())))
-            } else {
-                Ok(None)
-            }
-        })
+    // files from earlier exports that were kept stay removable later on
+    let manifest: BTreeSet<&String> = exported.iter()
+        .filter(|path| existing.contains_key(*path) && summary.removed.binary_search(path).is_err())
+        .chain(&summary.added)
+        .chain(&summary.modified)
+        .chain(&summary.unchanged)
         .collect();
+    let manifest: Vec<&str> = manifest.into_iter().map(String::as_str).collect();
+    fs::write(base.join(EXPORT_MANIFEST), manifest.join("\n"))?;
 
-    let base = Path::new(path);
-    for result in encoded {
-        if let Some((rel_path, bytes)) = result? {
-            let out = base.join(&rel_path);
-            if let Some(parent) = out.parent() {
-                fs::create_dir_all(parent)?;
-            }
-            fs::write(&out, &bytes)?;
+    Ok(summary)
+}
+
+fn hash_dir(dir: &Path, prefix: &str, hashes: &mut HashMap<String, u64>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let rel_path = if prefix.is_empty() { name } else { format!("{prefix}/{name}") };
+        if entry.file_type()?.is_dir() {
+            hash_dir(&entry.path(), &rel_path, hashes)?;
+        } else {
+            hashes.insert(rel_path, xxh3_64(&fs::read(entry.path())?));
         }
     }
     Ok(())
 }
 
+pub fn export_textures(textures: &TextureStore, path: &str) -> io::Result<()> {
+    export_files(encode_textures(textures)?, path)
+}
+
 pub fn export_samples(samples: &SampleStore, path: &str) -> io::Result<()> {
-    export_binaries(samples, path, |sample, base_path| {
-        let sample_path_with_ext = change_extension(sample.get_path(), "wav"); // TODO: preserve original extension
-        let output_path = Path::new(base_path).join(&sample_path_with_ext);
-        
-        if let Some(parent) = output_path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        
-        if let Some(bytes) = sample.get_data() {
-            fs::write(&output_path, bytes)
-        } else {
-            Ok(())
-        }
-    })
+    export_files(encode_samples(samples), path)
+}
+
+/// the folder usually has other files in it, so nothing is removed
+pub fn export_textures_incremental(textures: &TextureStore, path: &str) -> io::Result<ExportSummary> {
+    export_files_incremental(encode_textures(textures)?.into_iter().collect(), path, false)
+}
+
+/// the folder usually has other files in it, so nothing is removed
+pub fn export_samples_incremental(samples: &SampleStore, path: &str) -> io::Result<ExportSummary> {
+    export_files_incremental(encode_samples(samples).into_iter().collect(), path, false)
 }
 
 pub fn export_osu_ini(skin_ini: &osu::OsuSkinIni, path: &str) -> io::Result<()> {
@@ -136,7 +139,29 @@ pub fn export_fluxis_layout_json(layout_json: &fluxis::FluXisLayout, path: &str)
     Ok(())
 }
 
+pub fn export_malody_conf_json(conf_json: &malody::ConfJson, path: &str) -> io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json_content = conf_json.to_string();
+    fs::write(path, json_content)?;
+    Ok(())
+}
+
+pub fn export_etterna_metrics(metrics: &etterna::EtternaMetrics, path: &str) -> io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let ini_content = metrics.to_string();
+    fs::write(path, ini_content)?;
+    Ok(())
+}
+
 pub fn export_osu_skin(skin: &OsuSkin, path: &str) -> io::Result<()> {
+    export_osu_skin_with(skin, path, &OsuExportOptions::default())
+}
+
+pub fn export_osu_skin_with(skin: &OsuSkin, path: &str, options: &OsuExportOptions) -> io::Result<()> {
     let skin_ini = &skin.skin_ini;
     let skin_path = Path::new(path).join(&skin_ini.general.name);
     fs::create_dir_all(&skin_path)?;
@@ -144,7 +169,7 @@ pub fn export_osu_skin(skin: &OsuSkin, path: &str) -> io::Result<()> {
     let ini_path = skin_path.join("skin.ini");
     export_osu_ini(skin_ini, ini_path.to_str().unwrap())?;
     
-    export_textures(&skin.textures, skin_path.to_str().unwrap())?;
+    export_files(encode_osu_textures(&skin.textures, options)?, skin_path.to_str().unwrap())?;
     export_samples(&skin.samples, skin_path.to_str().unwrap())?;
     
     Ok(())
@@ -177,3 +202,58 @@ pub fn export_fluxis_skin(skin: &FluXisSkin, path: &str) -> io::Result<()> {
     
     Ok(())
 }
+
+pub fn export_malody_skin(skin: &malody::MalodySkin, path: &str) -> io::Result<()> {
+    let conf_json = &skin.conf_json;
+    let skin_path = Path::new(path).join(&conf_json.meta.name);
+    fs::create_dir_all(&skin_path)?;
+
+    let json_path = skin_path.join("conf.json");
+    export_malody_conf_json(conf_json, json_path.to_str().unwrap())?;
+
+    export_textures(&skin.textures, skin_path.to_str().unwrap())?;
+    export_samples(&skin.samples, skin_path.to_str().unwrap())?;
+
+    Ok(())
+}
+
+pub fn export_etterna_noteskin(skin: &etterna::EtternaNoteskin, path: &str) -> io::Result<()> {
+    let skin_path = Path::new(path).join(&skin.name);
+    export_files(etterna_noteskin_to_files(skin)?, skin_path.to_str().unwrap())
+}
+
+macro_rules! impl_skin_incremental_exporter {
+    ($fn_name:ident, $to_files:ident, $skin_type:ty, $($name:ident).+) => {
+        /// like the full export into the skin's folder, but leaves files that didn't change alone.
+        /// the folder is named like the archive would be, a skin without a usable name is refused
+        pub fn $fn_name(skin: &$skin_type, path: &str, remove_stale: bool) -> io::Result<ExportSummary> {
+            let folder = safe_file_name(&skin.$($name).+);
+            if folder.is_empty() {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, "the skin needs a name to be exported into its own folder"));
+            }
+            export_files_incremental($to_files(skin)?, Path::new(path).join(folder), remove_stale)
+        }
+    };
+}
+
+impl_skin_incremental_exporter!(export_osu_skin_incremental, osu_skin_to_files, OsuSkin, skin_ini.general.name);
+impl_skin_incremental_exporter!(export_quaver_skin_incremental, quaver_skin_to_files, crate::quaver::QuaSkin, skin_ini.general.name);
+impl_skin_incremental_exporter!(export_fluxis_skin_incremental, fluxis_skin_to_files, FluXisSkin, skin_json.info.name);
+impl_skin_incremental_exporter!(export_malody_skin_incremental, malody_skin_to_files, malody::MalodySkin, conf_json.meta.name);
+impl_skin_incremental_exporter!(export_etterna_noteskin_incremental, etterna_noteskin_to_files, etterna::EtternaNoteskin, name);
+
+macro_rules! impl_skin_archive_file_exporter {
+    ($fn_name:ident, $to_archive:ident, $archive_name:ident, $skin_type:ty) => {
+        pub fn $fn_name(skin: &$skin_type, path: &str) -> io::Result<()> {
+            let bytes = $to_archive(skin)?;
+            fs::create_dir_all(path)?;
+            fs::write(Path::new(path).join($archive_name(skin)), bytes)
+        }
+    };
+}
+
+impl_skin_archive_file_exporter!(export_osu_skin_to_archive_file, export_osu_skin_to_archive, osu_archive_name, OsuSkin);
+impl_skin_archive_file_exporter!(export_quaver_skin_to_archive_file, export_quaver_skin_to_archive, quaver_archive_name, crate::quaver::QuaSkin);
+impl_skin_archive_file_exporter!(export_fluxis_skin_to_archive_file, export_fluxis_skin_to_archive, fluxis_archive_name, FluXisSkin);
+impl_skin_archive_file_exporter!(export_malody_skin_to_archive_file, export_malody_skin_to_archive, malody_archive_name, malody::MalodySkin);
+impl_skin_archive_file_exporter!(export_etterna_noteskin_to_archive_file, export_etterna_noteskin_to_archive, etterna_archive_name, etterna::EtternaNoteskin);
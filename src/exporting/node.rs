@@ -4,94 +4,22 @@
 use crate::io::node;
 use std::io;
 use crate::sample::SampleStore;
-use crate::utils::io::change_extension;
-use crate::Binary;
+use crate::exporting::archive::*;
+use crate::exporting::common::{encode_osu_textures, encode_samples, encode_textures, etterna_noteskin_to_files};
 use crate::FluXisSkin;
 use crate::OsuSkin;
-use crate::Store;
-use crate::{osu, fluxis, texture::TextureStore};
-
-pub fn export_binaries<T, S, F>(
-    store: &S,
-    path: &str,
-    mut exporter: F,
-) -> io::Result<()>
+use crate::osu::OsuExportOptions;
+use crate::{osu, fluxis, etterna, malody, texture::TextureStore};
+
+pub fn export_files<I>(files: I, path: &str) -> io::Result<()>
 where
-    S: Store<T>,
-    T: Binary + 'static,
-    F: FnMut(&T, &str) -> io::Result<()>,
+    I: IntoIterator<Item = (String, Vec<u8>)>,
 {
-    node::create_dir_all(path)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
-    
-    let mut result: io::Result<()> = Ok(());
-    store.for_each(|item| {
-        if result.is_err() { return; }
-        
-        if let Err(e) = exporter(item, path) {
-            result = Err(e);
-        }
-    });
-    result?;
-    
-    Ok(())
-}
-
-pub fn export_textures(textures: &TextureStore, path: &str) -> io::Result<()> {
-    use rayon::prelude::*;
-    use std::sync::Arc;
-
     node::create_dir_all(path)
         .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
 
-    let arcs: Vec<Arc<std::sync::RwLock<crate::io::texture::Texture>>> = textures
-        .textures
-        .iter()
-        .map(|entry| Arc::clone(entry.value()))
-        .collect();
-
-    let encoded: Vec<io::Result<Option<(String, Vec<u8>)>>> = arcs
-        .par_iter()
-        .map(|arc| {
-            let texture = arc.read().unwrap();
-            let out_path = change_extension(texture.get_path(), "png");
-
-            if let Some(img) = texture.state().as_loaded() {
-                let mut bytes = Vec::new();
-                img.write_to(
-                    &mut std::io::Cursor::new(&mut bytes),
-                    image::ImageFormat::Png,
-                )
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-                Ok(Some((out_path, bytes)))
-            } else if let Some(raw) = texture.state().as_unloaded() {
-                Ok(Some((out_path, raw.clone())))
-            } else {
-                Ok(None)
-            }
-        })
-        .collect();
-
-    for result in encoded {
-        if let Some((rel_path, bytes)) = result? {
-            let output_path = node::join_path(path, &rel_path);
-            if let Some(slash_pos) = output_path.rfind('/') {
-                let parent = &output_path[..slash_pos];
-                node::create_dir_all(parent)
-                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
-            }
-            node::write_file(&output_path, &bytes)
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
-        }
-    }
-    Ok(())
-}
-
-pub fn export_samples(samples: &SampleStore, path: &str) -> io::Result<()> {
-    export_binaries(samples, path, |sample, base_path| {
-        let sample_path_with_ext = change_extension(sample.get_path(), "wav");
-        let output_path = node::join_path(base_path, &sample_path_with_ext);
-        
+    for (rel_path, bytes) in files {
+        let output_path = node::join_path(path, &rel_path);
         if let Some(slash_pos) = output_path.rfind('/') {
             let parent = &output_path[..slash_pos];
             node::create_dir_all(parent)
@@ -101,14 +29,18 @@ pub fn export_samples(samples: &SampleStore, path: &str) -> io::Result<()> {
             node::create_dir_all(parent)
                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
         }
-        
-        if let Some(bytes) = sample.get_data() {
-            node::write_file(&output_path, bytes)
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
-        } else {
-            Ok(())
-        }
-    })
+        node::write_file(&output_path, &bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    }
+    Ok(())
+}
+
+pub fn export_textures(textures: &TextureStore, path: &str) -> io::Result<()> {
+    export_files(encode_textures(textures)?, path)
+}
+
+pub fn export_samples(samples: &SampleStore, path: &str) -> io::Result<()> {
+    export_files(encode_samples(samples), path)
 }
 
 pub fn export_osu_ini(skin_ini: &osu::OsuSkinIni, path: &str) -> io::Result<()> {
@@ -187,7 +119,49 @@ pub fn export_fluxis_layout_json(layout_json: &fluxis::FluXisLayout, path: &str)
     Ok(())
 }
 
+pub fn export_malody_conf_json(conf_json: &malody::ConfJson, path: &str) -> io::Result<()> {
+    if let Some(slash_pos) = path.rfind('/') {
+        let parent = &path[..slash_pos];
+        node::create_dir_all(parent)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    } else if let Some(slash_pos) = path.rfind('\\') {
+        let parent = &path[..slash_pos];
+        node::create_dir_all(parent)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    }
+    
+    let json_content = conf_json.to_string();
+    
+    node::write_file(path, json_content.as_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    
+    Ok(())
+}
+
+pub fn export_etterna_metrics(metrics: &etterna::EtternaMetrics, path: &str) -> io::Result<()> {
+    if let Some(slash_pos) = path.rfind('/') {
+        let parent = &path[..slash_pos];
+        node::create_dir_all(parent)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    } else if let Some(slash_pos) = path.rfind('\\') {
+        let parent = &path[..slash_pos];
+        node::create_dir_all(parent)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    }
+    
+    let ini_content = metrics.to_string();
+    
+    node::write_file(path, ini_content.as_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    
+    Ok(())
+}
+
 pub fn export_osu_skin(skin: &OsuSkin, path: &str) -> io::Result<()> {
+    export_osu_skin_with(skin, path, &OsuExportOptions::default())
+}
+
+pub fn export_osu_skin_with(skin: &OsuSkin, path: &str, options: &OsuExportOptions) -> io::Result<()> {
     let skin_ini = &skin.skin_ini;
     let skin_path = node::join_path(path, &skin_ini.general.name);
     
@@ -197,7 +171,7 @@ pub fn export_osu_skin(skin: &OsuSkin, path: &str) -> io::Result<()> {
     let ini_path = node::join_path(&skin_path, "skin.ini");
     export_osu_ini(skin_ini, &ini_path)?;
     
-    export_textures(&skin.textures, &skin_path)?;
+    export_files(encode_osu_textures(&skin.textures, options)?, &skin_path)?;
     export_samples(&skin.samples, &skin_path)?;
     
     Ok(())
@@ -233,4 +207,43 @@ pub fn export_fluxis_skin(skin: &FluXisSkin, path: &str) -> io::Result<()> {
     export_samples(&skin.samples, &skin_path)?;
     
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn export_malody_skin(skin: &malody::MalodySkin, path: &str) -> io::Result<()> {
+    let conf_json = &skin.conf_json;
+    let skin_path = node::join_path(path, &conf_json.meta.name);
+    
+    node::create_dir_all(&skin_path)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    
+    let json_path = node::join_path(&skin_path, "conf.json");
+    export_malody_conf_json(conf_json, &json_path)?;
+    
+    export_textures(&skin.textures, &skin_path)?;
+    export_samples(&skin.samples, &skin_path)?;
+    
+    Ok(())
+}
+
+pub fn export_etterna_noteskin(skin: &etterna::EtternaNoteskin, path: &str) -> io::Result<()> {
+    let skin_path = node::join_path(path, &skin.name);
+    export_files(etterna_noteskin_to_files(skin)?, &skin_path)
+}
+
+macro_rules! impl_skin_archive_file_exporter {
+    ($fn_name:ident, $to_archive:ident, $archive_name:ident, $skin_type:ty) => {
+        pub fn $fn_name(skin: &$skin_type, path: &str) -> io::Result<()> {
+            let bytes = $to_archive(skin)?;
+            node::create_dir_all(path)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+            node::write_file(&node::join_path(path, &$archive_name(skin)), &bytes)
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+        }
+    };
+}
+
+impl_skin_archive_file_exporter!(export_osu_skin_to_archive_file, export_osu_skin_to_archive, osu_archive_name, OsuSkin);
+impl_skin_archive_file_exporter!(export_quaver_skin_to_archive_file, export_quaver_skin_to_archive, quaver_archive_name, crate::quaver::QuaSkin);
+impl_skin_archive_file_exporter!(export_fluxis_skin_to_archive_file, export_fluxis_skin_to_archive, fluxis_archive_name, FluXisSkin);
+impl_skin_archive_file_exporter!(export_malody_skin_to_archive_file, export_malody_skin_to_archive, malody_archive_name, malody::MalodySkin);
+impl_skin_archive_file_exporter!(export_etterna_noteskin_to_archive_file, export_etterna_noteskin_to_archive, etterna_archive_name, etterna::EtternaNoteskin);
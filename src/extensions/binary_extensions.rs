@@ -1,4 +1,4 @@
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, PoisonError, RwLock};
 use js_sys::{Uint8Array, ArrayBuffer};
 use crate::{Binary, BinaryState};
 
@@ -8,6 +8,9 @@ pub trait BinaryArcExt<T: Binary> {
     fn is_unloaded(&self) -> bool;
     fn is_empty(&self) -> bool;
 
+    /// decodes unloaded bytes in place, true if there's loaded data afterwards
+    fn ensure_loaded(&self) -> bool;
+
     fn get_path(&self) -> String;
     fn path_ref<F, R>(&self, f: F) -> R
     where
@@ -88,34 +91,44 @@ where
     T: Binary,
 {
     fn has_data(&self) -> bool {
-        self.read().unwrap().has_data()
+        self.read().unwrap_or_else(PoisonError::into_inner).has_data()
     }
     
     fn is_loaded(&self) -> bool {
-        self.read().unwrap().is_loaded()
+        self.read().unwrap_or_else(PoisonError::into_inner).is_loaded()
     }
     
     fn is_unloaded(&self) -> bool {
-        self.read().unwrap().is_unloaded()
+        self.read().unwrap_or_else(PoisonError::into_inner).is_unloaded()
     }
     
     fn is_empty(&self) -> bool {
-        self.read().unwrap().is_empty()
+        self.read().unwrap_or_else(PoisonError::into_inner).is_empty()
+    }
+
+    fn ensure_loaded(&self) -> bool {
+        if !self.is_unloaded() {
+            return self.is_loaded();
+        }
+
+        // another thread may have decoded it while this one waited for the lock
+        let mut guard = self.write().unwrap_or_else(PoisonError::into_inner);
+        guard.load().is_ok() && guard.is_loaded()
     }
 
     fn get_path(&self) -> String {
-        self.read().unwrap().get_path().to_string()
+        self.read().unwrap_or_else(PoisonError::into_inner).get_path().to_string()
     }
 
     fn get_hash(&self) -> Option<u64> {
-        self.read().unwrap().get_hash()
+        self.read().unwrap_or_else(PoisonError::into_inner).get_hash()
     }
     
     fn path_ref<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&str) -> R,
     {
-        let guard = self.read().unwrap();
+        let guard = self.read().unwrap_or_else(PoisonError::into_inner);
         f(guard.get_path())
     }
     
@@ -123,107 +136,109 @@ where
     where
         T::LoadedData: Clone,
     {
-        self.read().unwrap().state().as_loaded().cloned()
+        self.ensure_loaded();
+        self.read().unwrap_or_else(PoisonError::into_inner).state().as_loaded().cloned()
     }
     
     fn get_unloaded_data(&self) -> Option<Vec<u8>> {
-        self.read().unwrap().state().as_unloaded().cloned()
+        self.read().unwrap_or_else(PoisonError::into_inner).state().as_unloaded().cloned()
     }
     
     fn set_data(&self, data: T::LoadedData) {
-        *self.write().unwrap().state_mut() = BinaryState::Loaded(data);
+        *self.write().unwrap_or_else(PoisonError::into_inner).state_mut() = BinaryState::Loaded(data);
     }
     
     fn set_unloaded_data(&self, bytes: Vec<u8>) {
-        *self.write().unwrap().state_mut() = BinaryState::Unloaded(bytes);
+        *self.write().unwrap_or_else(PoisonError::into_inner).state_mut() = BinaryState::Unloaded(bytes);
     }
     
     fn clear_data(&self) {
-        *self.write().unwrap().state_mut() = BinaryState::Empty;
+        *self.write().unwrap_or_else(PoisonError::into_inner).state_mut() = BinaryState::Empty;
     }
     
     fn replace_data(&self, data: T::LoadedData) -> BinaryState<T::LoadedData> {
         std::mem::replace(
-            self.write().unwrap().state_mut(),
+            self.write().unwrap_or_else(PoisonError::into_inner).state_mut(),
             BinaryState::Loaded(data)
         )
     }
     
     fn replace_unloaded_data(&self, bytes: Vec<u8>) -> BinaryState<T::LoadedData> {
         std::mem::replace(
-            self.write().unwrap().state_mut(),
+            self.write().unwrap_or_else(PoisonError::into_inner).state_mut(),
             BinaryState::Unloaded(bytes)
         )
     }
     
     fn take_data(&self) -> Option<T::LoadedData> {
-        self.write().unwrap().state_mut().take_loaded()
+        self.write().unwrap_or_else(PoisonError::into_inner).state_mut().take_loaded()
     }
     
     fn take_unloaded_data(&self) -> Option<Vec<u8>> {
-        self.write().unwrap().state_mut().take_unloaded()
+        self.write().unwrap_or_else(PoisonError::into_inner).state_mut().take_unloaded()
     }
     
     fn load_from_bytes(&self, bytes: Vec<u8>) -> Result<(), T::Error> {
-        self.write().unwrap().set_from_bytes(bytes)
+        self.write().unwrap_or_else(PoisonError::into_inner).set_from_bytes(bytes)
     }
     
     fn load_from_bytes_unloaded(&self, bytes: Vec<u8>) -> Result<(), T::Error> {
-        self.write().unwrap().set_from_bytes_unloaded(bytes)
+        self.write().unwrap_or_else(PoisonError::into_inner).set_from_bytes_unloaded(bytes)
     }
     
     fn load_from_uint8_array(&self, array: &Uint8Array) -> Result<(), T::Error> {
-        self.write().unwrap().set_from_uint8_array(array)
+        self.write().unwrap_or_else(PoisonError::into_inner).set_from_uint8_array(array)
     }
     
     fn load_from_uint8_array_unloaded(&self, array: &Uint8Array) -> Result<(), T::Error> {
-        self.write().unwrap().set_from_uint8_array_unloaded(array)
+        self.write().unwrap_or_else(PoisonError::into_inner).set_from_uint8_array_unloaded(array)
     }
     
     fn load_from_array_buffer(&self, buffer: &ArrayBuffer) -> Result<(), T::Error> {
-        self.write().unwrap().set_from_array_buffer(buffer)
+        self.write().unwrap_or_else(PoisonError::into_inner).set_from_array_buffer(buffer)
     }
     
     fn load_from_array_buffer_unloaded(&self, buffer: &ArrayBuffer) -> Result<(), T::Error> {
-        self.write().unwrap().set_from_array_buffer_unloaded(buffer)
+        self.write().unwrap_or_else(PoisonError::into_inner).set_from_array_buffer_unloaded(buffer)
     }
     
     fn to_bytes(&self) -> Result<Vec<u8>, T::Error> {
-        self.read().unwrap().to_bytes()
+        self.read().unwrap_or_else(PoisonError::into_inner).to_bytes()
     }
     
     fn to_uint8_array(&self) -> Result<Uint8Array, T::Error> {
-        self.read().unwrap().to_uint8_array()
+        self.read().unwrap_or_else(PoisonError::into_inner).to_uint8_array()
     }
     
     fn to_array_buffer(&self) -> Result<ArrayBuffer, T::Error> {
-        self.read().unwrap().to_array_buffer()
+        self.read().unwrap_or_else(PoisonError::into_inner).to_array_buffer()
     }
     
     fn load(&self) -> Result<(), T::Error> {
-        self.write().unwrap().load()
+        self.write().unwrap_or_else(PoisonError::into_inner).load()
     }
     
     fn unload(&self) -> Result<(), T::Error> {
-        self.write().unwrap().unload()
+        self.write().unwrap_or_else(PoisonError::into_inner).unload()
     }
     
     fn clone_data(&self) -> Option<T::LoadedData>
     where
         T::LoadedData: Clone,
     {
-        self.read().unwrap().state().as_loaded().cloned()
+        self.ensure_loaded();
+        self.read().unwrap_or_else(PoisonError::into_inner).state().as_loaded().cloned()
     }
     
     fn clone_unloaded_data(&self) -> Option<Vec<u8>> {
-        self.read().unwrap().state().as_unloaded().cloned()
+        self.read().unwrap_or_else(PoisonError::into_inner).state().as_unloaded().cloned()
     }
     
     fn state_ref<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&BinaryState<T::LoadedData>) -> R,
     {
-        let guard = self.read().unwrap();
+        let guard = self.read().unwrap_or_else(PoisonError::into_inner);
         f(guard.state())
     }
     
@@ -231,7 +246,7 @@ where
     where
         F: FnOnce(&mut BinaryState<T::LoadedData>) -> R,
     {
-        let mut guard = self.write().unwrap();
+        let mut guard = self.write().unwrap_or_else(PoisonError::into_inner);
         f(guard.state_mut())
     }
     
@@ -239,7 +254,8 @@ where
     where
         F: FnOnce(&T::LoadedData) -> R,
     {
-        let guard = self.read().unwrap();
+        self.ensure_loaded();
+        let guard = self.read().unwrap_or_else(PoisonError::into_inner);
         guard.state().as_loaded().map(f)
     }
     
@@ -247,7 +263,8 @@ where
     where
         F: FnOnce(&mut T::LoadedData) -> R,
     {
-        let mut guard = self.write().unwrap();
+        self.ensure_loaded();
+        let mut guard = self.write().unwrap_or_else(PoisonError::into_inner);
         match guard.state_mut() {
             BinaryState::Loaded(data) => Some(f(data)),
             _ => None,
@@ -258,7 +275,7 @@ where
     where
         F: FnOnce(&Vec<u8>) -> R,
     {
-        let guard = self.read().unwrap();
+        let guard = self.read().unwrap_or_else(PoisonError::into_inner);
         guard.state().as_unloaded().map(f)
     }
     
@@ -266,7 +283,7 @@ where
     where
         F: FnOnce(&mut Vec<u8>) -> R,
     {
-        let mut guard = self.write().unwrap();
+        let mut guard = self.write().unwrap_or_else(PoisonError::into_inner);
         match guard.state_mut() {
             BinaryState::Unloaded(bytes) => Some(f(bytes)),
             _ => None,
@@ -277,7 +294,8 @@ where
     where
         F: FnOnce(&T::LoadedData) -> R,
     {
-        let guard = self.read().unwrap();
+        self.ensure_loaded();
+        let guard = self.read().unwrap_or_else(PoisonError::into_inner);
         f(guard.state().as_loaded().expect("Data is not loaded"))
     }
     
@@ -285,7 +303,7 @@ where
     where
         F: FnOnce(&Vec<u8>) -> R,
     {
-        let guard = self.read().unwrap();
+        let guard = self.read().unwrap_or_else(PoisonError::into_inner);
         f(guard.state().as_unloaded().expect("Data is not unloaded"))
     }
 }
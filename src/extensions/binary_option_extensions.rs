@@ -1,4 +1,4 @@
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, PoisonError, RwLock};
 use js_sys::{Uint8Array, ArrayBuffer};
 use crate::{Binary, BinaryArcExt, BinaryState};
 
@@ -258,10 +258,7 @@ where
     where
         F: FnOnce(&T::LoadedData) -> R,
     {
-        self.as_ref().and_then(|arc| {
-            let guard = arc.read().unwrap();
-            guard.state().as_loaded().map(f)
-        })
+        self.as_ref().and_then(|arc| arc.data_ref(f))
     }
     
     fn with_unloaded<F, R>(&self, f: F) -> Option<R>
@@ -269,7 +266,7 @@ where
         F: FnOnce(&Vec<u8>) -> R,
     {
         self.as_ref().and_then(|arc| {
-            let guard = arc.read().unwrap();
+            let guard = arc.read().unwrap_or_else(PoisonError::into_inner);
             guard.state().as_unloaded().map(f)
         })
     }
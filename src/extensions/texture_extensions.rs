@@ -1,8 +1,9 @@
 #![allow(unused)]
 #![warn(unused_imports)]
 
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, PoisonError, RwLock};
 use image::RgbaImage;
+use crate::BinaryState;
 use crate::texture::Texture;
 
 pub trait TextureArcExt {
@@ -29,11 +30,11 @@ pub trait TextureArcExt {
 
 impl TextureArcExt for Arc<RwLock<Texture>> {
     fn clone_texture(&self) -> Texture {
-        self.read().unwrap().clone()
+        self.read().unwrap_or_else(PoisonError::into_inner).clone()
     }
     
     fn take_texture(&self) -> Texture {
-        std::mem::replace(&mut *self.write().unwrap(), Texture::default())
+        std::mem::replace(&mut *self.write().unwrap_or_else(PoisonError::into_inner), Texture::default())
     }
     
     fn get_image(&self) -> Option<RgbaImage> {
@@ -42,8 +43,8 @@ impl TextureArcExt for Arc<RwLock<Texture>> {
     }
     
     fn set_image(&self, image: RgbaImage) {
-        use crate::BinaryArcExt;
-        self.set_data(image)
+        use crate::Binary;
+        self.write().unwrap_or_else(PoisonError::into_inner).set_data(image)
     }
     
     fn take_image(&self) -> Option<RgbaImage> {
@@ -69,7 +70,13 @@ impl TextureArcExt for Arc<RwLock<Texture>> {
         F: FnOnce(&mut RgbaImage) -> R,
     {
         use crate::BinaryArcExt;
-        self.data_mut(f)
+        self.ensure_loaded();
+        let mut guard = self.write().unwrap_or_else(PoisonError::into_inner);
+        guard.forget_source();
+        match &mut guard.data {
+            BinaryState::Loaded(img) => Some(f(img)),
+            _ => None,
+        }
     }
     
     fn with_image<F, R>(&self, f: F) -> R
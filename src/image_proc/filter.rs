@@ -0,0 +1,29 @@
+use fast_image_resize::FilterType;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// `fast_image_resize::FilterType` without the parts wasm can't carry
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResizeFilter {
+    Box,
+    Bilinear,
+    #[default]
+    Hamming,
+    CatmullRom,
+    Mitchell,
+    Lanczos3,
+}
+
+impl From<ResizeFilter> for FilterType {
+    fn from(filter: ResizeFilter) -> Self {
+        match filter {
+            ResizeFilter::Box => FilterType::Box,
+            ResizeFilter::Bilinear => FilterType::Bilinear,
+            ResizeFilter::Hamming => FilterType::Hamming,
+            ResizeFilter::CatmullRom => FilterType::CatmullRom,
+            ResizeFilter::Mitchell => FilterType::Mitchell,
+            ResizeFilter::Lanczos3 => FilterType::Lanczos3,
+        }
+    }
+}
@@ -2,7 +2,7 @@ use fast_image_resize::FilterType;
 use image::{Rgba, RgbaImage};
 use rayon::prelude::*;
 use crate::{
-    BinaryArcExt, Store, fluxis::{SkinJson, skin_json::Keymode}, image_proc::proc::{fill_rect, overlay_image, resize_img}, prelude::TextureStore, traits::ManiaSkinConfig
+    BinaryArcExt, Store, error::ConvertError, fluxis::{SkinJson, skin_json::Keymode}, image_proc::proc::{fill_rect, overlay_image, resize_img}, prelude::TextureStore, traits::ManiaSkinConfig
 }; 
 
 pub fn generate_fluxis_preview(
@@ -10,13 +10,13 @@ pub fn generate_fluxis_preview(
     textures: &TextureStore,
     width: u32,
     height: u32
-) -> Result<RgbaImage, Box<dyn std::error::Error>> {
+) -> Result<RgbaImage, ConvertError> {
     let mut canvas = image::RgbaImage::new(width, height);
     
     draw_background(&mut canvas, textures, &skin_json.overrides.stage.background, width, height);
     
     let keymode_4k = skin_json.get_keymode(4)
-        .ok_or("4k not found in skin json")?;
+        .ok_or(ConvertError::MissingKeymode { keymode: 4 })?;
     
     let scaled_column_width = calculate_scaled_column_width(keymode_4k.column_width, width);
     let stage_x_offset = calculate_stage_offset(scaled_column_width, width);
@@ -116,11 +116,11 @@ fn draw_receptors(
     stage_x_offset: u32,
     scaled_column_width: u32,
     hit_y: u32
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<(), ConvertError> {
     for col in 0..4 {
         let x = stage_x_offset + (col * scaled_column_width);
         let receptor_key = keymode.receptor_images.get(col as usize)
-            .ok_or(format!("Receptor image not found for column {}", col))?;
+            .ok_or_else(|| missing_column("receptor image", col))?;
         
         if let Some(receptor_texture) = textures.get_shared(receptor_key) {
             if let Some(receptor_img) = receptor_texture.get_data() {
@@ -150,7 +150,7 @@ fn draw_notes(
     note_spacing: u32,
     note_gap: u32,
     canvas_height: u32
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<(), ConvertError> {
     for col in 0..4 {
         let x = stage_x_offset + (col * scaled_column_width);
         
@@ -176,23 +176,23 @@ fn draw_long_note(
     hit_y: u32,
     note_spacing: u32,
     note_gap: u32
-) -> Result<(), Box<dyn std::error::Error>> {    
+) -> Result<(), ConvertError> {    
     let ln_head_key = keymode.long_note_head_images.get(col as usize)
-        .ok_or(format!("Long note head image not found for column {}", col))?;
+        .ok_or_else(|| missing_column("long note head image", col))?;
     let ln_body_key = keymode.long_note_body_images.get(col as usize)
-        .ok_or(format!("Long note body image not found for column {}", col))?;
+        .ok_or_else(|| missing_column("long note body image", col))?;
     let ln_tail_key = keymode.long_note_tail_images.get(col as usize)
-        .ok_or(format!("Long note tail image not found for column {}", col))?;
+        .ok_or_else(|| missing_column("long note tail image", col))?;
     
     let head_img = textures.get_shared(ln_head_key)
         .and_then(|t| t.get_data())
-        .ok_or("Long note head texture not found")?;
+        .ok_or_else(|| not_loaded(ln_head_key))?;
     let body_img = textures.get_shared(ln_body_key)
         .and_then(|t| t.get_data())
-        .ok_or("Long note body texture not found")?;
+        .ok_or_else(|| not_loaded(ln_body_key))?;
     let tail_img = textures.get_shared(ln_tail_key)
         .and_then(|t| t.get_data())
-        .ok_or("Long note tail texture not found")?;
+        .ok_or_else(|| not_loaded(ln_tail_key))?;
     
     let head_aspect = head_img.height() as f32 / head_img.width() as f32;
     let head_height = (scaled_column_width as f32 * head_aspect) as u32;
@@ -243,9 +243,9 @@ fn draw_normal_notes(
     note_spacing: u32,
     note_gap: u32,
     canvas_height: u32
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<(), ConvertError> {
     let note_key = keymode.normal_note_images.get(col as usize)
-        .ok_or(format!("Note image not found for column {}", col))?;
+        .ok_or_else(|| missing_column("note image", col))?;
     
     if let Some(note_texture) = textures.get_shared(note_key) {
         if let Some(note_img) = note_texture.get_data() {
@@ -273,3 +273,11 @@ fn draw_normal_notes(
     
     Ok(())
 }
+
+fn missing_column(field: &str, col: u32) -> ConvertError {
+    ConvertError::InvalidLayout { reason: format!("4K has no {field} for column {col}") }
+}
+
+fn not_loaded(path: &str) -> ConvertError {
+    ConvertError::ImageProcessing { path: path.to_string(), reason: "texture is not loaded".to_string() }
+}
@@ -1,33 +1,45 @@
+/// runs `$processor` on the texture's image and stores the result,
+/// unloaded textures are decoded first
 #[macro_export]
 macro_rules! process_texture {
     ($texture:expr, $processor:expr) => {
         {
             use crate::Binary;
-            let processed_image = {
-                let mut write_guard = $texture.write().unwrap();
-                if let Some(img) = write_guard.take_data() {
-                    $processor(img)
-                } else {
-                    return Err("No texture data available".into());
-                }
+            use crate::error::ConvertError;
+            let mut write_guard = $texture.write().map_err(|e| ConvertError::PoisonedLock {
+                path: e.into_inner().get_path().to_string(),
+            })?;
+            if write_guard.is_unloaded() {
+                let _ = write_guard.load();
+            }
+            let Some(img) = write_guard.take_data() else {
+                return Err(ConvertError::ImageProcessing {
+                    path: write_guard.get_path().to_string(),
+                    reason: "no texture data available".to_string(),
+                });
             };
-            
-            let mut write_guard = $texture.write().unwrap();
+            let processed_image = $processor(img);
             write_guard.set_data(processed_image);
             Ok(())
         }
     };
 }
 
+/// edits the texture's image in place, unloaded textures are left alone
 #[macro_export]
 macro_rules! process_texture_mut {
     ($texture:expr, $processor:expr) => {
         {
-            use crate::BinaryState;
-            let mut write_guard = $texture.write().unwrap();
+            use crate::{Binary, BinaryState};
+            use crate::error::ConvertError;
+            let mut write_guard = $texture.write().map_err(|e| ConvertError::PoisonedLock {
+                path: e.into_inner().get_path().to_string(),
+            })?;
             if let BinaryState::Loaded(ref mut img) = write_guard.data {
                 $processor(img);
+                write_guard.forget_source();
             }
+            Ok(())
         }
     };
 }
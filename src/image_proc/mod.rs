@@ -1,7 +1,11 @@
 pub mod proc;
+mod filter;
 mod fluxis;
 mod osu;
+mod preview;
 
 pub(crate) mod macros;
 pub use fluxis::*;
-pub use osu::*;
\ No newline at end of file
+pub use osu::*;
+pub use preview::{generate_preview, generate_animated_preview};
+pub use filter::ResizeFilter;
@@ -2,11 +2,11 @@ use std::sync::{Arc, RwLock};
 use fast_image_resize::FilterType;
 use image::RgbaImage;
 use crate::{
-    common::color::Rgba, image_proc::proc::{fill_rect, pad_image_vertical, resize_img, trim_image_vertical}, io::texture::Texture, process_texture, utils::osu::OsuDimensions
+    common::color::Rgba, error::ConvertError, image_proc::proc::{fill_rect, pad_image_vertical, resize_img, trim_image_vertical}, io::texture::Texture, process_texture, utils::osu::OsuDimensions
 }; 
 
 /// Replicates how the key image is drawn in osu!mania
-pub fn to_osu_column_draw(texture: &Arc<RwLock<Texture>>, column_width: u32) -> Result<(), Box<dyn std::error::Error>> {
+pub fn to_osu_column_draw(texture: &Arc<RwLock<Texture>>, column_width: u32, filter: FilterType) -> Result<(), ConvertError> {
     process_texture!(texture, |img: RgbaImage| {
         let ns = OsuDimensions::ReceptorScale.as_f32();
         let hds = OsuDimensions::ReceptorScale2x.as_f32();
@@ -18,13 +18,13 @@ pub fn to_osu_column_draw(texture: &Arc<RwLock<Texture>>, column_width: u32) ->
         let multiplier = if is_2x { hds } else { ns};
         let new_width = (column_width as f32 * multiplier) as u32;
 
-        resize_img(&trimmed_img, new_width, trimmed_img.height(), FilterType::Hamming)
+        resize_img(&trimmed_img, new_width, trimmed_img.height(), filter)
     })
 }
 
 /// Converts the key image to be displayed having the correct ratios inside osu!mania
 /// osu!mania stretches the image so we have to counter-stretch it for it to display as the original
-pub fn to_osu_column(texture: &Arc<RwLock<Texture>>, column_width: u32, receptor_offset: u32) -> Result<(), Box<dyn std::error::Error>> {
+pub fn to_osu_column(texture: &Arc<RwLock<Texture>>, column_width: u32, receptor_offset: u32, filter: FilterType) -> Result<(), ConvertError> {
     process_texture!(texture, |img: RgbaImage| {
         let ns = OsuDimensions::ReceptorScale.as_f32();
         
@@ -49,7 +49,7 @@ pub fn to_osu_column(texture: &Arc<RwLock<Texture>>, column_width: u32, receptor
 
         let new_width = (column_width as f32 / actual_scale_factor).round() as u32;
 
-        let resized_img = resize_img(&trimmed_orig, new_width, new_height, FilterType::Hamming);
+        let resized_img = resize_img(&trimmed_orig, new_width, new_height, filter);
         
         pad_image_vertical(&resized_img, 0, receptor_offset)
     })
@@ -0,0 +1,435 @@
+use std::sync::{Arc, RwLock};
+use fast_image_resize::FilterType;
+use image::{Rgba, RgbaImage};
+use image::imageops::crop_imm;
+
+use crate::error::ConvertError;
+use crate::extensions::TextureArcExt;
+use crate::image_proc::proc::{overlay_image, resize_img};
+use crate::skin::generic::{GenericManiaSkin, Keymode};
+use crate::texture::Texture;
+use crate::traits::ManiaSkin;
+
+/// the most of the canvas width the stage can take up
+const MAX_STAGE_WIDTH: f32 = 0.9;
+/// the health bar next to the stage, as part of the canvas height
+const HEALTH_BAR_HEIGHT: f32 = 0.6;
+
+const ANIMATION_FPS: u16 = 30;
+const ANIMATION_LENGTH_MS: f32 = 2000.0;
+/// time a note takes to scroll from the top of the canvas to the hit position
+const SCROLL_TIME_MS: f32 = 800.0;
+const NOTE_INTERVAL_MS: f32 = 125.0;
+const LONG_NOTE_LENGTH_MS: f32 = 300.0;
+/// how long a normal note keeps the column pressed
+const PRESS_LENGTH_MS: f32 = 80.0;
+/// for hit lighting without its own fps
+const DEFAULT_LIGHTING_FPS: f32 = 30.0;
+
+/// draws `keymode` of any skin after `to_generic_mania`, the stage is centred with a few notes
+/// in a staircase and a long note in every third column
+pub fn generate_preview(
+    skin: &GenericManiaSkin,
+    keymode: u8,
+    width: u32,
+    height: u32
+) -> Result<RgbaImage, ConvertError> {
+    let playfield = Playfield::new(skin, keymode, width, height)?;
+
+    let note_spacing = (height / 4) as i32;
+    let note_gap = (height / 40) as i32;
+    let notes: Vec<Note> = (0..playfield.lanes.len())
+        .flat_map(|column| {
+            let staircase_offset = (column as i32 % 4) * (note_spacing / 4);
+            let bottom = playfield.hit_y as i32 - note_gap - staircase_offset;
+
+            if column % 3 == 2 {
+                vec![Note { column, bottom, length: Some(note_spacing * 3 / 2) }]
+            } else {
+                (0..3).map(|i| Note { column, bottom: bottom - i * note_spacing, length: None }).collect()
+            }
+        })
+        .collect();
+
+    Ok(playfield.draw(&notes, &vec![None; playfield.lanes.len()]))
+}
+
+/// plays a short built-in chart on `keymode` as an APNG, with notes scrolling, receptors pressed
+/// and hit lighting at its fps, the same skin always gives the same bytes
+pub fn generate_animated_preview(
+    skin: &GenericManiaSkin,
+    keymode: u8,
+    width: u32,
+    height: u32
+) -> Result<Vec<u8>, ConvertError> {
+    let playfield = Playfield::new(skin, keymode, width, height)?;
+    let chart = built_in_chart(playfield.lanes.len());
+    let frame_count = (ANIMATION_LENGTH_MS * ANIMATION_FPS as f32 / 1000.0) as u32;
+
+    let to_error = |e: png::EncodingError| ConvertError::ImageProcessing {
+        path: "preview".to_string(),
+        reason: e.to_string(),
+    };
+
+    let mut buf = Vec::new();
+    let mut encoder = png::Encoder::new(&mut buf, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_compression(png::Compression::Fast);
+    encoder.set_animated(frame_count, 0).map_err(to_error)?;
+    encoder.set_frame_delay(1, ANIMATION_FPS).map_err(to_error)?;
+    let mut writer = encoder.write_header().map_err(to_error)?;
+
+    for frame in 0..frame_count {
+        let time = frame as f32 * 1000.0 / ANIMATION_FPS as f32;
+        let (notes, presses) = playfield.chart_at(&chart, time);
+        writer.write_image_data(playfield.draw(&notes, &presses).as_raw()).map_err(to_error)?;
+    }
+
+    writer.finish().map_err(to_error)?;
+    Ok(buf)
+}
+
+/// `column`, hit time and release time in ms, a staircase going back and forth with a long note
+/// in every third column
+fn built_in_chart(key_count: usize) -> Vec<(usize, f32, Option<f32>)> {
+    let last_hit = ANIMATION_LENGTH_MS + SCROLL_TIME_MS;
+    let cycle = (key_count * 2).saturating_sub(2).max(1);
+
+    (0..)
+        .map(|i| (i, NOTE_INTERVAL_MS * (i as f32 + 1.0)))
+        .take_while(|(_, time)| *time <= last_hit)
+        .map(|(i, time)| {
+            let step = i % cycle;
+            let column = if step < key_count { step } else { cycle - step };
+            let release = (column % 3 == 2).then_some(time + LONG_NOTE_LENGTH_MS);
+            (column, time, release)
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+struct Note {
+    column: usize,
+    /// bottom of the head, can be above the canvas
+    bottom: i32,
+    /// from the top of the head to the top of the tail, long notes only
+    length: Option<i32>,
+}
+
+#[derive(Clone, Copy)]
+struct Press {
+    held: bool,
+    elapsed_ms: f32,
+}
+
+struct Lane {
+    x: u32,
+    width: u32,
+    receptor_up: Option<RgbaImage>,
+    receptor_down: Option<RgbaImage>,
+    note: Option<RgbaImage>,
+    head: Option<RgbaImage>,
+    tail: Option<RgbaImage>,
+    /// only scaled to the column width, it's stretched to the note's length when drawn
+    body: Option<RgbaImage>,
+    column_lighting: Option<RgbaImage>,
+}
+
+/// every texture the preview uses, scaled once so animation frames only have to be put together
+struct Playfield {
+    hit_y: u32,
+    scroll_speed: f32,
+    receptor_above_notes: bool,
+    /// black, stage background, borders and health bar, which never change
+    stage: RgbaImage,
+    judgement_line: Option<(RgbaImage, u32, u32)>,
+    lanes: Vec<Lane>,
+    hit_lighting: Vec<Vec<RgbaImage>>,
+    hit_lighting_fps: f32,
+    hold_lighting: Vec<Vec<RgbaImage>>,
+    hold_lighting_fps: f32,
+}
+
+impl Playfield {
+    fn new(skin: &GenericManiaSkin, keymode: u8, width: u32, height: u32) -> Result<Self, ConvertError> {
+        let keymode = skin.get_keymode(keymode)
+            .ok_or(ConvertError::MissingKeymode { keymode })?;
+
+        if keymode.keymode == 0 || width == 0 || height == 0 {
+            return Err(ConvertError::InvalidLayout {
+                reason: format!("can't draw {}K on a {width}x{height} canvas", keymode.keymode),
+            });
+        }
+
+        let columns = column_bounds(keymode, width, height);
+        let stage_x = columns.first().map(|(x, _)| *x).unwrap_or(0);
+        let stage_end = columns.last().map(|(x, w)| x + w).unwrap_or(0);
+        let stage_width = stage_end - stage_x;
+        let hit_y = ((1.0 - keymode.layout.hit_position.clamp(0.0, 1.0)) * height as f32) as u32;
+
+        let mut stage = RgbaImage::from_pixel(width, height, Rgba([0, 0, 0, 255]));
+        if let Some(background) = image(&keymode.stage.background) {
+            overlay_image(&mut stage, &resize_img(&background, stage_width, height, FilterType::Hamming), stage_x, 0);
+        }
+
+        let mut right_border_width = 0;
+        if let Some(border) = image(&keymode.stage.border_left).map(|img| fit_height(&img, height)) {
+            overlay_image(&mut stage, &border, stage_x.saturating_sub(border.width()), 0);
+        }
+        if let Some(border) = image(&keymode.stage.border_right).map(|img| fit_height(&img, height)) {
+            overlay_image(&mut stage, &border, stage_end, 0);
+            right_border_width = border.width();
+        }
+
+        draw_health_bar(&mut stage, skin, stage_end + right_border_width, hit_y);
+
+        let lanes = columns.iter().enumerate()
+            .map(|(col, (x, width))| {
+                let scaled = |texture: Option<&Option<Arc<RwLock<Texture>>>>| {
+                    texture.and_then(image).map(|img| fit_width(&img, *width))
+                };
+
+                let note = scaled(keymode.normal_notes.get(col).map(|n| &n.texture));
+                Lane {
+                    x: *x,
+                    width: *width,
+                    receptor_up: scaled(keymode.receptor_up.get(col).map(|r| &r.texture)),
+                    receptor_down: scaled(keymode.receptor_down.get(col).map(|r| &r.texture)),
+                    head: scaled(keymode.long_note_heads.get(col).map(|n| &n.texture)).or_else(|| note.clone()),
+                    tail: scaled(keymode.long_note_tails.get(col).map(|n| &n.texture)),
+                    body: scaled(keymode.long_note_bodies.get(col).map(|n| &n.texture)),
+                    column_lighting: scaled(Some(&keymode.column_lighting.texture)),
+                    note,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let lighting = |frames: &[Arc<RwLock<Texture>>]| -> Vec<Vec<RgbaImage>> {
+            let frames: Vec<RgbaImage> = frames.iter().filter_map(|f| f.get_image()).collect();
+            lanes.iter()
+                .map(|lane| frames.iter().map(|f| fit_width(f, lane.width)).collect())
+                .collect()
+        };
+
+        Ok(Self {
+            hit_y,
+            scroll_speed: hit_y as f32 / SCROLL_TIME_MS,
+            receptor_above_notes: keymode.layout.receptor_above_notes,
+            judgement_line: judgement_line(keymode, stage_x, stage_width, hit_y, height),
+            hit_lighting: lighting(&keymode.hit_lighting_normal.frames),
+            hit_lighting_fps: keymode.hit_lighting_normal.fps.filter(|fps| *fps > 0.0).unwrap_or(DEFAULT_LIGHTING_FPS),
+            hold_lighting: lighting(&keymode.hit_lighting_hold.frames),
+            hold_lighting_fps: keymode.hit_lighting_hold.fps.filter(|fps| *fps > 0.0).unwrap_or(DEFAULT_LIGHTING_FPS),
+            stage,
+            lanes,
+        })
+    }
+
+    /// where the chart's notes are and which columns are pressed at `time`
+    fn chart_at(&self, chart: &[(usize, f32, Option<f32>)], time: f32) -> (Vec<Note>, Vec<Option<Press>>) {
+        let mut notes = Vec::new();
+        let mut presses = vec![None; self.lanes.len()];
+        let y_at = |hit_time: f32| self.hit_y as i32 - ((hit_time - time) * self.scroll_speed) as i32;
+
+        for (column, hit_time, release_time) in chart.iter().copied() {
+            let end_time = release_time.unwrap_or(hit_time + PRESS_LENGTH_MS);
+            if time >= hit_time && time < end_time {
+                presses[column] = Some(Press { held: release_time.is_some(), elapsed_ms: time - hit_time });
+            }
+
+            match release_time {
+                Some(release_time) if time < release_time => {
+                    let head_time = hit_time.max(time);
+                    let head_height = self.lanes[column].head.as_ref().map(|img| img.height() as i32).unwrap_or(0);
+                    let length = y_at(head_time) - y_at(release_time) - head_height;
+                    notes.push(Note { column, bottom: y_at(head_time), length: Some(length.max(0)) });
+                }
+                None if time < hit_time => notes.push(Note { column, bottom: y_at(hit_time), length: None }),
+                _ => {}
+            }
+        }
+
+        (notes, presses)
+    }
+
+    fn draw(&self, notes: &[Note], presses: &[Option<Press>]) -> RgbaImage {
+        let mut canvas = self.stage.clone();
+
+        for (lane, press) in self.lanes.iter().zip(presses) {
+            if press.is_some()
+                && let Some(light) = &lane.column_lighting
+            {
+                overlay_at(&mut canvas, light, lane.x, self.hit_y as i32 - light.height() as i32);
+            }
+        }
+
+        if !self.receptor_above_notes {
+            self.draw_receptors(&mut canvas, presses);
+        }
+
+        if let Some((line, x, y)) = &self.judgement_line {
+            overlay_image(&mut canvas, line, *x, *y);
+        }
+
+        for note in notes {
+            self.draw_note(&mut canvas, note);
+        }
+
+        if self.receptor_above_notes {
+            self.draw_receptors(&mut canvas, presses);
+        }
+
+        for (col, press) in presses.iter().enumerate() {
+            let Some(press) = press else { continue };
+            let (frames, fps) = if press.held {
+                (&self.hold_lighting[col], self.hold_lighting_fps)
+            } else {
+                (&self.hit_lighting[col], self.hit_lighting_fps)
+            };
+            if frames.is_empty() {
+                continue;
+            }
+
+            let mut frame = (press.elapsed_ms * fps / 1000.0) as usize;
+            if press.held {
+                frame %= frames.len();
+            }
+            if let Some(light) = frames.get(frame) {
+                let x = self.lanes[col].x as i32 + (self.lanes[col].width as i32 - light.width() as i32) / 2;
+                overlay_at(&mut canvas, light, x.max(0) as u32, self.hit_y as i32 - light.height() as i32 / 2);
+            }
+        }
+
+        canvas
+    }
+
+    /// centred on the hit position
+    fn draw_receptors(&self, canvas: &mut RgbaImage, presses: &[Option<Press>]) {
+        for (lane, press) in self.lanes.iter().zip(presses) {
+            let receptor = if press.is_some() { lane.receptor_down.as_ref().or(lane.receptor_up.as_ref()) } else { lane.receptor_up.as_ref() };
+            if let Some(img) = receptor {
+                overlay_at(canvas, img, lane.x, self.hit_y as i32 - img.height() as i32 / 2);
+            }
+        }
+    }
+
+    /// the body of long notes is drawn from the middle of the head to the middle of the tail
+    fn draw_note(&self, canvas: &mut RgbaImage, note: &Note) {
+        let lane = &self.lanes[note.column];
+
+        let Some(length) = note.length else {
+            if let Some(img) = &lane.note {
+                overlay_at(canvas, img, lane.x, note.bottom - img.height() as i32);
+            }
+            return;
+        };
+
+        let head_height = lane.head.as_ref().map(|img| img.height() as i32).unwrap_or(0);
+        let tail_height = lane.tail.as_ref().map(|img| img.height() as i32).unwrap_or(0);
+        let head_y = note.bottom - head_height;
+        let tail_y = head_y - length;
+
+        let body_start = tail_y + tail_height / 2;
+        let body_end = head_y + head_height / 2;
+        if let Some(body) = &lane.body
+            && body_end > body_start
+        {
+            let body = resize_img(body, lane.width, (body_end - body_start) as u32, FilterType::Hamming);
+            overlay_at(canvas, &body, lane.x, body_start);
+        }
+        if let Some(tail) = &lane.tail {
+            overlay_at(canvas, tail, lane.x, tail_y);
+        }
+        if let Some(head) = &lane.head {
+            overlay_at(canvas, head, lane.x, head_y);
+        }
+    }
+}
+
+/// `(x, width)` of every column, the widths are relative to a 4:3 screen like osu!'s 640x480
+fn column_bounds(keymode: &Keymode, width: u32, height: u32) -> Vec<(u32, u32)> {
+    let key_count = keymode.keymode as usize;
+    let reference_width = height as f32 * 4.0 / 3.0;
+    let fallback_width = 1.0 / 16.0;
+
+    let mut widths: Vec<f32> = (0..key_count)
+        .map(|i| keymode.layout.column_widths.get(i).copied().filter(|w| *w > 0.0).unwrap_or(fallback_width))
+        .map(|w| w * reference_width)
+        .collect();
+
+    let total: f32 = widths.iter().sum();
+    let max_total = width as f32 * MAX_STAGE_WIDTH;
+    if total > max_total {
+        widths.iter_mut().for_each(|w| *w *= max_total / total);
+    }
+
+    let total: u32 = widths.iter().map(|w| (*w as u32).max(1)).sum();
+    let mut x = width.saturating_sub(total) / 2;
+
+    widths.into_iter()
+        .map(|w| {
+            let column = (x, (w as u32).max(1));
+            x += column.1;
+            column
+        })
+        .collect()
+}
+
+fn image(texture: &Option<Arc<RwLock<Texture>>>) -> Option<RgbaImage> {
+    texture.as_ref()?.get_image().filter(|img| img.width() > 0 && img.height() > 0)
+}
+
+fn fit_width(img: &RgbaImage, width: u32) -> RgbaImage {
+    let height = (img.height() as f32 * width as f32 / img.width() as f32).round() as u32;
+    resize_img(img, width, height.max(1), FilterType::Hamming)
+}
+
+fn fit_height(img: &RgbaImage, height: u32) -> RgbaImage {
+    let width = (img.width() as f32 * height as f32 / img.height() as f32).round() as u32;
+    resize_img(img, width.max(1), height, FilterType::Hamming)
+}
+
+/// like `overlay_image`, but the part above the canvas is cut off
+fn overlay_at(canvas: &mut RgbaImage, img: &RgbaImage, x: u32, y: i32) {
+    if y >= 0 {
+        overlay_image(canvas, img, x, y as u32);
+    } else if y.unsigned_abs() < img.height() {
+        let cropped = crop_imm(img, 0, y.unsigned_abs(), img.width(), img.height() - y.unsigned_abs()).to_image();
+        overlay_image(canvas, &cropped, x, 0);
+    }
+}
+
+/// the texture stretched over the stage, or a line in its colour if there is none
+fn judgement_line(keymode: &Keymode, stage_x: u32, stage_width: u32, hit_y: u32, height: u32) -> Option<(RgbaImage, u32, u32)> {
+    if !keymode.layout.show_judgement_line {
+        return None;
+    }
+
+    let max_height = (height / 20).max(1);
+    if let Some(img) = image(&keymode.judgement_line.texture).filter(|img| img.width() > 1 || img.height() > 1) {
+        let line_height = fit_width(&img, stage_width).height().clamp(1, max_height);
+        let scaled = resize_img(&img, stage_width, line_height, FilterType::Hamming);
+        Some((scaled, stage_x, hit_y.saturating_sub(line_height / 2)))
+    } else {
+        let color = keymode.judgement_line.color.to_image_rs();
+        if color[3] == 0 {
+            return None;
+        }
+
+        Some((RgbaImage::from_pixel(stage_width, 2, color), stage_x, hit_y.saturating_sub(1)))
+    }
+}
+
+/// standing next to the stage with its bottom on the hit position, the fill is drawn at full health
+fn draw_health_bar(canvas: &mut RgbaImage, skin: &GenericManiaSkin, x: u32, hit_y: u32) {
+    let bar_height = (canvas.height() as f32 * HEALTH_BAR_HEIGHT) as u32;
+    let health_bar = &skin.gameplay.health_bar;
+
+    for texture in [&health_bar.background, &health_bar.fill] {
+        if let Some(img) = image(texture) {
+            let scaled = fit_height(&img, bar_height.max(1));
+            overlay_image(canvas, &scaled, x, hit_y.saturating_sub(scaled.height()));
+        }
+    }
+}
@@ -3,9 +3,10 @@ use fast_image_resize::{FilterType, ResizeAlg, ResizeOptions, Resizer};
 use image::{Rgba, RgbaImage, imageops};
 use rayon::prelude::*;
 use crate::{
-    common, io::texture::Texture, process_texture, process_texture_mut
+    common, error::ConvertError, io::texture::Texture, process_texture, process_texture_mut
 }; 
 
+/// images without pixels can't be resized and come back transparent
 pub fn resize_img(
     img: &RgbaImage,
     new_width: u32,
@@ -13,15 +14,16 @@ pub fn resize_img(
     filter: FilterType,
 ) -> RgbaImage {
     let mut dst_image = RgbaImage::new(new_width, new_height);
+    if img.width() == 0 || img.height() == 0 || new_width == 0 || new_height == 0 {
+        return dst_image;
+    }
 
     let mut resizer = Resizer::new();
     let options = ResizeOptions::new().resize_alg(ResizeAlg::Convolution(filter));
 
-    resizer.resize(
-        img,
-        &mut dst_image,
-        Some(&options),
-    ).expect("Failed to resize image");
+    if resizer.resize(img, &mut dst_image, Some(&options)).is_err() {
+        return RgbaImage::new(new_width, new_height);
+    }
 
     dst_image
 }
@@ -30,7 +32,7 @@ pub fn resize_width(
     texture: &Arc<RwLock<Texture>>, 
     new_width: u32,
     filter: FilterType
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<(), ConvertError> {
     process_texture!(texture, |img: RgbaImage| {
         let aspect_ratio = img.height() as f32 / img.width() as f32;
         let new_height = (new_width as f32 * aspect_ratio) as u32;
@@ -42,7 +44,7 @@ pub fn resize_height(
     texture: &Arc<RwLock<Texture>>, 
     new_height: u32,
     filter: FilterType
-) -> Result<(), Box<dyn std::error::Error>> {
+) -> Result<(), ConvertError> {
     process_texture!(texture, |img: RgbaImage| {
         let aspect_ratio = img.width() as f32 / img.height() as f32;
         let new_width = (new_height as f32 * aspect_ratio) as u32;
@@ -99,8 +101,10 @@ pub fn dist_from_bottom(img: &RgbaImage, alpha_tolerance: f32) -> u32 {
         })
         .collect();
 
-    let max_row = last_rows.into_iter().flatten().max().unwrap_or(0);
-    height - 1 - max_row
+    match last_rows.into_iter().flatten().max() {
+        Some(max_row) => height - 1 - max_row,
+        None => height.saturating_sub(1),
+    }
 }
 
 pub fn dist_from_left(img: &RgbaImage, alpha_tolerance: f32) -> u32 {
@@ -239,8 +243,9 @@ pub fn pad_image_vertical(img: &RgbaImage, top_pad: u32, bottom_pad: u32) -> Rgb
         .par_chunks_mut((width * 4) as usize)
         .enumerate()
         .for_each(|(y, row_out)| {
-            let src_y = y as u32 - top_pad;
-            if src_y < height {
+            if let Some(src_y) = (y as u32).checked_sub(top_pad)
+                && src_y < height
+            {
                 let source_row = &img.as_raw()[(src_y * width) as usize * 4..][..row_out.len()];
                 row_out.copy_from_slice(source_row);
             }
@@ -249,19 +254,19 @@ pub fn pad_image_vertical(img: &RgbaImage, top_pad: u32, bottom_pad: u32) -> Rgb
     padded_img
 }
 
-pub fn flip_vertical(texture: &Arc<RwLock<Texture>>) {
+pub fn flip_vertical(texture: &Arc<RwLock<Texture>>) -> Result<(), ConvertError> {
     process_texture_mut!(texture, |img: &mut RgbaImage| {
         imageops::flip_vertical_in_place(img);
     })
 }
 
-pub fn rotate_90_deg_cw(texture: &Arc<RwLock<Texture>>) -> Result<(), Box<dyn std::error::Error>> {
+pub fn rotate_90_deg_cw(texture: &Arc<RwLock<Texture>>) -> Result<(), ConvertError> {
     process_texture!(texture, |img: RgbaImage| {
         imageops::rotate90(&img)
     })
 }
 
-pub fn rotate_90_deg_ccw(texture: &Arc<RwLock<Texture>>) -> Result<(), Box<dyn std::error::Error>> {
+pub fn rotate_90_deg_ccw(texture: &Arc<RwLock<Texture>>) -> Result<(), ConvertError> {
     process_texture!(texture, |img: RgbaImage| {
         imageops::rotate270(&img)
     })
@@ -375,19 +380,23 @@ pub fn blend_alpha(bg: u8, fg: u8, _alpha: f32) -> u8 {
     ((fg_alpha + bg_alpha * (1.0 - fg_alpha)) * 255.0) as u8
 }
 
+/// `None` if there are no images, their sizes differ or there isn't one tint per image
 pub fn extract_grayscale_base(
     images: &[&RgbaImage],
     tints: Option<&[common::color::Rgba]>,
     filter: FilterType,
-) -> RgbaImage {
+) -> Option<RgbaImage> {
     use rayon::prelude::*;
 
-    assert!(!images.is_empty());
-    if let Some(t) = tints {
-        assert_eq!(t.len(), images.len());
+    let first = images.first()?;
+    if tints.is_some_and(|t| t.len() != images.len()) {
+        return None;
     }
 
-    let (w, h) = images[0].dimensions();
+    let (w, h) = first.dimensions();
+    if images.iter().any(|img| img.dimensions() != (w, h)) {
+        return None;
+    }
     let n = (w * h) as usize;
     let count = images.len() as f32;
 
@@ -432,10 +441,14 @@ pub fn extract_grayscale_base(
             out[3] = a;
         });
 
-    RgbaImage::from_raw(w, h, raw_out).unwrap()
+    RgbaImage::from_raw(w, h, raw_out)
 }
 
 pub fn extract_from_sheet(sheet: &RgbaImage, rows: u32, columns: u32) -> Vec<RgbaImage> {
+    if rows == 0 || columns == 0 {
+        return Vec::new();
+    }
+
     let (width, height) = sheet.dimensions();
     let sprite_w = width / columns;
     let sprite_h = height / rows;
@@ -453,6 +466,10 @@ pub fn extract_from_sheet(sheet: &RgbaImage, rows: u32, columns: u32) -> Vec<Rgb
 }
 
 pub fn extract_from_sheet_trimmed(sheet: &RgbaImage, rows: u32, columns: u32) -> Vec<RgbaImage> {
+    if rows == 0 || columns == 0 {
+        return Vec::new();
+    }
+
     let (width, height) = sheet.dimensions();
     let sprite_w = width / columns;
     let sprite_h = height / rows;
@@ -470,12 +487,13 @@ pub fn extract_from_sheet_trimmed(sheet: &RgbaImage, rows: u32, columns: u32) ->
         .collect()
 }
 
+/// `None` if there are no sprites or they aren't all the same non zero size
 pub fn concat_into_sheet(sprites: &[&RgbaImage], rows: u32, columns: u32) -> Option<RgbaImage> {
-    if sprites.is_empty() {
+    let (sprite_w, sprite_h) = sprites.first()?.dimensions();
+    if sprite_w == 0 || sprite_h == 0 || sprites.iter().any(|s| s.dimensions() != (sprite_w, sprite_h)) {
         return None;
     }
 
-    let (sprite_w, sprite_h) = sprites[0].dimensions();
     let width = sprite_w * columns;
     let height = sprite_h * rows;
 
@@ -0,0 +1,90 @@
+use std::str::FromStr;
+use std::collections::HashMap;
+use crate::common::traits::SkinConfig;
+use crate::error::{ImportError, ParseError};
+use crate::io::archive::read_archive;
+use crate::importing::common::{
+    import_textures_from_files, import_all_textures_from_files,
+    import_samples_from_files, import_all_samples_from_files,
+};
+use crate::utils::io::{get_filename, normalize};
+use crate::utils::string::string_iter_as_str;
+
+pub fn read_config_from_files<C>(
+    files: &HashMap<String, Vec<u8>>,
+    config_file: &str,
+) -> Result<C, ImportError>
+where
+    C: SkinConfig + Default,
+    <C as FromStr>::Err: Into<ParseError>,
+{
+    // a config at the archive root wins over one that happens to be nested somewhere
+    let found = files.iter()
+        .find(|(path, _)| normalize(path) == config_file)
+        .or_else(|| files.iter().find(|(path, _)| {
+            get_filename(&normalize(path)).eq_ignore_ascii_case(config_file)
+        }));
+
+    match found {
+        Some((path, bytes)) => {
+            let content = String::from_utf8(bytes.clone()).map_err(|source| {
+                ImportError::ParseConfig {
+                    path: path.clone(),
+                    source: Box::new(source.into()),
+                }
+            })?;
+            C::from_str(&content).map_err(|source| ImportError::ParseConfig {
+                path: path.clone(),
+                source: Box::new(source.into()),
+            })
+        }
+        None => Ok(C::default()),
+    }
+}
+
+macro_rules! impl_archive_importer {
+    ($fn_name:ident, $config_file:expr, $config_type:ty, $skin_type:ty, filtered) => {
+        pub fn $fn_name(bytes: &[u8], import_all: bool) -> Result<$skin_type, ImportError> {
+            let files = read_archive(bytes)?;
+            let config: $config_type = read_config_from_files(&files, $config_file)?;
+
+            let texture_paths = config.get_required_texture_paths();
+            let sample_paths  = config.get_required_sample_paths();
+            let textures = if import_all {
+                import_all_textures_from_files(&files, Some(&texture_paths))?
+            } else {
+                let texture_path_strings: Vec<String> = texture_paths.iter().map(|p| p.to_string()).collect();
+                import_textures_from_files(&files, &string_iter_as_str(texture_path_strings.iter()))?
+            };
+            let samples = if import_all {
+                import_all_samples_from_files(&files)?
+            } else {
+                let sample_path_strings: Vec<String> = sample_paths.iter().map(|p| p.to_string()).collect();
+                import_samples_from_files(&files, &string_iter_as_str(sample_path_strings.iter()))?
+            };
+            Ok(<$skin_type>::new(config, Some(textures), Some(samples)))
+        }
+    };
+    ($fn_name:ident, $config_file:expr, $config_type:ty, $skin_type:ty, all) => {
+        pub fn $fn_name(bytes: &[u8], import_all: bool) -> Result<$skin_type, ImportError> {
+            let files = read_archive(bytes)?;
+            let config: $config_type = read_config_from_files(&files, $config_file)?;
+
+            let sample_paths = config.get_required_sample_paths();
+            let textures = import_all_textures_from_files(&files, None)?;
+            let samples = if import_all {
+                import_all_samples_from_files(&files)?
+            } else {
+                let sample_path_strings: Vec<String> = sample_paths.iter().map(|p| p.to_string()).collect();
+                import_samples_from_files(&files, &string_iter_as_str(sample_path_strings.iter()))?
+            };
+            Ok(<$skin_type>::new(config, Some(textures), Some(samples)))
+        }
+    };
+}
+
+impl_archive_importer!(import_osu_mania_skin_from_archive, "skin.ini", crate::osu::OsuSkinIni, crate::OsuSkin, filtered);
+impl_archive_importer!(import_fluxis_skin_from_archive, "skin.json", crate::fluxis::SkinJson, crate::fluxis::FluXisSkin, all);
+impl_archive_importer!(import_quaver_skin_from_archive, "skin.ini", crate::quaver::QuaSkinIni, crate::quaver::QuaSkin, filtered);
+impl_archive_importer!(import_malody_skin_from_archive, "conf.json", crate::malody::ConfJson, crate::MalodySkin, filtered);
+impl_archive_importer!(import_etterna_noteskin_from_archive, "metrics.ini", crate::etterna::EtternaMetrics, crate::EtternaNoteskin, filtered);
@@ -4,10 +4,12 @@ use std::collections::{HashMap, HashSet};
 use std::fmt;
 use rayon::prelude::*;
 
-use crate::error::TextureLoadError;
-use crate::{Binary, Store, texture::{Texture, TextureStore}, utils::io::get_stem};
+use crate::error::{ImportError, TextureLoadError};
+use crate::{Binary, Store, texture::{Texture, TextureFormat, TextureStore}, utils::io::get_stem};
+use crate::io::texture::{EncodedSource, TEXTURE_EXTENSIONS, decode_texture};
 use crate::io::{BinaryState, StringPattern};
-use crate::utils::io::normalize;
+use crate::sample::{SampleStore, SAMPLE_EXTENSIONS};
+use crate::utils::io::{normalize, remove_extension};
 
 pub fn file_matches_target(file_stem: &str, target_filename: &str) -> bool {
     file_stem.to_lowercase() == target_filename.to_lowercase()
@@ -71,9 +73,15 @@ pub fn pair_at2x_files<'a>(
                 hires,
                 lores,
             },
-            (Some(bytes), None) | (None, Some(bytes)) => TextureEntry::Plain {
+            (Some(bytes), None) => TextureEntry::Plain {
                 path: canonical.to_string(),
                 bytes,
+                at2x: false,
+            },
+            (None, Some(bytes)) => TextureEntry::Plain {
+                path: canonical.to_string(),
+                bytes,
+                at2x: true,
             },
             (None, None) => unreachable!(),
         })
@@ -85,12 +93,15 @@ enum Decoded {
         path: String,
         image: image::RgbaImage,
         hash: u64,
-        mip: Option<image::RgbaImage>,
+        format: Option<TextureFormat>,
+        source: EncodedSource,
+        mips: Option<Vec<image::RgbaImage>>,
     },
     Unloaded {
         path: String,
         bytes: Vec<u8>,
         hash: u64,
+        mips: Option<Vec<image::RgbaImage>>,
     },
 }
 
@@ -110,15 +121,13 @@ pub fn build_texture_store_from_files(
                 if should_load {
                     let hash = xxhash_rust::xxh3::xxh3_64(hires);
 
-                    let image = image::load_from_memory(hires)
-                        .map(|img| img.to_rgba8())
+                    let (image, format) = decode_texture(hires)
                         .map_err(|source| TextureLoadError::DecodeHires {
                             path: canonical_path.clone(),
                             source,
                         })?;
 
-                    let mip = image::load_from_memory(lores)
-                        .map(|img| img.to_rgba8())
+                    let (mip, _) = decode_texture(lores)
                         .map_err(|source| TextureLoadError::DecodeMip {
                             path: canonical_path.clone(),
                             source,
@@ -126,9 +135,11 @@ pub fn build_texture_store_from_files(
 
                     Ok(Decoded::Loaded {
                         path: canonical_path.clone(),
+                        source: EncodedSource::new(hires.to_vec(), &image),
                         image,
                         hash,
-                        mip: Some(mip),
+                        format,
+                        mips: Some(vec![mip]),
                     })
                 } else {
                     let hash = xxhash_rust::xxh3::xxh3_64(hires);
@@ -136,18 +147,21 @@ pub fn build_texture_store_from_files(
                         path: canonical_path.clone(),
                         bytes: hires.to_vec(),
                         hash,
+                        mips: Some(Vec::new()),
                     })
                 }
             }
-            TextureEntry::Plain { path, bytes } => {
+            TextureEntry::Plain { path, bytes, at2x } => {
+                // an empty mipmap list still marks the texture as HD for the osu export
+                let mips = at2x.then(Vec::new);
+
                 let should_load = load_only
                     .map_or(true, |s| should_load_from_set(path, s));
 
                 if should_load {
                     let hash = xxhash_rust::xxh3::xxh3_64(bytes);
 
-                    let image = image::load_from_memory(bytes)
-                        .map(|img| img.to_rgba8())
+                    let (image, format) = decode_texture(bytes)
                         .map_err(|source| TextureLoadError::DecodeHires {
                             path: path.clone(),
                             source,
@@ -155,9 +169,11 @@ pub fn build_texture_store_from_files(
 
                     Ok(Decoded::Loaded {
                         path: path.clone(),
+                        source: EncodedSource::new(bytes.to_vec(), &image),
                         image,
                         hash,
-                        mip: None,
+                        format,
+                        mips,
                     })
                 } else {
                     let hash = xxhash_rust::xxh3::xxh3_64(bytes);
@@ -165,6 +181,7 @@ pub fn build_texture_store_from_files(
                         path: path.clone(),
                         bytes: bytes.to_vec(),
                         hash,
+                        mips,
                     })
                 }
             }
@@ -174,30 +191,35 @@ pub fn build_texture_store_from_files(
     let store = TextureStore::new();
     decoded.into_par_iter().try_for_each(|result| {
         let decoded = result?;
-        let (path, texture, mip) = match decoded {
-            Decoded::Loaded { path, image, hash, mip } => {
+        let (path, texture, mips) = match decoded {
+            Decoded::Loaded { path, image, hash, format, source, mips } => {
                 let texture = Texture {
                     path: path.clone(),
                     data: BinaryState::Loaded(image),
                     hash: Some(hash),
+                    format,
+                    source: Some(source),
                 };
-                (path, texture, mip)
+                (path, texture, mips)
             }
-            Decoded::Unloaded { path, bytes, hash } => {
+            Decoded::Unloaded { path, bytes, hash, mips } => {
+                let format = TextureFormat::detect(&bytes);
                 let texture = Texture {
                     path: path.clone(),
                     data: BinaryState::Unloaded(bytes),
                     hash: Some(hash),
+                    format,
+                    source: None,
                 };
-                (path, texture, None)
+                (path, texture, mips)
             }
         };
 
         let normalized = normalize(&path);
         let arc = std::sync::Arc::new(std::sync::RwLock::new(texture));
         store.textures.insert(normalized.clone(), arc);
-        if let Some(mip_image) = mip {
-            store.mipmaps.insert(normalized, vec![mip_image]);
+        if let Some(mips) = mips {
+            store.mipmaps.insert(normalized, mips);
         }
         Ok::<_, TextureLoadError>(())
     })?;
@@ -214,6 +236,8 @@ pub enum TextureEntry<'a> {
     Plain {
         path: String,
         bytes: &'a [u8],
+        /// read from `name@2x` without a `name` next to it
+        at2x: bool,
     },
 }
 
@@ -237,3 +261,116 @@ impl SeenFiles {
 pub fn should_load_from_set(path: &str, load_only: &[StringPattern]) -> bool {
     load_only.iter().any(|p| p.matches_path(path))
 }
+
+pub fn import_binaries_from_files<F>(
+    files: &HashMap<String, Vec<u8>>,
+    patterns: &[&str],
+    extensions: &[&str],
+    mut loader: F,
+) -> Result<(), ImportError>
+where
+    F: FnMut(String, &[u8]) -> Result<(), ImportError>,
+{
+    let mut seen = SeenFiles::new();
+    let patterns: Vec<StringPattern> = patterns
+        .iter()
+        .map(|p| StringPattern::from(p.to_string()))
+        .collect();
+    for (file_path, bytes) in files {
+        let normalized = normalize(file_path);
+        if let Some(ext_pos) = normalized.rfind('.') {
+            let ext = &normalized[ext_pos + 1..];
+            if extension_matches(ext, extensions) {
+                let path_without_ext = remove_extension(&normalized);
+                if seen.try_insert(&path_without_ext)
+                    && patterns.iter().any(|p| p.matches_path(&path_without_ext))
+                {
+                    loader(path_without_ext.to_string(), bytes)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn import_all_binaries_from_files<F>(
+    files: &HashMap<String, Vec<u8>>,
+    extensions: &[&str],
+    mut loader: F,
+) -> Result<(), ImportError>
+where
+    F: FnMut(String, &[u8]) -> Result<(), ImportError>,
+{
+    let mut seen_files = SeenFiles::new();
+    for (file_path, bytes) in files {
+        let normalized_path = normalize(file_path);
+        if let Some(ext_pos) = normalized_path.rfind('.') {
+            let ext = &normalized_path[ext_pos + 1..];
+            if extension_matches(ext, extensions) {
+                let path_without_ext = remove_extension(&normalized_path);
+                if seen_files.try_insert(&path_without_ext) {
+                    loader(path_without_ext.to_string(), bytes)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn import_textures_from_files(
+    files: &HashMap<String, Vec<u8>>,
+    patterns: &[&str],
+) -> Result<TextureStore, ImportError> {
+    let mut filtered = HashMap::new();
+    import_binaries_from_files(files, patterns, TEXTURE_EXTENSIONS, |path, bytes| {
+        filtered.insert(path, bytes.to_vec());
+        Ok(())
+    })?;
+    build_texture_store_from_files(&filtered, None).map_err(ImportError::from)
+}
+
+pub fn import_all_textures_from_files(
+    files: &HashMap<String, Vec<u8>>,
+    load_only: Option<&[StringPattern]>,
+) -> Result<TextureStore, ImportError> {
+    let mut filtered = HashMap::new();
+    import_all_binaries_from_files(files, TEXTURE_EXTENSIONS, |path, bytes| {
+        filtered.insert(path, bytes.to_vec());
+        Ok(())
+    })?;
+    build_texture_store_from_files(&filtered, load_only).map_err(ImportError::from)
+}
+
+pub fn import_samples_from_files(
+    files: &HashMap<String, Vec<u8>>,
+    relative_sample_paths: &[&str],
+) -> Result<SampleStore, ImportError> {
+    let mut sample_store = SampleStore::new();
+    import_binaries_from_files(files, relative_sample_paths, SAMPLE_EXTENSIONS, |path, bytes| {
+        sample_store
+            .load_from_bytes(path.clone(), bytes)
+            .map_err(|source| ImportError::Sample {
+                path,
+                source: source.into(),
+            })?;
+        Ok(())
+    })?;
+    Ok(sample_store)
+}
+
+#[allow(unused)]
+pub fn import_all_samples_from_files(
+    files: &HashMap<String, Vec<u8>>,
+) -> Result<SampleStore, ImportError> {
+    let mut sample_store = SampleStore::new();
+    import_all_binaries_from_files(files, SAMPLE_EXTENSIONS, |path, bytes| {
+        sample_store
+            .load_from_bytes(path.clone(), bytes)
+            .map_err(|source| ImportError::Sample {
+                path,
+                source: source.into(),
+            })?;
+        Ok(())
+    })?;
+    Ok(sample_store)
+}
@@ -2,4 +2,5 @@ pub mod native;
 pub mod browser;
 pub mod node;
 pub mod common;
-pub(crate) mod macros;
\ No newline at end of file
+pub mod archive;
+pub(crate) mod macros;
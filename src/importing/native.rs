@@ -11,10 +11,10 @@ use crate::error::ImportError;
 use crate::fluxis::{self, FluXisSkin};
 use crate::io::StringPattern;
 use crate::{osu, quaver, Store};
-use crate::sample::SampleStore;
+use crate::sample::{SampleStore, SAMPLE_EXTENSIONS};
 use crate::utils::io::{get_filename, get_parent, get_stem, join_paths_unix, remove_extension};
 use crate::OsuSkin;
-use crate::io::texture::{TextureStore, Texture};
+use crate::io::texture::{TextureStore, Texture, TEXTURE_EXTENSIONS};
 use crate::importing::common::{
     SeenFiles, build_texture_store_from_files, extension_matches,
 };
@@ -114,7 +114,7 @@ pub fn import_textures_from_dir(
     patterns: &[StringPattern],
 ) -> Result<TextureStore, ImportError> {
     let mut files = HashMap::new();
-    import_binaries_from_dir(path, patterns, &["png", "jpg", "jpeg"], |path, bytes| {
+    import_binaries_from_dir(path, patterns, TEXTURE_EXTENSIONS, |path, bytes| {
         files.insert(path, bytes.to_vec());
         Ok(())
     })?;
@@ -126,7 +126,7 @@ pub fn import_all_textures_from_dir(
     load_only: Option<&[StringPattern]>,
 ) -> Result<TextureStore, ImportError> {
     let mut files: HashMap<String, Vec<u8>> = HashMap::new();
-    import_all_binaries_from_dir(path, &["png", "jpg", "jpeg"], |path, bytes| {
+    import_all_binaries_from_dir(path, TEXTURE_EXTENSIONS, |path, bytes| {
         files.insert(path, bytes.to_vec());
         Ok(())
     })?;
@@ -138,7 +138,7 @@ pub fn import_samples_from_dir(
     relative_sample_paths: &[StringPattern],
 ) -> Result<SampleStore, ImportError> {
     let mut sample_store = SampleStore::new();
-    import_binaries_from_dir(path, relative_sample_paths, &["wav", "ogg"], |sample_path, bytes| {
+    import_binaries_from_dir(path, relative_sample_paths, SAMPLE_EXTENSIONS, |sample_path, bytes| {
         sample_store
             .load_from_bytes(sample_path.clone(), bytes)
             .map_err(|source| ImportError::Sample {
@@ -153,7 +153,7 @@ pub fn import_samples_from_dir(
 #[allow(unused)]
 pub fn import_all_samples_from_dir(path: &str) -> Result<SampleStore, ImportError> {
     let mut sample_store = SampleStore::new();
-    import_all_binaries_from_dir(path, &["wav", "ogg"], |sample_path, bytes| {
+    import_all_binaries_from_dir(path, SAMPLE_EXTENSIONS, |sample_path, bytes| {
         sample_store
             .load_from_bytes(sample_path.clone(), bytes)
             .map_err(|source| ImportError::Sample {
@@ -190,7 +190,7 @@ macro_rules! impl_skin_importer {
                 Some(content) => <$config_type>::from_str(&content).map_err(|source| {
                     ImportError::ParseConfig {
                         path: config_path_str.clone(),
-                        source: source.into(),
+                        source: Box::new(source.into()),
                     }
                 })?,
                 None => <$config_type>::default(),
@@ -223,7 +223,7 @@ macro_rules! impl_skin_importer {
                 Some(content) => <$config_type>::from_str(&content).map_err(|source| {
                     ImportError::ParseConfig {
                         path: config_path_str.clone(),
-                        source: source.into(),
+                        source: Box::new(source.into()),
                     }
                 })?,
                 None => <$config_type>::default(),
@@ -243,4 +243,13 @@ macro_rules! impl_skin_importer {
 
 impl_skin_importer!(import_osu_mania_skin_from_dir, "skin.ini", osu::OsuSkinIni, OsuSkin, filtered);
 impl_skin_importer!(import_fluxis_skin_from_dir, "skin.json", fluxis::SkinJson, FluXisSkin, all);
-impl_skin_importer!(import_quaver_skin_from_dir, "skin.ini", quaver::QuaSkinIni, quaver::QuaSkin, filtered);
\ No newline at end of file
+impl_skin_importer!(import_quaver_skin_from_dir, "skin.ini", quaver::QuaSkinIni, quaver::QuaSkin, filtered);
+impl_skin_importer!(import_malody_skin_from_dir, "conf.json", crate::malody::ConfJson, crate::MalodySkin, filtered);
+impl_skin_importer!(import_etterna_noteskin_files_from_dir, "metrics.ini", crate::etterna::EtternaMetrics, crate::EtternaNoteskin, filtered);
+
+/// noteskins are named after the folder they live in
+pub fn import_etterna_noteskin_from_dir(path: &str, import_all: bool) -> Result<crate::EtternaNoteskin, ImportError> {
+    let mut skin = import_etterna_noteskin_files_from_dir(path, import_all)?;
+    skin.name = get_filename(path);
+    Ok(skin)
+}
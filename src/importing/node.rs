@@ -7,8 +7,8 @@ use std::str::FromStr;
 #[cfg(all(target_arch = "wasm32", feature = "node"))]
 use crate::io::node;
 use crate::common::traits::SkinConfig;
-use crate::io::texture::TextureStore;
-use crate::sample::SampleStore;
+use crate::io::texture::{TextureStore, TEXTURE_EXTENSIONS};
+use crate::sample::{SampleStore, SAMPLE_EXTENSIONS};
 use crate::utils::io::remove_extension;
 use crate::importing::common::{extension_matches, build_texture_store_from_files, SeenFiles};
 use crate::io::StringPattern;
@@ -22,7 +22,7 @@ pub fn import_binaries_from_dir<F>(
 where
     F: FnMut(String, &[u8]) -> Result<(), ImportError>,
 {
-    import_all_binaries_from_dir(path, &["png", "jpg", "jpeg", "wav", "ogg"], |file_path, bytes| {
+    import_all_binaries_from_dir(path, &["png", "jpg", "jpeg", "webp", "bmp", "gif", "tga", "wav", "ogg", "mp3", "flac"], |file_path, bytes| {
         if patterns.iter().any(|p| p.matches_path(&file_path)) {
             loader(file_path, bytes)?;
         }
@@ -108,7 +108,7 @@ pub fn import_all_textures_from_dir(
     load_only: Option<&[StringPattern]>,
 ) -> Result<TextureStore, ImportError> {
     let mut files: HashMap<String, Vec<u8>> = HashMap::new();
-    import_all_binaries_from_dir(path, &["png", "jpg", "jpeg"], |path, bytes| {
+    import_all_binaries_from_dir(path, TEXTURE_EXTENSIONS, |path, bytes| {
         files.insert(path, bytes.to_vec());
         Ok(())
     })?;
@@ -136,7 +136,7 @@ pub fn import_all_samples_from_dir(
     path: &str,
 ) -> Result<SampleStore, ImportError> {
     let mut sample_store = SampleStore::new();
-    import_all_binaries_from_dir(path, &["wav", "ogg"], |sample_path, bytes| {
+    import_all_binaries_from_dir(path, SAMPLE_EXTENSIONS, |sample_path, bytes| {
         sample_store
             .load_from_bytes(sample_path.clone(), bytes)
             .map_err(|source| ImportError::Sample {
@@ -168,7 +168,7 @@ macro_rules! impl_skin_importer {
                 Some(content) => <$config_type>::from_str(&content).map_err(|source| {
                     ImportError::ParseConfig {
                         path: config_path.clone(),
-                        source: source.into(),
+                        source: Box::new(source.into()),
                     }
                 })?,
                 None => <$config_type>::default(),
@@ -198,7 +198,7 @@ macro_rules! impl_skin_importer {
                 Some(content) => <$config_type>::from_str(&content).map_err(|source| {
                     ImportError::ParseConfig {
                         path: config_path.clone(),
-                        source: source.into(),
+                        source: Box::new(source.into()),
                     }
                 })?,
                 None => <$config_type>::default(),
@@ -217,4 +217,13 @@ macro_rules! impl_skin_importer {
 
 impl_skin_importer!(import_osu_mania_skin_from_dir, "skin.ini", crate::osu::OsuSkinIni, crate::OsuSkin, filtered);
 impl_skin_importer!(import_fluxis_skin_from_dir, "skin.json", crate::fluxis::SkinJson, crate::fluxis::FluXisSkin, all);
-impl_skin_importer!(import_quaver_skin_from_dir, "skin.ini", crate::quaver::QuaSkinIni, crate::quaver::QuaSkin, filtered);
\ No newline at end of file
+impl_skin_importer!(import_quaver_skin_from_dir, "skin.ini", crate::quaver::QuaSkinIni, crate::quaver::QuaSkin, filtered);
+impl_skin_importer!(import_malody_skin_from_dir, "conf.json", crate::malody::ConfJson, crate::MalodySkin, filtered);
+impl_skin_importer!(import_etterna_noteskin_files_from_dir, "metrics.ini", crate::etterna::EtternaMetrics, crate::EtternaNoteskin, filtered);
+
+/// noteskins are named after the folder they live in
+pub fn import_etterna_noteskin_from_dir(path: &str, import_all: bool) -> Result<crate::EtternaNoteskin, ImportError> {
+    let mut skin = import_etterna_noteskin_files_from_dir(path, import_all)?;
+    skin.name = crate::utils::io::get_filename(path);
+    Ok(skin)
+}
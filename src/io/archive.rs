@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Write};
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+use zip::result::ZipError;
+use zip::write::SimpleFileOptions;
+
+use crate::error::ImportError;
+use crate::utils::io::{get_extension, path_to_unix, safe_file_name};
+
+/// how many bytes all entries of an archive may unpack to, the sizes in the zip headers aren't trusted
+pub const MAX_UNPACKED_SIZE: u64 = 1 << 30;
+
+/// extensions that are already compressed, deflating them again only costs time
+const STORED_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "gif", "ogg", "mp3", "flac"];
+
+/// reads every file of a zip based skin archive (.osk, .qsk, fluXis .zip) into a `path -> bytes` map,
+/// if every entry lives under the same top level folder that folder is stripped
+pub fn read_archive(bytes: &[u8]) -> Result<HashMap<String, Vec<u8>>, ImportError> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(|source| ImportError::ReadArchive { source })?;
+    let mut files = HashMap::new();
+    let mut remaining = MAX_UNPACKED_SIZE;
+
+    for idx in 0..archive.len() {
+        let mut entry = archive.by_index(idx).map_err(|source| ImportError::ReadArchive { source })?;
+        if entry.is_dir() {
+            continue;
+        }
+
+        // skip entries trying to escape the archive root (../ or absolute paths)
+        let Some(path) = entry.enclosed_name() else { continue };
+        let path = path_to_unix(&path.to_string_lossy());
+
+        // one byte past what's left is enough to tell the archive is too large
+        let mut data = Vec::new();
+        (&mut entry).take(remaining + 1).read_to_end(&mut data)
+            .map_err(|e| ImportError::ReadArchive { source: ZipError::Io(e) })?;
+        remaining = remaining.checked_sub(data.len() as u64)
+            .ok_or(ImportError::ArchiveTooLarge { limit: MAX_UNPACKED_SIZE })?;
+        files.insert(path, data);
+    }
+
+    Ok(strip_common_root(files))
+}
+
+pub fn write_archive(files: &HashMap<String, Vec<u8>>) -> Result<Vec<u8>, ZipError> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+
+    // sorted so the same skin always produces the same archive
+    let mut paths: Vec<&String> = files.keys().collect();
+    paths.sort_unstable();
+
+    for path in paths {
+        let method = if STORED_EXTENSIONS.contains(&get_extension(path).as_str()) {
+            CompressionMethod::Stored
+        } else {
+            CompressionMethod::Deflated
+        };
+        let options = SimpleFileOptions::default().compression_method(method);
+
+        writer.start_file(path_to_unix(path), options)?;
+        writer.write_all(&files[path])?;
+    }
+
+    Ok(writer.finish()?.into_inner())
+}
+
+/// `{skin_name}.{extension}` with the name made safe by `safe_file_name`, an empty result falls back to `skin`
+pub fn archive_file_name(skin_name: &str, extension: &str) -> String {
+    let name = safe_file_name(skin_name);
+    let name = if name.is_empty() { "skin" } else { &name };
+
+    format!("{name}.{extension}")
+}
+
+fn strip_common_root(files: HashMap<String, Vec<u8>>) -> HashMap<String, Vec<u8>> {
+    let root = match files.keys().next().and_then(|p| p.split_once('/')) {
+        Some((root, _)) => format!("{root}/"),
+        None => return files,
+    };
+
+    if !files.keys().all(|p| p.starts_with(&root)) {
+        return files;
+    }
+
+    files
+        .into_iter()
+        .map(|(path, bytes)| (path[root.len()..].to_string(), bytes))
+        .collect()
+}
@@ -1,4 +1,4 @@
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, PoisonError, RwLock};
 use dashmap::DashMap;
 use merge::Merge;
 use wasm_bindgen::prelude::*;
@@ -8,6 +8,7 @@ use js_sys::{Uint8Array, ArrayBuffer};
 use js_sys::Array;
 
 use crate::{impl_store_wasm, io::{Binary, BinaryState, RawBytes, Store}};
+use crate::io::sample::SampleFormat;
 
 #[wasm_bindgen]
 #[derive(Clone, Merge, Debug)]
@@ -83,6 +84,16 @@ impl BinaryStore {
         self.with_item(path, |binary| binary.has_data()).unwrap_or(false)
     }
 
+    #[wasm_bindgen(js_name = getFormat)]
+    pub fn get_format_wasm(&self, path: &str) -> Option<SampleFormat> {
+        self.get_format(path)
+    }
+
+    #[wasm_bindgen(js_name = pathsWithFormat)]
+    pub fn paths_with_format_wasm(&self, format: SampleFormat) -> Vec<String> {
+        self.paths_with_format(format)
+    }
+
     #[wasm_bindgen(js_name = getBinaryData)]
     pub fn get_binary_data_wasm(&self, path: &str) -> Option<Uint8Array> {
         self.with_item(path, |binary| {
@@ -153,21 +164,35 @@ impl BinaryStore {
     
     pub fn all_loaded(&self) -> bool {
         self.binaries.iter().all(|entry| {
-            let binary = entry.value().read().unwrap();
+            let binary = entry.value().read().unwrap_or_else(PoisonError::into_inner);
             binary.has_data()
         })
     }
     
     pub fn loaded_count(&self) -> usize {
         self.binaries.iter().filter(|entry| {
-            let binary = entry.value().read().unwrap();
+            let binary = entry.value().read().unwrap_or_else(PoisonError::into_inner);
             binary.has_data()
         }).count()
     }
     
+    pub fn get_format(&self, path: &str) -> Option<SampleFormat> {
+        self.with_item(path, |binary| binary.format()).flatten()
+    }
+
+    /// sorted, for picking a sample a game can play when it only supports some formats
+    pub fn paths_with_format(&self, format: SampleFormat) -> Vec<String> {
+        let mut paths: Vec<String> = self.binaries.iter().filter_map(|entry| {
+            let binary = entry.value().read().unwrap_or_else(PoisonError::into_inner);
+            (binary.format() == Some(format)).then(|| binary.get_path().to_string())
+        }).collect();
+        paths.sort();
+        paths
+    }
+
     pub fn unloaded_paths(&self) -> Vec<String> {
         self.binaries.iter().filter_map(|entry| {
-            let binary = entry.value().read().unwrap();
+            let binary = entry.value().read().unwrap_or_else(PoisonError::into_inner);
             if !binary.has_data() {
                 Some(binary.get_path().to_string())
             } else {
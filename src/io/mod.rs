@@ -2,6 +2,7 @@ pub mod texture;
 pub mod sample;
 pub mod node;
 pub mod traits;
+pub mod archive;
 mod string_pattern;
 mod store;
 mod binary;
@@ -1,5 +1,7 @@
 use wasm_bindgen::prelude::*;
+use xxhash_rust::xxh3::xxh3_64;
 use crate::{impl_binary_wasm, io::{Binary, BinaryState}};
+use crate::io::sample::SampleFormat;
 
 #[wasm_bindgen]
 #[derive(Clone, Default, Debug)]
@@ -37,6 +39,11 @@ impl RawBytes {
         self.get_data().map(|bytes| Uint8Array::from(bytes.as_slice()))
     }
 
+    #[wasm_bindgen(js_name = getFormat)]
+    pub fn get_format_wasm(&self) -> Option<SampleFormat> {
+        self.format()
+    }
+
     #[cfg(target_arch = "wasm32")]
     #[wasm_bindgen(js_name = getHash)]
     pub fn get_hash_wasm(&self) -> Option<u64> {
@@ -97,6 +104,20 @@ impl RawBytes {
             hash: None
         }
     }
+
+    /// `None` if it's empty or not audio
+    pub fn format(&self) -> Option<SampleFormat> {
+        self.get_data()
+            .or(self.get_unloaded_data())
+            .and_then(|bytes| SampleFormat::detect(bytes))
+    }
+
+    /// xxh3 of the bytes, loaded or not
+    pub fn content_hash(&self) -> Option<u64> {
+        self.get_data()
+            .or(self.get_unloaded_data())
+            .map(|bytes| xxh3_64(bytes))
+    }
 }
 
 impl_binary_wasm!(RawBytes, "Failed to process binary data");
\ No newline at end of file
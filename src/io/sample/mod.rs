@@ -1,5 +1,8 @@
 mod sample;
 mod sample_store;
+mod sample_format;
 
 pub use sample_store::SampleStore;
-pub use sample::Sample;
\ No newline at end of file
+pub use sample::Sample;
+pub use sample_format::SampleFormat;
+pub(crate) use sample_format::SAMPLE_EXTENSIONS;
\ No newline at end of file
@@ -0,0 +1,49 @@
+use wasm_bindgen::prelude::*;
+
+/// every extension samples are imported from
+pub(crate) const SAMPLE_EXTENSIONS: &[&str] = &["wav", "ogg", "mp3", "flac"];
+
+/// the container a sample's bytes are in
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SampleFormat {
+    Wav,
+    Ogg,
+    Mp3,
+    Flac,
+}
+
+impl SampleFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SampleFormat::Wav => "wav",
+            SampleFormat::Ogg => "ogg",
+            SampleFormat::Mp3 => "mp3",
+            SampleFormat::Flac => "flac",
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "wav" => Some(SampleFormat::Wav),
+            "ogg" => Some(SampleFormat::Ogg),
+            "mp3" => Some(SampleFormat::Mp3),
+            "flac" => Some(SampleFormat::Flac),
+            _ => None,
+        }
+    }
+
+    /// sniffed from the magic bytes, the file name can't be trusted since skins often rename
+    /// an .ogg to .wav
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', ..] => Some(SampleFormat::Wav),
+            [b'O', b'g', b'g', b'S', ..] => Some(SampleFormat::Ogg),
+            [b'f', b'L', b'a', b'C', ..] => Some(SampleFormat::Flac),
+            [b'I', b'D', b'3', ..] => Some(SampleFormat::Mp3),
+            // mpeg frame sync
+            [0xFF, second, ..] if second & 0xE0 == 0xE0 => Some(SampleFormat::Mp3),
+            _ => None,
+        }
+    }
+}
@@ -1,3 +1,51 @@
-use crate::io::BinaryStore;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
 
-pub type SampleStore = BinaryStore;
\ No newline at end of file
+use crate::audio_proc::{SampleInfo, SampleProcessing, process_sample, read_sample_info};
+use crate::error::SampleProcessError;
+use crate::io::{Binary, BinaryState, BinaryStore, Store};
+use crate::sample::Sample;
+
+pub type SampleStore = BinaryStore;
+
+impl SampleStore {
+    /// `None` if there is no such sample or it isn't wav or ogg
+    pub fn get_info(&self, path: &str) -> Option<SampleInfo> {
+        self.with_item(path, |sample| {
+            sample.get_data()
+                .or(sample.get_unloaded_data())
+                .and_then(|bytes| read_sample_info(bytes).ok())
+        }).flatten()
+    }
+
+    /// replaces the sample with a processed wav, the old one is left alone for anything still
+    /// sharing it, `Ok(false)` if there is no such sample
+    pub fn process_sample(&mut self, path: &str, options: &SampleProcessing) -> Result<bool, SampleProcessError> {
+        let Some(processed) = self.with_item(path, |sample| {
+            sample.get_data()
+                .or(sample.get_unloaded_data())
+                .map(|bytes| process_sample(bytes, options).map(|bytes| (sample.get_path().to_string(), bytes)))
+        }).flatten() else {
+            return Ok(false);
+        };
+
+        let (path, bytes) = processed?;
+        self.insert(Sample::new_with_state(path, BinaryState::Loaded(bytes)));
+        Ok(true)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl BinaryStore {
+    #[wasm_bindgen(js_name = getSampleInfo)]
+    pub fn get_info_wasm(&self, path: &str) -> Option<SampleInfo> {
+        self.get_info(path)
+    }
+
+    #[wasm_bindgen(js_name = processSample)]
+    pub fn process_sample_wasm(&mut self, path: &str, options: &SampleProcessing) -> Result<bool, JsValue> {
+        self.process_sample(path, options)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, hash_map};
 use std::fmt::Debug;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, PoisonError, RwLock};
 use wasm_bindgen::prelude::*;
 use js_sys::Array;
 
@@ -82,7 +82,7 @@ pub trait Store<T: Binary>: Debug {
     }
 
     fn insert_shared(&mut self, arc: Arc<RwLock<T>>) -> Arc<RwLock<T>> {
-        let path = normalize(Self::get_item_path(&*arc.read().unwrap()));
+        let path = normalize(Self::get_item_path(&*arc.read().unwrap_or_else(PoisonError::into_inner)));
         self.map_mut().insert(path, arc.clone());
         arc
     }
@@ -106,7 +106,7 @@ pub trait Store<T: Binary>: Debug {
     {
         let mut result = Vec::new();
         self.map().for_each_entry(|k, arc| {
-            let guard = arc.read().unwrap();
+            let guard = arc.read().unwrap_or_else(PoisonError::into_inner);
             if predicate(&*guard) {
                 result.push((k.clone(), Arc::clone(arc)));
             }
@@ -209,7 +209,7 @@ pub trait Store<T: Binary>: Debug {
         }
 
         let original_ref = self.get_shared(original_path)?;
-        let original = original_ref.read().unwrap();
+        let original = original_ref.read().unwrap_or_else(PoisonError::into_inner);
         let data = Self::clone_item_data(&*original);
         let hash = original.get_hash();
         drop(original);
@@ -228,7 +228,7 @@ pub trait Store<T: Binary>: Debug {
 
     fn make_unique_copy(&mut self, original_path: &str, new_base_path: &str) -> Option<String> {
         let original_ref = self.get_shared(original_path)?;
-        let original = original_ref.read().unwrap();
+        let original = original_ref.read().unwrap_or_else(PoisonError::into_inner);
         let data = Self::clone_item_data(&*original);
         let hash = original.get_hash();
         drop(original);
@@ -250,7 +250,7 @@ pub trait Store<T: Binary>: Debug {
                 Some(a) => a,
                 None => return HashMap::new(),
             };
-            let hash = match arc.read().unwrap().get_hash() {
+            let hash = match arc.read().unwrap_or_else(PoisonError::into_inner).get_hash() {
                 Some(h) => h,
                 None => return HashMap::new(),
             };
@@ -264,7 +264,7 @@ pub trait Store<T: Binary>: Debug {
                 continue;
             }
             if let Some(arc) = self.map().clone_value(&key) {
-                if arc.read().unwrap().get_hash() == Some(target_hash) {
+                if arc.read().unwrap_or_else(PoisonError::into_inner).get_hash() == Some(target_hash) {
                     self.map_mut().insert(key.clone(), Arc::clone(&target_arc));
                     culled.insert(key, normalized.clone());
                 }
@@ -279,7 +279,7 @@ pub trait Store<T: Binary>: Debug {
 
         let entries: Vec<(String, Arc<RwLock<T>>)> = self.iter();
         for (path, arc) in entries {
-            if let Some(hash) = arc.read().unwrap().get_hash() {
+            if let Some(hash) = arc.read().unwrap_or_else(PoisonError::into_inner).get_hash() {
                 match canonical.entry(hash) {
                     hash_map::Entry::Vacant(e) => {
                         e.insert((Arc::clone(&arc), path.to_string()));
@@ -305,7 +305,7 @@ pub trait Store<T: Binary>: Debug {
         F: FnOnce(&T) -> R,
     {
         let arc = self.get(path)?;
-        let guard = arc.read().unwrap();
+        let guard = arc.read().unwrap_or_else(PoisonError::into_inner);
         Some(f(&*guard))
     }
     
@@ -314,7 +314,7 @@ pub trait Store<T: Binary>: Debug {
         F: FnOnce(&mut T) -> R,
     {
         let arc = self.get(path)?;
-        let mut guard = arc.write().unwrap();
+        let mut guard = arc.write().unwrap_or_else(PoisonError::into_inner);
         Some(f(&mut *guard))
     }
     
@@ -323,7 +323,7 @@ pub trait Store<T: Binary>: Debug {
         F: FnMut(&T),
     {
         self.map().for_each_entry(|_, arc| {
-            let guard = arc.read().unwrap();
+            let guard = arc.read().unwrap_or_else(PoisonError::into_inner);
             f(&*guard);
         });
     }
@@ -335,7 +335,7 @@ pub trait Store<T: Binary>: Debug {
         let paths = self.map().keys_cloned();
         for path in paths {
             if let Some(arc) = self.map().clone_value(&path) {
-                let mut guard = arc.write().unwrap();
+                let mut guard = arc.write().unwrap_or_else(PoisonError::into_inner);
                 f(&mut *guard);
             }
         }
@@ -346,7 +346,7 @@ pub trait Store<T: Binary>: Debug {
         F: FnMut(&T) -> bool,
     {
         self.map_mut().retain(|_, arc| {
-            let guard = arc.read().unwrap();
+            let guard = arc.read().unwrap_or_else(PoisonError::into_inner);
             predicate(&*guard)
         });
     }
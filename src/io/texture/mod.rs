@@ -1,7 +1,12 @@
 mod texture;
 mod texture_store;
 mod texture_processor;
+mod texture_format;
+mod texture_residency;
 
 pub use texture_store::TextureStore;
 pub use texture::Texture;
+pub(crate) use texture::EncodedSource;
+pub use texture_format::TextureFormat;
+pub(crate) use texture_format::{TEXTURE_EXTENSIONS, decode_texture};
 pub use texture_processor::*;
\ No newline at end of file
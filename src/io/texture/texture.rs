@@ -1,8 +1,10 @@
 use std::fmt;
+use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 use image::{ImageError, RgbaImage};
-use xxhash_rust::xxh3::xxh3_64;
+use xxhash_rust::xxh3::{Xxh3, xxh3_64};
 use crate::{impl_binary_wasm, io::{Binary, BinaryState}};
+use crate::io::texture::{TextureFormat, decode_texture};
 
 #[wasm_bindgen]
 #[derive(Clone, Default)]
@@ -13,6 +15,36 @@ pub struct Texture {
     pub data: BinaryState<RgbaImage>,
     #[wasm_bindgen(skip)]
     pub hash: Option<u64>,
+    /// what the bytes it was made from were encoded as, `None` for textures made in memory
+    #[wasm_bindgen(skip)]
+    pub format: Option<TextureFormat>,
+    #[wasm_bindgen(skip)]
+    pub(crate) source: Option<EncodedSource>,
+}
+
+/// the bytes a loaded texture was decoded from, used again as long as its pixels are unchanged
+#[derive(Clone, Debug)]
+pub(crate) struct EncodedSource {
+    bytes: Arc<Vec<u8>>,
+    pixel_hash: u64,
+}
+
+impl EncodedSource {
+    pub(crate) fn new(bytes: Vec<u8>, img: &RgbaImage) -> Self {
+        EncodedSource { bytes: Arc::new(bytes), pixel_hash: xxh3_64(img.as_raw()) }
+    }
+
+    pub(crate) fn matches(&self, img: &RgbaImage) -> bool {
+        self.pixel_hash == xxh3_64(img.as_raw())
+    }
+
+    pub(crate) fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.bytes.len()
+    }
 }
 
 #[wasm_bindgen]
@@ -23,6 +55,8 @@ impl Texture {
             path,
             data: BinaryState::Empty,
             hash: None,
+            format: None,
+            source: None,
         }
     }
 
@@ -34,9 +68,17 @@ impl Texture {
             path,
             data: BinaryState::Loaded(img),
             hash: None,
+            format: None,
+            source: None,
         }
     }
 
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen(js_name = getFormat)]
+    pub fn get_format_wasm(&self) -> Option<TextureFormat> {
+        self.format
+    }
+
     #[cfg(target_arch = "wasm32")]
     #[wasm_bindgen(js_name = getHash)]
     pub fn get_hash_wasm(&self) -> Option<u64> {
@@ -65,11 +107,12 @@ impl Binary for Texture {
     }
 
     fn new_with_state(path: String, state: BinaryState<Self::LoadedData>) -> Self {
-        Texture { path, data: state, hash: None }
+        let format = state.as_unloaded().and_then(|bytes| TextureFormat::detect(bytes));
+        Texture { path, data: state, hash: None, format, source: None }
     }
 
     fn decode_bytes(bytes: &[u8]) -> Result<Self::LoadedData, Self::Error> {
-        image::load_from_memory(bytes).map(|img| img.to_rgba8())
+        decode_texture(bytes).map(|(img, _)| img)
     }
 
     fn encode_to_bytes(data: &Self::LoadedData) -> Result<Vec<u8>, Self::Error> {
@@ -95,24 +138,72 @@ impl Binary for Texture {
 
     fn from_bytes(path: String, bytes: &[u8]) -> Result<Self, Self::Error> {
         let hash = xxh3_64(bytes);
-        let data = Self::decode_bytes(bytes)?;
-        Ok(Texture { path, data: BinaryState::Loaded(data), hash: Some(hash) })
+        let (data, format) = decode_texture(bytes)?;
+        let source = Some(EncodedSource::new(bytes.to_vec(), &data));
+        Ok(Texture { path, data: BinaryState::Loaded(data), hash: Some(hash), format, source })
     }
 
     fn from_bytes_unloaded(path: String, bytes: Vec<u8>) -> Result<Self, Self::Error> {
         let hash = xxh3_64(&bytes);
-        Ok(Texture { path, data: BinaryState::Unloaded(bytes), hash: Some(hash) })
+        let format = TextureFormat::detect(&bytes);
+        Ok(Texture { path, data: BinaryState::Unloaded(bytes), hash: Some(hash), format, source: None })
     }
 
     fn set_from_bytes(&mut self, bytes: Vec<u8>) -> Result<(), Self::Error> {
         self.hash = Some(xxh3_64(&bytes));
-        let data = Self::decode_bytes(&bytes)?;
+        let (data, format) = decode_texture(&bytes)?;
+        self.format = format;
+        self.source = Some(EncodedSource::new(bytes, &data));
         *self.state_mut() = BinaryState::Loaded(data);
         Ok(())
     }
 
     fn set_from_bytes_unloaded(&mut self, bytes: Vec<u8>) -> Result<(), Self::Error> {
         self.hash = Some(xxh3_64(&bytes));
+        self.format = TextureFormat::detect(&bytes);
+        self.source = None;
+        *self.state_mut() = BinaryState::Unloaded(bytes);
+        Ok(())
+    }
+
+    /// new pixels, so the bytes it was decoded from don't describe it anymore
+    fn set_data(&mut self, data: Self::LoadedData) {
+        self.source = None;
+        self.data = BinaryState::Loaded(data);
+    }
+
+    /// the original bytes are kept so unloading an unchanged texture doesn't have to encode it
+    fn load(&mut self) -> Result<(), Self::Error> {
+        match self.take_unloaded_data() {
+            Some(bytes) => match decode_texture(&bytes) {
+                Ok((data, _)) => {
+                    self.source = Some(EncodedSource::new(bytes, &data));
+                    *self.state_mut() = BinaryState::Loaded(data);
+                    Ok(())
+                }
+                Err(e) => {
+                    *self.state_mut() = BinaryState::Unloaded(bytes);
+                    Err(e)
+                }
+            },
+            None if self.is_loaded() => Ok(()),
+            None => Err(Self::empty_error()),
+        }
+    }
+
+    /// goes back to the bytes it was decoded from if the pixels weren't changed, otherwise they're
+    /// encoded as png, the hash is kept either way
+    fn unload(&mut self) -> Result<(), Self::Error> {
+        let Some(data) = self.data.as_loaded() else { return Ok(()) };
+
+        let bytes = match self.source.take() {
+            Some(source) if source.matches(data) => Arc::unwrap_or_clone(source.bytes),
+            _ => {
+                let bytes = Self::encode_to_bytes(data)?;
+                self.format = Some(TextureFormat::Png);
+                bytes
+            }
+        };
         *self.state_mut() = BinaryState::Unloaded(bytes);
         Ok(())
     }
@@ -120,17 +211,56 @@ impl Binary for Texture {
 
 impl Texture {
     pub fn with_data(path: String, data: RgbaImage) -> Self {
-        Texture { path, data: BinaryState::Loaded(data), hash: None }
+        Texture { path, data: BinaryState::Loaded(data), hash: None, format: None, source: None }
     }
 
     pub fn with_unloaded_data(path: String, bytes: Vec<u8>) -> Self {
         let hash = xxh3_64(&bytes);
-        Texture { path, data: BinaryState::Unloaded(bytes), hash: Some(hash) }
+        let format = TextureFormat::detect(&bytes);
+        Texture { path, data: BinaryState::Unloaded(bytes), hash: Some(hash), format, source: None }
     }
 
     pub fn get_loaded_data(&self) -> Option<&RgbaImage> {
         self.get_data()
     }
+
+    /// the bytes it was loaded from if its pixels haven't changed since, what export writes as is
+    pub fn original_bytes(&self) -> Option<&[u8]> {
+        match &self.data {
+            BinaryState::Unloaded(bytes) => Some(bytes),
+            BinaryState::Loaded(img) => self.source.as_ref().filter(|source| source.matches(img)).map(EncodedSource::bytes),
+            BinaryState::Empty => None,
+        }
+    }
+
+    /// xxh3 of the decoded pixels and their size, the same whether the texture is loaded, unloaded
+    /// or was re-encoded when it got evicted. unloaded textures are decoded for it
+    pub fn content_hash(&self) -> Option<u64> {
+        match &self.data {
+            BinaryState::Loaded(img) => Some(pixel_hash(img)),
+            BinaryState::Unloaded(bytes) => decode_texture(bytes).ok().map(|(img, _)| pixel_hash(&img)),
+            BinaryState::Empty => None,
+        }
+    }
+
+    /// what a loaded texture holds in memory, its pixels plus the original bytes kept for export
+    pub(crate) fn loaded_size(&self) -> Option<usize> {
+        let img = self.data.as_loaded()?;
+        Some(img.as_raw().len() + self.source.as_ref().map_or(0, EncodedSource::len))
+    }
+
+    /// drops the original bytes, for when the pixels are about to change
+    pub(crate) fn forget_source(&mut self) {
+        self.source = None;
+    }
+}
+
+fn pixel_hash(img: &RgbaImage) -> u64 {
+    let mut hasher = Xxh3::new();
+    hasher.update(&img.width().to_le_bytes());
+    hasher.update(&img.height().to_le_bytes());
+    hasher.update(img.as_raw());
+    hasher.digest()
 }
 
 impl fmt::Debug for Texture {
@@ -139,6 +269,7 @@ impl fmt::Debug for Texture {
             .field("path", &self.path)
             .field("has_data", &self.data.has_data())
             .field("hash", &self.hash.map(|h| format!("{h:016x}")))
+            .field("format", &self.format)
             .finish()
     }
 }
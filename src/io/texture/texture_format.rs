@@ -0,0 +1,96 @@
+use image::{DynamicImage, ImageError, ImageFormat, RgbaImage};
+use wasm_bindgen::prelude::*;
+
+/// every extension textures are imported from
+pub(crate) const TEXTURE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "bmp", "gif", "tga"];
+
+/// how a texture's bytes are encoded
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TextureFormat {
+    #[default]
+    Png,
+    Jpeg,
+    WebP,
+    Bmp,
+    Gif,
+    Tga,
+}
+
+impl TextureFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TextureFormat::Png => "png",
+            TextureFormat::Jpeg => "jpg",
+            TextureFormat::WebP => "webp",
+            TextureFormat::Bmp => "bmp",
+            TextureFormat::Gif => "gif",
+            TextureFormat::Tga => "tga",
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "png" => Some(TextureFormat::Png),
+            "jpg" | "jpeg" => Some(TextureFormat::Jpeg),
+            "webp" => Some(TextureFormat::WebP),
+            "bmp" => Some(TextureFormat::Bmp),
+            "gif" => Some(TextureFormat::Gif),
+            "tga" => Some(TextureFormat::Tga),
+            _ => None,
+        }
+    }
+
+    /// sniffed from the magic bytes, tga has none so its header is checked last
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        match image::guess_format(bytes) {
+            Ok(ImageFormat::Png) => Some(TextureFormat::Png),
+            Ok(ImageFormat::Jpeg) => Some(TextureFormat::Jpeg),
+            Ok(ImageFormat::WebP) => Some(TextureFormat::WebP),
+            Ok(ImageFormat::Bmp) => Some(TextureFormat::Bmp),
+            Ok(ImageFormat::Gif) => Some(TextureFormat::Gif),
+            Ok(_) => None,
+            Err(_) => is_tga(bytes).then_some(TextureFormat::Tga),
+        }
+    }
+
+    pub fn image_format(&self) -> ImageFormat {
+        match self {
+            TextureFormat::Png => ImageFormat::Png,
+            TextureFormat::Jpeg => ImageFormat::Jpeg,
+            TextureFormat::WebP => ImageFormat::WebP,
+            TextureFormat::Bmp => ImageFormat::Bmp,
+            TextureFormat::Gif => ImageFormat::Gif,
+            TextureFormat::Tga => ImageFormat::Tga,
+        }
+    }
+
+    /// jpeg has no alpha, so it's dropped first
+    pub fn encode(&self, img: &RgbaImage) -> Result<Vec<u8>, ImageError> {
+        let mut bytes = Vec::new();
+        let mut cursor = std::io::Cursor::new(&mut bytes);
+        match self {
+            TextureFormat::Jpeg => DynamicImage::ImageRgba8(img.clone()).to_rgb8().write_to(&mut cursor, self.image_format())?,
+            _ => img.write_to(&mut cursor, self.image_format())?,
+        }
+        Ok(bytes)
+    }
+}
+
+/// the first frame for animated gif and webp
+pub(crate) fn decode_texture(bytes: &[u8]) -> Result<(RgbaImage, Option<TextureFormat>), ImageError> {
+    let format = TextureFormat::detect(bytes);
+    let img = match format {
+        Some(format) => image::load_from_memory_with_format(bytes, format.image_format())?,
+        None => image::load_from_memory(bytes)?,
+    };
+    Ok((img.to_rgba8(), format))
+}
+
+/// no color map or a palette one, and an image type tga defines
+fn is_tga(bytes: &[u8]) -> bool {
+    bytes.len() >= 18
+        && bytes[1] <= 1
+        && matches!(bytes[2], 1 | 2 | 3 | 9 | 10 | 11)
+        && matches!(bytes[16], 8 | 15 | 16 | 24 | 32)
+}
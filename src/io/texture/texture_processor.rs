@@ -77,6 +77,39 @@ impl<T> TextureProcessor<T> {
         self.processed.insert(key, T::default());
     }
 
+    /// `process_once` for processing that can fail, failures aren't cached
+    pub fn try_process_once<F, E>(&mut self, texture: &Arc<RwLock<Texture>>, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&Arc<RwLock<Texture>>) -> Result<T, E>,
+        T: Clone,
+    {
+        let key = self.get_key(texture);
+
+        if let Some(cached) = self.processed.get(&key) {
+            return Ok(cached.clone());
+        }
+
+        let result = f(texture)?;
+        self.processed.insert(key, result.clone());
+        Ok(result)
+    }
+
+    pub fn try_process_once_void<F, E>(&mut self, texture: &Arc<RwLock<Texture>>, f: F) -> Result<(), E>
+    where
+        F: FnOnce(&Arc<RwLock<Texture>>) -> Result<(), E>,
+        T: Default,
+    {
+        let key = self.get_key(texture);
+
+        if self.processed.contains_key(&key) {
+            return Ok(());
+        }
+
+        f(texture)?;
+        self.processed.insert(key, T::default());
+        Ok(())
+    }
+
     pub fn is_processed(&self, texture: &Arc<RwLock<Texture>>) -> bool {
         self.processed.contains_key(&self.get_key(texture))
     }
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Mutex, PoisonError};
+
+/// when each texture was last used and how many were evicted, kept while a memory budget is set
+#[derive(Default)]
+pub(crate) struct Residency {
+    state: Mutex<ResidencyState>,
+}
+
+#[derive(Clone, Default)]
+struct ResidencyState {
+    clock: u64,
+    last_used: HashMap<String, u64>,
+    evictions: usize,
+}
+
+impl Residency {
+    pub(crate) fn touch(&self, path: &str) {
+        let mut state = self.lock();
+        state.clock += 1;
+        let clock = state.clock;
+        state.last_used.insert(path.to_string(), clock);
+    }
+
+    /// 0 for textures that were never used
+    pub(crate) fn last_used(&self, path: &str) -> u64 {
+        self.lock().last_used.get(path).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn forget(&self, path: &str) {
+        self.lock().last_used.remove(path);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.lock().last_used.clear();
+    }
+
+    pub(crate) fn count_evictions(&self, count: usize) {
+        self.lock().evictions += count;
+    }
+
+    pub(crate) fn evictions(&self) -> usize {
+        self.lock().evictions
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ResidencyState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Clone for Residency {
+    fn clone(&self) -> Self {
+        Residency { state: Mutex::new(self.lock().clone()) }
+    }
+}
+
+impl fmt::Debug for Residency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("Residency")
+            .field("tracked", &state.last_used.len())
+            .field("evictions", &state.evictions)
+            .finish()
+    }
+}
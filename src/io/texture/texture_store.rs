@@ -1,4 +1,4 @@
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, PoisonError, RwLock};
 use dashmap::DashMap;
 use merge::Merge;
 use wasm_bindgen::prelude::*;
@@ -9,7 +9,9 @@ use js_sys::Array;
 
 use image::{ImageError, RgbaImage};
 use crate::{Binary, BinaryState, impl_store_wasm, io::Store, utils::io::normalize};
-use crate::io::texture::Texture;
+use crate::extensions::TextureArcExt;
+use crate::io::texture::{Texture, TextureFormat};
+use crate::io::texture::texture_residency::Residency;
 use crate::utils;
 
 #[wasm_bindgen]
@@ -19,10 +21,25 @@ pub struct TextureStore {
     #[merge(strategy = utils::merge::dashmap::overwrite)]
     pub(crate) textures: DashMap<String, Arc<RwLock<Texture>>>,
 
-    /// indexes are scaled lower in powers of 2 (0 = 1/2, 1 = 1/4, 2 = 1/8, etc.)
+    /// indexes are scaled lower in powers of 2 (0 = 1/2, 1 = 1/4, 2 = 1/8, etc.),
+    /// textures with an entry here are HD, an empty list is an `@2x` import without an SD file
     #[wasm_bindgen(skip)]
     #[merge(strategy = utils::merge::dashmap::overwrite)]
     pub(crate) mipmaps: DashMap<String, Vec<RgbaImage>>,
+
+    /// what changed textures are encoded as on export, untouched ones keep their original bytes
+    #[wasm_bindgen(skip)]
+    #[merge(skip)]
+    pub encode_format: TextureFormat,
+
+    /// how many bytes loaded textures may hold, see `loaded_bytes`, before they are unloaded again, `None` for no limit
+    #[wasm_bindgen(skip)]
+    #[merge(skip)]
+    pub memory_budget: Option<usize>,
+
+    #[wasm_bindgen(skip)]
+    #[merge(skip)]
+    residency: Residency,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -33,6 +50,9 @@ impl TextureStore {
         TextureStore {
             textures: DashMap::new(),
             mipmaps: DashMap::new(),
+            encode_format: TextureFormat::default(),
+            memory_budget: None,
+            residency: Residency::default(),
         }
     }
 
@@ -58,6 +78,41 @@ impl TextureStore {
             .map_err(|e| JsValue::from_str(&format!("Failed to load texture from uint8 array: {}", e)))
     }
 
+    #[wasm_bindgen(js_name = getEncodeFormat)]
+    pub fn get_encode_format_wasm(&self) -> TextureFormat {
+        self.encode_format
+    }
+
+    #[wasm_bindgen(js_name = setEncodeFormat)]
+    pub fn set_encode_format_wasm(&mut self, format: TextureFormat) {
+        self.encode_format = format;
+    }
+
+    #[wasm_bindgen(js_name = getMemoryBudget)]
+    pub fn get_memory_budget_wasm(&self) -> Option<usize> {
+        self.memory_budget
+    }
+
+    #[wasm_bindgen(js_name = setMemoryBudget)]
+    pub fn set_memory_budget_wasm(&mut self, budget: Option<usize>) {
+        self.memory_budget = budget;
+    }
+
+    #[wasm_bindgen(js_name = trimToBudget)]
+    pub fn trim_to_budget_wasm(&self) -> usize {
+        self.trim_to_budget()
+    }
+
+    #[wasm_bindgen(js_name = loadedBytes)]
+    pub fn loaded_bytes_wasm(&self) -> usize {
+        self.loaded_bytes()
+    }
+
+    #[wasm_bindgen(js_name = evictions)]
+    pub fn evictions_wasm(&self) -> usize {
+        self.evictions()
+    }
+
     #[wasm_bindgen(js_name = allLoaded)]
     pub fn all_loaded_wasm(&self) -> bool {
         self.all_loaded()
@@ -100,10 +155,13 @@ impl Store<Texture> for TextureStore {
     type MapType = DashMap<String, Arc<RwLock<Texture>>>;
     
     fn create_item(path: String, data: Self::Data, hash: Option<u64>) -> Texture {
+        let format = data.as_unloaded().and_then(|bytes| TextureFormat::detect(bytes));
         Texture {
             path,
             data,
             hash,
+            format,
+            source: None,
         }
     }
     
@@ -119,6 +177,17 @@ impl Store<Texture> for TextureStore {
         item.state().clone()
     }
     
+    /// while there's a memory budget this counts as using the texture, which may evict others
+    fn get(&self, path: &str) -> Option<Arc<RwLock<Texture>>> {
+        let normalized = normalize(path);
+        let texture = self.textures.get(&normalized).map(|entry| Arc::clone(entry.value()))?;
+        if self.memory_budget.is_some() {
+            self.residency.touch(&normalized);
+            self.evict_over_budget(&normalized);
+        }
+        Some(texture)
+    }
+
     fn map(&self) -> &DashMap<String, Arc<RwLock<Texture>>> {
         &self.textures
     }
@@ -133,18 +202,23 @@ impl TextureStore {
         TextureStore {
             textures: DashMap::new(),
             mipmaps: DashMap::new(),
+            encode_format: TextureFormat::default(),
+            memory_budget: None,
+            residency: Residency::default(),
         }
     }
 
     pub fn remove(&mut self, path: &str) -> bool {
         let normalized = normalize(path);
         self.mipmaps.remove(&normalized);
+        self.residency.forget(&normalized);
         self.textures.remove(&normalized).is_some()
     }
 
     pub fn clear(&mut self) {
         self.textures.clear();
         self.mipmaps.clear();
+        self.residency.clear();
     }
 
     pub fn load_from_bytes(&mut self, path: String, bytes: &[u8]) -> Result<(), ImageError> {
@@ -174,21 +248,21 @@ impl TextureStore {
     
     pub fn all_loaded(&self) -> bool {
         self.textures.iter().all(|entry| {
-            let texture = entry.value().read().unwrap();
+            let texture = entry.value().read().unwrap_or_else(PoisonError::into_inner);
             texture.has_data()
         })
     }
     
     pub fn loaded_count(&self) -> usize {
         self.textures.iter().filter(|entry| {
-            let texture = entry.value().read().unwrap();
+            let texture = entry.value().read().unwrap_or_else(PoisonError::into_inner);
             texture.has_data()
         }).count()
     }
     
     pub fn unloaded_paths(&self) -> Vec<String> {
         self.textures.iter().filter_map(|entry| {
-            let texture = entry.value().read().unwrap();
+            let texture = entry.value().read().unwrap_or_else(PoisonError::into_inner);
             if !texture.has_data() {
                 Some(texture.get_path().to_string())
             } else {
@@ -197,6 +271,67 @@ impl TextureStore {
         }).collect()
     }
 
+    /// decodes the texture if it's still unloaded, then evicts others if that went over the budget
+    pub fn with_image<F, R>(&self, path: &str, f: F) -> Option<R>
+    where
+        F: FnOnce(&RgbaImage) -> R,
+    {
+        let result = self.get(path)?.image_ref(f);
+        self.evict_over_budget(&normalize(path));
+        result
+    }
+
+    /// evicts the least recently used textures until the loaded ones fit the budget, returns how many were evicted
+    pub fn trim_to_budget(&self) -> usize {
+        self.evict_over_budget("")
+    }
+
+    /// bytes held by loaded textures, their decoded pixels and the original bytes kept next to them
+    pub fn loaded_bytes(&self) -> usize {
+        self.textures.iter().filter_map(|entry| {
+            entry.value().read().unwrap_or_else(PoisonError::into_inner).loaded_size()
+        }).sum()
+    }
+
+    /// how many textures were unloaded to stay under the budget
+    pub fn evictions(&self) -> usize {
+        self.residency.evictions()
+    }
+
+    /// textures locked by someone else are in use, so they're neither counted nor evicted
+    fn evict_over_budget(&self, keep: &str) -> usize {
+        let Some(budget) = self.memory_budget else { return 0 };
+
+        let mut loaded: Vec<(u64, String, Arc<RwLock<Texture>>, usize)> = self.iter()
+            .into_iter()
+            .filter_map(|(path, texture)| {
+                let size = texture.try_read().ok()?.loaded_size()?;
+                Some((self.residency.last_used(&path), path, texture, size))
+            })
+            .collect();
+
+        let mut total: usize = loaded.iter().map(|(_, _, _, size)| size).sum();
+        loaded.sort_by(|(a_used, a, _, _), (b_used, b, _, _)| a_used.cmp(b_used).then_with(|| a.cmp(b)));
+
+        let mut evicted = 0;
+        for (_, path, texture, size) in loaded {
+            if total <= budget {
+                break;
+            }
+            if path == keep {
+                continue;
+            }
+            let Ok(mut texture) = texture.try_write() else { continue };
+            if texture.unload().is_ok() {
+                total -= size;
+                evicted += 1;
+            }
+        }
+
+        self.residency.count_evictions(evicted);
+        evicted
+    }
+
     pub fn set_mipmaps(&mut self, path: &str, mips: Vec<RgbaImage>) {
         let normalized = normalize(path);
         self.mipmaps.insert(normalized, mips);
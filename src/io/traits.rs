@@ -23,6 +23,35 @@ impl GetAllTextures for Arc<RwLock<Texture>> {
     }
 }
 
+pub(crate) type TextureMapper<'a> = dyn FnMut(&Arc<RwLock<Texture>>) -> Arc<RwLock<Texture>> + 'a;
+
+/// swaps every texture for whatever `f` returns, for moving elements into another skin's store
+pub(crate) trait MapTextures {
+    fn map_textures(&mut self, f: &mut TextureMapper);
+}
+
+impl<T: MapTextures> MapTextures for Option<T> {
+    fn map_textures(&mut self, f: &mut TextureMapper) {
+        if let Some(inner) = self {
+            inner.map_textures(f);
+        }
+    }
+}
+
+impl<T: MapTextures> MapTextures for Vec<T> {
+    fn map_textures(&mut self, f: &mut TextureMapper) {
+        for item in self {
+            item.map_textures(f);
+        }
+    }
+}
+
+impl MapTextures for Arc<RwLock<Texture>> {
+    fn map_textures(&mut self, f: &mut TextureMapper) {
+        *self = f(self);
+    }
+}
+
 #[doc(hidden)]
 pub struct Wrap<T>(pub T);
 
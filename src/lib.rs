@@ -14,19 +14,28 @@ mod resources;
 mod parse;
 
 pub mod image_proc;
+pub mod audio_proc;
 pub mod utils;
 pub mod extensions;
 pub mod error;
+pub mod lint;
+pub mod diff;
 
 pub use skin::osu;
 pub use skin::quaver;
 pub use skin::fluxis;
+pub use skin::etterna;
+pub use skin::malody;
 pub use skin::generic;
 pub use parse::ini;
+pub use converting::report;
+pub use converting::options;
 
 pub use osu::OsuSkin;
 pub use quaver::QuaSkin;
 pub use fluxis::FluXisSkin;
+pub use etterna::EtternaNoteskin;
+pub use malody::MalodySkin;
 pub use generic::GenericManiaSkin;
 
 pub use extensions::{TextureArcExt, BinaryArcExt, BinaryArcExtOption};
@@ -42,10 +51,14 @@ pub mod prelude {
     pub use crate::OsuSkin;
     pub use crate::QuaSkin;
     pub use crate::FluXisSkin;
+    pub use crate::EtternaNoteskin;
+    pub use crate::MalodySkin;
     
     pub use crate::osu::OsuSkinIni;
     pub use crate::quaver::QuaSkinIni;
     pub use crate::fluxis::{SkinJson, FluXisLayout};
+    pub use crate::etterna::EtternaMetrics;
+    pub use crate::malody::ConfJson;
     
     pub use crate::io::{Binary, RawBytes, BinaryState, BinaryStore, Store};
     pub use crate::io::texture::{TextureStore, Texture};
@@ -53,6 +66,11 @@ pub mod prelude {
     
     pub use crate::common::traits::*;
     pub use crate::extensions::*;
+    pub use crate::lint::Lint;
+    pub use crate::diff::{Diff, SkinDiff, ThreeWayMerge};
+    pub use crate::generic::{MergePlan, MergeWithPlan};
+    pub use crate::report::ConversionReport;
+    pub use crate::options::ConversionOptions;
     
     pub use crate::export;
     pub use crate::import;
@@ -61,21 +79,34 @@ pub mod prelude {
 #[cfg(not(target_arch = "wasm32"))]
 pub mod export {
     use std::io;
-    use crate::{exporting::native::*, io::texture::TextureStore, sample::SampleStore};
+    use crate::{exporting::native::*, exporting::archive::*, io::texture::TextureStore, sample::SampleStore};
+    pub use crate::exporting::incremental::{ExportSummary, ArchiveUpdate};
 
     pub fn textures_to_dir(textures: &TextureStore, path: &str) -> io::Result<()> { export_textures(textures, path) }
     pub fn samples_to_dir(samples: &SampleStore, path: &str) -> io::Result<()> { export_samples(samples, path) }
+    pub fn textures_to_dir_incremental(textures: &TextureStore, path: &str) -> io::Result<ExportSummary> { export_textures_incremental(textures, path) }
+    pub fn samples_to_dir_incremental(samples: &SampleStore, path: &str) -> io::Result<ExportSummary> { export_samples_incremental(samples, path) }
 
     pub mod osu {
         use super::*;
         pub fn skin_to_dir(skin: &crate::osu::OsuSkin, path: &str) -> io::Result<()> { export_osu_skin(skin, path) }
+        pub fn skin_to_dir_with(skin: &crate::osu::OsuSkin, path: &str, options: &crate::osu::OsuExportOptions) -> io::Result<()> { export_osu_skin_with(skin, path, options) }
         pub fn ini_to_dir(skin_ini: &crate::osu::OsuSkinIni, path: &str) -> io::Result<()> { export_osu_ini(skin_ini, path) }
+        pub fn skin_to_archive(skin: &crate::osu::OsuSkin) -> io::Result<Vec<u8>> { export_osu_skin_to_archive(skin) }
+        pub fn skin_to_archive_with(skin: &crate::osu::OsuSkin, options: &crate::osu::OsuExportOptions) -> io::Result<Vec<u8>> { export_osu_skin_to_archive_with(skin, options) }
+        pub fn skin_to_archive_file(skin: &crate::osu::OsuSkin, path: &str) -> io::Result<()> { export_osu_skin_to_archive_file(skin, path) }
+        pub fn skin_to_dir_incremental(skin: &crate::osu::OsuSkin, path: &str, remove_stale: bool) -> io::Result<ExportSummary> { export_osu_skin_incremental(skin, path, remove_stale) }
+        pub fn update_archive(skin: &crate::osu::OsuSkin, previous: &[u8], remove_stale: bool) -> io::Result<ArchiveUpdate> { update_osu_skin_archive(skin, previous, remove_stale) }
     }
 
     pub mod quaver {
         use super::*;
         pub fn skin_to_dir(skin: &crate::quaver::QuaSkin, path: &str) -> io::Result<()> { export_quaver_skin(skin, path) }
         pub fn ini_to_dir(skin_ini: &crate::quaver::QuaSkinIni, path: &str) -> io::Result<()> { export_quaver_ini(skin_ini, path) }
+        pub fn skin_to_archive(skin: &crate::quaver::QuaSkin) -> io::Result<Vec<u8>> { export_quaver_skin_to_archive(skin) }
+        pub fn skin_to_archive_file(skin: &crate::quaver::QuaSkin, path: &str) -> io::Result<()> { export_quaver_skin_to_archive_file(skin, path) }
+        pub fn skin_to_dir_incremental(skin: &crate::quaver::QuaSkin, path: &str, remove_stale: bool) -> io::Result<ExportSummary> { export_quaver_skin_incremental(skin, path, remove_stale) }
+        pub fn update_archive(skin: &crate::quaver::QuaSkin, previous: &[u8], remove_stale: bool) -> io::Result<ArchiveUpdate> { update_quaver_skin_archive(skin, previous, remove_stale) }
     }
 
     pub mod fluxis {
@@ -83,6 +114,30 @@ pub mod export {
         pub fn skin_to_dir(skin: &crate::fluxis::FluXisSkin, path: &str) -> io::Result<()> { export_fluxis_skin(skin, path) }
         pub fn layout_to_dir(layout_json: &crate::fluxis::FluXisLayout, path: &str) -> io::Result<()> { export_fluxis_layout_json(layout_json, path) }
         pub fn json_to_dir(skin_json: &crate::fluxis::SkinJson, path: &str) -> io::Result<()> { export_fluxis_skin_json(skin_json, path) }
+        pub fn skin_to_archive(skin: &crate::fluxis::FluXisSkin) -> io::Result<Vec<u8>> { export_fluxis_skin_to_archive(skin) }
+        pub fn skin_to_archive_file(skin: &crate::fluxis::FluXisSkin, path: &str) -> io::Result<()> { export_fluxis_skin_to_archive_file(skin, path) }
+        pub fn skin_to_dir_incremental(skin: &crate::fluxis::FluXisSkin, path: &str, remove_stale: bool) -> io::Result<ExportSummary> { export_fluxis_skin_incremental(skin, path, remove_stale) }
+        pub fn update_archive(skin: &crate::fluxis::FluXisSkin, previous: &[u8], remove_stale: bool) -> io::Result<ArchiveUpdate> { update_fluxis_skin_archive(skin, previous, remove_stale) }
+    }
+
+    pub mod malody {
+        use super::*;
+        pub fn skin_to_dir(skin: &crate::malody::MalodySkin, path: &str) -> io::Result<()> { export_malody_skin(skin, path) }
+        pub fn json_to_dir(conf_json: &crate::malody::ConfJson, path: &str) -> io::Result<()> { export_malody_conf_json(conf_json, path) }
+        pub fn skin_to_archive(skin: &crate::malody::MalodySkin) -> io::Result<Vec<u8>> { export_malody_skin_to_archive(skin) }
+        pub fn skin_to_archive_file(skin: &crate::malody::MalodySkin, path: &str) -> io::Result<()> { export_malody_skin_to_archive_file(skin, path) }
+        pub fn skin_to_dir_incremental(skin: &crate::malody::MalodySkin, path: &str, remove_stale: bool) -> io::Result<ExportSummary> { export_malody_skin_incremental(skin, path, remove_stale) }
+        pub fn update_archive(skin: &crate::malody::MalodySkin, previous: &[u8], remove_stale: bool) -> io::Result<ArchiveUpdate> { update_malody_skin_archive(skin, previous, remove_stale) }
+    }
+
+    pub mod etterna {
+        use super::*;
+        pub fn skin_to_dir(skin: &crate::etterna::EtternaNoteskin, path: &str) -> io::Result<()> { export_etterna_noteskin(skin, path) }
+        pub fn metrics_to_dir(metrics: &crate::etterna::EtternaMetrics, path: &str) -> io::Result<()> { export_etterna_metrics(metrics, path) }
+        pub fn skin_to_archive(skin: &crate::etterna::EtternaNoteskin) -> io::Result<Vec<u8>> { export_etterna_noteskin_to_archive(skin) }
+        pub fn skin_to_archive_file(skin: &crate::etterna::EtternaNoteskin, path: &str) -> io::Result<()> { export_etterna_noteskin_to_archive_file(skin, path) }
+        pub fn skin_to_dir_incremental(skin: &crate::etterna::EtternaNoteskin, path: &str, remove_stale: bool) -> io::Result<ExportSummary> { export_etterna_noteskin_incremental(skin, path, remove_stale) }
+        pub fn update_archive(skin: &crate::etterna::EtternaNoteskin, previous: &[u8], remove_stale: bool) -> io::Result<ArchiveUpdate> { update_etterna_noteskin_archive(skin, previous, remove_stale) }
     }
 }
 
@@ -90,7 +145,8 @@ pub mod export {
 pub mod export {
     use wasm_bindgen::prelude::*;
     use js_sys::Map;
-    use crate::{exporting::browser::*, io::texture::TextureStore, sample::SampleStore, utils::wasm::*};
+    use crate::{exporting::browser::*, exporting::archive::*, io::texture::TextureStore, sample::SampleStore, utils::wasm::*};
+    pub use crate::exporting::incremental::{ExportSummary, ArchiveUpdate};
 
     #[wasm_bindgen(js_name = texturesToFiles)]
     pub fn textures_to_files(textures: &TextureStore) -> Result<Map, JsError> { Ok(hash_to_js(export_textures(textures)?)) }
@@ -103,8 +159,23 @@ pub mod export {
         #[wasm_bindgen(js_name = osuSkinToFiles)]
         pub fn skin_to_files(skin: &crate::osu::OsuSkin) -> Result<Map, JsError> { Ok(hash_to_js(export_osu_skin(skin)?)) }
 
+        #[wasm_bindgen(js_name = osuSkinToFilesWith)]
+        pub fn skin_to_files_with(skin: &crate::osu::OsuSkin, options: &crate::osu::OsuExportOptions) -> Result<Map, JsError> { Ok(hash_to_js(export_osu_skin_with(skin, options)?)) }
+
         #[wasm_bindgen(js_name = osuIniToString)]
         pub fn ini_to_string(skin_ini: &crate::osu::OsuSkinIni) -> String { export_osu_ini(skin_ini) }
+
+        #[wasm_bindgen(js_name = osuSkinToArchive)]
+        pub fn skin_to_archive(skin: &crate::osu::OsuSkin) -> Result<Vec<u8>, JsError> { export_osu_skin_to_archive(skin).map_err(|e| JsError::new(&e.to_string())) }
+
+        #[wasm_bindgen(js_name = osuSkinToArchiveWith)]
+        pub fn skin_to_archive_with(skin: &crate::osu::OsuSkin, options: &crate::osu::OsuExportOptions) -> Result<Vec<u8>, JsError> { export_osu_skin_to_archive_with(skin, options).map_err(|e| JsError::new(&e.to_string())) }
+
+        #[wasm_bindgen(js_name = osuArchiveName)]
+        pub fn archive_name(skin: &crate::osu::OsuSkin) -> String { osu_archive_name(skin) }
+
+        #[wasm_bindgen(js_name = osuSkinUpdateArchive)]
+        pub fn update_archive(skin: &crate::osu::OsuSkin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { update_osu_skin_archive(skin, previous, remove_stale).map_err(|e| JsError::new(&e.to_string())) }
     }
 
     pub mod quaver {
@@ -114,6 +185,15 @@ pub mod export {
 
         #[wasm_bindgen(js_name = quaverIniToString)]
         pub fn ini_to_string(skin_ini: &crate::quaver::QuaSkinIni) -> String { export_quaver_ini(skin_ini) }
+
+        #[wasm_bindgen(js_name = quaverSkinToArchive)]
+        pub fn skin_to_archive(skin: &crate::quaver::QuaSkin) -> Result<Vec<u8>, JsError> { export_quaver_skin_to_archive(skin).map_err(|e| JsError::new(&e.to_string())) }
+
+        #[wasm_bindgen(js_name = quaverArchiveName)]
+        pub fn archive_name(skin: &crate::quaver::QuaSkin) -> String { quaver_archive_name(skin) }
+
+        #[wasm_bindgen(js_name = quaverSkinUpdateArchive)]
+        pub fn update_archive(skin: &crate::quaver::QuaSkin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { update_quaver_skin_archive(skin, previous, remove_stale).map_err(|e| JsError::new(&e.to_string())) }
     }
 
     pub mod fluxis {
@@ -126,13 +206,59 @@ pub mod export {
 
         #[wasm_bindgen(js_name = fluxisJsonToString)]
         pub fn json_to_string(skin_json: &crate::fluxis::SkinJson) -> String { export_fluxis_skin_json(skin_json) }
+
+        #[wasm_bindgen(js_name = fluXisSkinToArchive)]
+        pub fn skin_to_archive(skin: &crate::fluxis::FluXisSkin) -> Result<Vec<u8>, JsError> { export_fluxis_skin_to_archive(skin).map_err(|e| JsError::new(&e.to_string())) }
+
+        #[wasm_bindgen(js_name = fluXisArchiveName)]
+        pub fn archive_name(skin: &crate::fluxis::FluXisSkin) -> String { fluxis_archive_name(skin) }
+
+        #[wasm_bindgen(js_name = fluXisSkinUpdateArchive)]
+        pub fn update_archive(skin: &crate::fluxis::FluXisSkin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { update_fluxis_skin_archive(skin, previous, remove_stale).map_err(|e| JsError::new(&e.to_string())) }
+    }
+
+    pub mod malody {
+        use super::*;
+        #[wasm_bindgen(js_name = malodySkinToFiles)]
+        pub fn skin_to_files(skin: &crate::malody::MalodySkin) -> Result<Map, JsError> { Ok(hash_to_js(export_malody_skin(skin)?)) }
+
+        #[wasm_bindgen(js_name = malodyJsonToString)]
+        pub fn json_to_string(conf_json: &crate::malody::ConfJson) -> String { export_malody_conf_json(conf_json) }
+
+        #[wasm_bindgen(js_name = malodySkinToArchive)]
+        pub fn skin_to_archive(skin: &crate::malody::MalodySkin) -> Result<Vec<u8>, JsError> { export_malody_skin_to_archive(skin).map_err(|e| JsError::new(&e.to_string())) }
+
+        #[wasm_bindgen(js_name = malodyArchiveName)]
+        pub fn archive_name(skin: &crate::malody::MalodySkin) -> String { malody_archive_name(skin) }
+
+        #[wasm_bindgen(js_name = malodySkinUpdateArchive)]
+        pub fn update_archive(skin: &crate::malody::MalodySkin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { update_malody_skin_archive(skin, previous, remove_stale).map_err(|e| JsError::new(&e.to_string())) }
+    }
+
+    pub mod etterna {
+        use super::*;
+        #[wasm_bindgen(js_name = etternaNoteskinToFiles)]
+        pub fn skin_to_files(skin: &crate::etterna::EtternaNoteskin) -> Result<Map, JsError> { Ok(hash_to_js(export_etterna_noteskin(skin)?)) }
+
+        #[wasm_bindgen(js_name = etternaMetricsToString)]
+        pub fn metrics_to_string(metrics: &crate::etterna::EtternaMetrics) -> String { export_etterna_metrics(metrics) }
+
+        #[wasm_bindgen(js_name = etternaNoteskinToArchive)]
+        pub fn skin_to_archive(skin: &crate::etterna::EtternaNoteskin) -> Result<Vec<u8>, JsError> { export_etterna_noteskin_to_archive(skin).map_err(|e| JsError::new(&e.to_string())) }
+
+        #[wasm_bindgen(js_name = etternaArchiveName)]
+        pub fn archive_name(skin: &crate::etterna::EtternaNoteskin) -> String { etterna_archive_name(skin) }
+
+        #[wasm_bindgen(js_name = etternaNoteskinUpdateArchive)]
+        pub fn update_archive(skin: &crate::etterna::EtternaNoteskin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { update_etterna_noteskin_archive(skin, previous, remove_stale).map_err(|e| JsError::new(&e.to_string())) }
     }
 }
 
 #[cfg(all(target_arch = "wasm32", feature = "node"))]
 pub mod export {
     use wasm_bindgen::prelude::*;
-    use crate::{exporting::node::*, io::texture::TextureStore, sample::SampleStore};
+    use crate::{exporting::node::*, exporting::archive::*, io::texture::TextureStore, sample::SampleStore};
+    pub use crate::exporting::incremental::{ExportSummary, ArchiveUpdate};
 
     macro_rules! map_err { ($e:expr) => { $e.map_err(|e| JsError::new(&e.to_string())) } }
 
@@ -147,8 +273,23 @@ pub mod export {
         #[wasm_bindgen(js_name = osuSkinToDir)]
         pub fn skin_to_dir(skin: &crate::osu::OsuSkin, path: &str) -> Result<(), JsError> { map_err!(export_osu_skin(skin, path)) }
 
+        #[wasm_bindgen(js_name = osuSkinToDirWith)]
+        pub fn skin_to_dir_with(skin: &crate::osu::OsuSkin, path: &str, options: &crate::osu::OsuExportOptions) -> Result<(), JsError> { map_err!(export_osu_skin_with(skin, path, options)) }
+
         #[wasm_bindgen(js_name = osuIniToDir)]
         pub fn ini_to_dir(skin_ini: &crate::osu::OsuSkinIni, path: &str) -> Result<(), JsError> { map_err!(export_osu_ini(skin_ini, path)) }
+
+        #[wasm_bindgen(js_name = osuSkinToArchive)]
+        pub fn skin_to_archive(skin: &crate::osu::OsuSkin) -> Result<Vec<u8>, JsError> { map_err!(export_osu_skin_to_archive(skin)) }
+
+        #[wasm_bindgen(js_name = osuSkinToArchiveWith)]
+        pub fn skin_to_archive_with(skin: &crate::osu::OsuSkin, options: &crate::osu::OsuExportOptions) -> Result<Vec<u8>, JsError> { map_err!(export_osu_skin_to_archive_with(skin, options)) }
+
+        #[wasm_bindgen(js_name = osuSkinToArchiveFile)]
+        pub fn skin_to_archive_file(skin: &crate::osu::OsuSkin, path: &str) -> Result<(), JsError> { map_err!(export_osu_skin_to_archive_file(skin, path)) }
+
+        #[wasm_bindgen(js_name = osuSkinUpdateArchive)]
+        pub fn update_archive(skin: &crate::osu::OsuSkin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { map_err!(update_osu_skin_archive(skin, previous, remove_stale)) }
     }
 
     pub mod quaver {
@@ -158,6 +299,15 @@ pub mod export {
 
         #[wasm_bindgen(js_name = quaverIniToDir)]
         pub fn ini_to_dir(skin_ini: &crate::quaver::QuaSkinIni, path: &str) -> Result<(), JsError> { map_err!(export_quaver_ini(skin_ini, path)) }
+
+        #[wasm_bindgen(js_name = quaverSkinToArchive)]
+        pub fn skin_to_archive(skin: &crate::quaver::QuaSkin) -> Result<Vec<u8>, JsError> { map_err!(export_quaver_skin_to_archive(skin)) }
+
+        #[wasm_bindgen(js_name = quaverSkinToArchiveFile)]
+        pub fn skin_to_archive_file(skin: &crate::quaver::QuaSkin, path: &str) -> Result<(), JsError> { map_err!(export_quaver_skin_to_archive_file(skin, path)) }
+
+        #[wasm_bindgen(js_name = quaverSkinUpdateArchive)]
+        pub fn update_archive(skin: &crate::quaver::QuaSkin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { map_err!(update_quaver_skin_archive(skin, previous, remove_stale)) }
      }
 
      pub mod fluxis {
@@ -170,12 +320,57 @@ pub mod export {
 
         #[wasm_bindgen(js_name = fluxisJsonToDir)]
         pub fn json_to_dir(skin_json: &crate::fluxis::SkinJson, path: &str) -> Result<(), JsError> { map_err!(export_fluxis_skin_json(skin_json, path)) }
+
+        #[wasm_bindgen(js_name = fluXisSkinToArchive)]
+        pub fn skin_to_archive(skin: &crate::fluxis::FluXisSkin) -> Result<Vec<u8>, JsError> { map_err!(export_fluxis_skin_to_archive(skin)) }
+
+        #[wasm_bindgen(js_name = fluXisSkinToArchiveFile)]
+        pub fn skin_to_archive_file(skin: &crate::fluxis::FluXisSkin, path: &str) -> Result<(), JsError> { map_err!(export_fluxis_skin_to_archive_file(skin, path)) }
+
+        #[wasm_bindgen(js_name = fluXisSkinUpdateArchive)]
+        pub fn update_archive(skin: &crate::fluxis::FluXisSkin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { map_err!(update_fluxis_skin_archive(skin, previous, remove_stale)) }
+     }
+
+     pub mod malody {
+        use super::*;
+        #[wasm_bindgen(js_name = malodySkinToDir)]
+        pub fn skin_to_dir(skin: &crate::malody::MalodySkin, path: &str) -> Result<(), JsError> { map_err!(export_malody_skin(skin, path)) }
+
+        #[wasm_bindgen(js_name = malodyJsonToDir)]
+        pub fn json_to_dir(conf_json: &crate::malody::ConfJson, path: &str) -> Result<(), JsError> { map_err!(export_malody_conf_json(conf_json, path)) }
+
+        #[wasm_bindgen(js_name = malodySkinToArchive)]
+        pub fn skin_to_archive(skin: &crate::malody::MalodySkin) -> Result<Vec<u8>, JsError> { map_err!(export_malody_skin_to_archive(skin)) }
+
+        #[wasm_bindgen(js_name = malodySkinToArchiveFile)]
+        pub fn skin_to_archive_file(skin: &crate::malody::MalodySkin, path: &str) -> Result<(), JsError> { map_err!(export_malody_skin_to_archive_file(skin, path)) }
+
+        #[wasm_bindgen(js_name = malodySkinUpdateArchive)]
+        pub fn update_archive(skin: &crate::malody::MalodySkin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { map_err!(update_malody_skin_archive(skin, previous, remove_stale)) }
+    }
+
+     pub mod etterna {
+        use super::*;
+        #[wasm_bindgen(js_name = etternaNoteskinToDir)]
+        pub fn skin_to_dir(skin: &crate::etterna::EtternaNoteskin, path: &str) -> Result<(), JsError> { map_err!(export_etterna_noteskin(skin, path)) }
+
+        #[wasm_bindgen(js_name = etternaMetricsToDir)]
+        pub fn metrics_to_dir(metrics: &crate::etterna::EtternaMetrics, path: &str) -> Result<(), JsError> { map_err!(export_etterna_metrics(metrics, path)) }
+
+        #[wasm_bindgen(js_name = etternaNoteskinToArchive)]
+        pub fn skin_to_archive(skin: &crate::etterna::EtternaNoteskin) -> Result<Vec<u8>, JsError> { map_err!(export_etterna_noteskin_to_archive(skin)) }
+
+        #[wasm_bindgen(js_name = etternaNoteskinToArchiveFile)]
+        pub fn skin_to_archive_file(skin: &crate::etterna::EtternaNoteskin, path: &str) -> Result<(), JsError> { map_err!(export_etterna_noteskin_to_archive_file(skin, path)) }
+
+        #[wasm_bindgen(js_name = etternaNoteskinUpdateArchive)]
+        pub fn update_archive(skin: &crate::etterna::EtternaNoteskin, previous: &[u8], remove_stale: bool) -> Result<ArchiveUpdate, JsError> { map_err!(update_etterna_noteskin_archive(skin, previous, remove_stale)) }
      }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod import {
-    use crate::{StringPattern, error::ImportError, importing::native::*, io::texture::TextureStore, sample::SampleStore};
+    use crate::{StringPattern, error::ImportError, importing::native::*, importing::archive::*, io::texture::TextureStore, sample::SampleStore};
 
     type Res<T> = Result<T, ImportError>;
 
@@ -187,76 +382,134 @@ pub mod import {
     pub mod osu {
         use super::*;
         pub fn skin_from_dir(path: &str, import_all: bool) -> Res<crate::osu::OsuSkin> { import_osu_mania_skin_from_dir(path, import_all) }
+        pub fn skin_from_archive(bytes: &[u8], import_all: bool) -> Res<crate::osu::OsuSkin> { import_osu_mania_skin_from_archive(bytes, import_all) }
         pub fn ini_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
     }
 
     pub mod quaver {
         use super::*;
         pub fn skin_from_dir(path: &str, import_all: bool) -> Res<crate::quaver::QuaSkin> { import_quaver_skin_from_dir(path, import_all) }
+        pub fn skin_from_archive(bytes: &[u8], import_all: bool) -> Res<crate::quaver::QuaSkin> { import_quaver_skin_from_archive(bytes, import_all) }
         pub fn ini_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
     }
 
     pub mod fluxis {
         use super::*;
         pub fn skin_from_dir(path: &str, import_all: bool) -> Res<crate::fluxis::FluXisSkin> { import_fluxis_skin_from_dir(path, import_all) }
+        pub fn skin_from_archive(bytes: &[u8], import_all: bool) -> Res<crate::fluxis::FluXisSkin> { import_fluxis_skin_from_archive(bytes, import_all) }
+        pub fn json_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
+    }
+
+    pub mod malody {
+        use super::*;
+        pub fn skin_from_dir(path: &str, import_all: bool) -> Res<crate::malody::MalodySkin> { import_malody_skin_from_dir(path, import_all) }
+        pub fn skin_from_archive(bytes: &[u8], import_all: bool) -> Res<crate::malody::MalodySkin> { import_malody_skin_from_archive(bytes, import_all) }
         pub fn json_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
     }
+
+    pub mod etterna {
+        use super::*;
+        pub fn skin_from_dir(path: &str, import_all: bool) -> Res<crate::etterna::EtternaNoteskin> { import_etterna_noteskin_from_dir(path, import_all) }
+        pub fn skin_from_archive(bytes: &[u8], import_all: bool) -> Res<crate::etterna::EtternaNoteskin> { import_etterna_noteskin_from_archive(bytes, import_all) }
+        pub fn metrics_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
+    }
 }
 
 #[cfg(all(target_arch = "wasm32", feature = "browser"))]
 pub mod import {
     use wasm_bindgen::prelude::*;
     use js_sys::{Array, Map};
-    use crate::{importing::browser::*, io::texture::TextureStore, sample::SampleStore, utils::wasm::*};
+    use crate::{importing::browser::*, importing::common::*, importing::archive::*, io::texture::TextureStore, sample::SampleStore, utils::wasm::*};
 
-    macro_rules! map_err { ($e:expr) => { $e.map_err(|e| JsError::new(&e.to_string())) } }
+    macro_rules! map_err { ($e:expr) => { $e.map_err(import_error_to_js) } }
 
     #[wasm_bindgen(js_name = texturesFromFiles)]
-    pub fn textures_from_files(files: Map, relative_texture_paths: Array) -> Result<TextureStore, JsError> {
+    pub fn textures_from_files(files: Map, relative_texture_paths: Array) -> Result<TextureStore, JsValue> {
         let paths = arr_to_strs(relative_texture_paths)?;
         let path_refs: Vec<&str> = paths.iter().map(String::as_str).collect();
         map_err!(import_textures_from_files(&js_to_hash(files), &path_refs))
     }
 
     #[wasm_bindgen(js_name = allTexturesFromFiles)]
-    pub fn all_textures_from_files(files: Map) -> Result<TextureStore, JsError> {
-        map_err!(import_all_textures_from_files(&js_to_hash(files)))
+    pub fn all_textures_from_files(files: Map) -> Result<TextureStore, JsValue> {
+        map_err!(import_all_textures_from_files(&js_to_hash(files), None))
     }
 
     #[wasm_bindgen(js_name = samplesFromFiles)]
-    pub fn samples_from_files(files: Map, relative_sample_paths: Array) -> Result<SampleStore, JsError> {
+    pub fn samples_from_files(files: Map, relative_sample_paths: Array) -> Result<SampleStore, JsValue> {
         let paths = arr_to_strs(relative_sample_paths)?;
         let path_refs: Vec<&str> = paths.iter().map(String::as_str).collect();
         map_err!(import_samples_from_files(&js_to_hash(files), &path_refs))
     }
 
     #[wasm_bindgen(js_name = allSamplesFromFiles)]
-    pub fn all_samples_from_files(files: Map) -> Result<SampleStore, JsError> {
+    pub fn all_samples_from_files(files: Map) -> Result<SampleStore, JsValue> {
         map_err!(import_all_samples_from_files(&js_to_hash(files)))
     }
 
     pub mod osu {
         use super::*;
         #[wasm_bindgen(js_name = osuSkinFromFiles)]
-        pub fn skin_from_files(files: Map) -> Result<crate::osu::OsuSkin, JsError> {
+        pub fn skin_from_files(files: Map) -> Result<crate::osu::OsuSkin, JsValue> {
             map_err!(import_osu_mania_skin_from_files(&js_to_hash(files)))
         }
+
+        #[wasm_bindgen(js_name = osuSkinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::osu::OsuSkin, JsValue> {
+            map_err!(import_osu_mania_skin_from_archive(bytes, import_all.unwrap_or(false)))
+        }
     }
 
     pub mod quaver {
         use super::*;
         #[wasm_bindgen(js_name = quaverSkinFromFiles)]
-        pub fn skin_from_files(files: Map) -> Result<crate::quaver::QuaSkin, JsError> {
+        pub fn skin_from_files(files: Map) -> Result<crate::quaver::QuaSkin, JsValue> {
             map_err!(import_quaver_skin_from_files(&js_to_hash(files)))
         }
+
+        #[wasm_bindgen(js_name = quaverSkinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::quaver::QuaSkin, JsValue> {
+            map_err!(import_quaver_skin_from_archive(bytes, import_all.unwrap_or(false)))
+        }
     }
 
     pub mod fluxis {
         use super::*;
         #[wasm_bindgen(js_name = fluXisSkinFromFiles)]
-        pub fn skin_from_files(files: Map) -> Result<crate::fluxis::FluXisSkin, JsError> {
+        pub fn skin_from_files(files: Map) -> Result<crate::fluxis::FluXisSkin, JsValue> {
             map_err!(import_fluxis_skin_from_files(&js_to_hash(files)))
         }
+
+        #[wasm_bindgen(js_name = fluXisSkinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::fluxis::FluXisSkin, JsValue> {
+            map_err!(import_fluxis_skin_from_archive(bytes, import_all.unwrap_or(false)))
+        }
+    }
+
+    pub mod malody {
+        use super::*;
+        #[wasm_bindgen(js_name = malodySkinFromFiles)]
+        pub fn skin_from_files(files: Map) -> Result<crate::malody::MalodySkin, JsValue> {
+            map_err!(import_malody_skin_from_files(&js_to_hash(files)))
+        }
+
+        #[wasm_bindgen(js_name = malodySkinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::malody::MalodySkin, JsValue> {
+            map_err!(import_malody_skin_from_archive(bytes, import_all.unwrap_or(false)))
+        }
+    }
+
+    pub mod etterna {
+        use super::*;
+        #[wasm_bindgen(js_name = etternaNoteskinFromFiles)]
+        pub fn skin_from_files(files: Map) -> Result<crate::etterna::EtternaNoteskin, JsValue> {
+            map_err!(import_etterna_noteskin_from_files(&js_to_hash(files)))
+        }
+
+        #[wasm_bindgen(js_name = etternaNoteskinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::etterna::EtternaNoteskin, JsValue> {
+            map_err!(import_etterna_noteskin_from_archive(bytes, import_all.unwrap_or(false)))
+        }
     }
 }
 
@@ -264,39 +517,42 @@ pub mod import {
 pub mod import {
     use wasm_bindgen::prelude::*;
     use js_sys::Array;
-    use crate::{StringPattern, importing::node::*, io::texture::TextureStore, sample::SampleStore, utils::wasm::*};
+    use crate::{StringPattern, importing::node::*, importing::archive::*, io::texture::TextureStore, sample::SampleStore, utils::wasm::*};
 
-    macro_rules! map_err { ($e:expr) => { $e.map_err(|e| JsError::new(&e.to_string())) } }
+    macro_rules! map_err { ($e:expr) => { $e.map_err(import_error_to_js) } }
 
     #[wasm_bindgen(js_name = texturesFromDir)]
-    pub fn textures_from_dir(path: &str, relative_texture_paths: Array) -> Result<TextureStore, JsError> {
+    pub fn textures_from_dir(path: &str, relative_texture_paths: Array) -> Result<TextureStore, JsValue> {
         let paths = arr_to_strs(relative_texture_paths)?;
         let path_refs: Vec<StringPattern> = paths.iter().map(StringPattern::from).collect();
         map_err!(import_textures_from_dir(path, &path_refs))
     }
 
     #[wasm_bindgen(js_name = allTexturesFromDir)]
-    pub fn all_textures_from_dir(path: &str, load_only: Option<Vec<String>>) -> Result<TextureStore, JsError> {
+    pub fn all_textures_from_dir(path: &str, load_only: Option<Vec<String>>) -> Result<TextureStore, JsValue> {
         let load_patterns: Option<Vec<StringPattern>> = load_only.map(|v| v.into_iter().map(StringPattern::from).collect());
         map_err!(import_all_textures_from_dir(path, load_patterns.as_ref().map(|v| &v[..])))
     }
 
     #[wasm_bindgen(js_name = samplesFromDir)]
-    pub fn samples_from_dir(path: &str, relative_sample_paths: Array) -> Result<SampleStore, JsError> {
+    pub fn samples_from_dir(path: &str, relative_sample_paths: Array) -> Result<SampleStore, JsValue> {
         let paths = arr_to_strs(relative_sample_paths)?;
         let path_refs: Vec<StringPattern> = paths.iter().map(StringPattern::from).collect();
         map_err!(import_samples_from_dir(path, &path_refs))
     }
 
     #[wasm_bindgen(js_name = allSamplesFromDir)]
-    pub fn all_samples_from_dir(path: &str) -> Result<SampleStore, JsError> {
+    pub fn all_samples_from_dir(path: &str) -> Result<SampleStore, JsValue> {
         map_err!(import_all_samples_from_dir(path))
     }
 
     pub mod osu {
         use super::*;
         #[wasm_bindgen(js_name = osuSkinFromDir)]
-        pub fn skin_from_dir(path: &str, import_all: Option<bool>) -> Result<crate::osu::OsuSkin, JsError> { map_err!(import_osu_mania_skin_from_dir(path, import_all.unwrap_or(false))) }
+        pub fn skin_from_dir(path: &str, import_all: Option<bool>) -> Result<crate::osu::OsuSkin, JsValue> { map_err!(import_osu_mania_skin_from_dir(path, import_all.unwrap_or(false))) }
+
+        #[wasm_bindgen(js_name = osuSkinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::osu::OsuSkin, JsValue> { map_err!(import_osu_mania_skin_from_archive(bytes, import_all.unwrap_or(false))) }
 
         #[wasm_bindgen(js_name = osuIniStrFromDir)]
         pub fn ini_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
@@ -305,7 +561,10 @@ pub mod import {
     pub mod quaver {
         use super::*;
         #[wasm_bindgen(js_name = quaverSkinFromDir)]
-        pub fn skin_from_dir(path: &str, import_all: Option<bool>) -> Result<crate::quaver::QuaSkin, JsError> { map_err!(import_quaver_skin_from_dir(path, import_all.unwrap_or(false))) }
+        pub fn skin_from_dir(path: &str, import_all: Option<bool>) -> Result<crate::quaver::QuaSkin, JsValue> { map_err!(import_quaver_skin_from_dir(path, import_all.unwrap_or(false))) }
+
+        #[wasm_bindgen(js_name = quaverSkinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::quaver::QuaSkin, JsValue> { map_err!(import_quaver_skin_from_archive(bytes, import_all.unwrap_or(false))) }
 
         #[wasm_bindgen(js_name = quaverIniStrFromDir)]
         pub fn ini_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
@@ -314,9 +573,36 @@ pub mod import {
     pub mod fluxis {
         use super::*;
         #[wasm_bindgen(js_name = fluXisSkinFromDir)]
-        pub fn skin_from_dir(path: &str, import_all: Option<bool>) -> Result<crate::fluxis::FluXisSkin, JsError> { map_err!(import_fluxis_skin_from_dir(path, import_all.unwrap_or(false))) }
+        pub fn skin_from_dir(path: &str, import_all: Option<bool>) -> Result<crate::fluxis::FluXisSkin, JsValue> { map_err!(import_fluxis_skin_from_dir(path, import_all.unwrap_or(false))) }
+
+        #[wasm_bindgen(js_name = fluXisSkinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::fluxis::FluXisSkin, JsValue> { map_err!(import_fluxis_skin_from_archive(bytes, import_all.unwrap_or(false))) }
 
         #[wasm_bindgen(js_name = fluxisJsonStrFromDir)]
         pub fn json_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
     }
+
+    pub mod malody {
+        use super::*;
+        #[wasm_bindgen(js_name = malodySkinFromDir)]
+        pub fn skin_from_dir(path: &str, import_all: Option<bool>) -> Result<crate::malody::MalodySkin, JsValue> { map_err!(import_malody_skin_from_dir(path, import_all.unwrap_or(false))) }
+
+        #[wasm_bindgen(js_name = malodySkinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::malody::MalodySkin, JsValue> { map_err!(import_malody_skin_from_archive(bytes, import_all.unwrap_or(false))) }
+
+        #[wasm_bindgen(js_name = malodyJsonStrFromDir)]
+        pub fn json_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
+    }
+
+    pub mod etterna {
+        use super::*;
+        #[wasm_bindgen(js_name = etternaNoteskinFromDir)]
+        pub fn skin_from_dir(path: &str, import_all: Option<bool>) -> Result<crate::etterna::EtternaNoteskin, JsValue> { map_err!(import_etterna_noteskin_from_dir(path, import_all.unwrap_or(false))) }
+
+        #[wasm_bindgen(js_name = etternaNoteskinFromArchive)]
+        pub fn skin_from_archive(bytes: &[u8], import_all: Option<bool>) -> Result<crate::etterna::EtternaNoteskin, JsValue> { map_err!(import_etterna_noteskin_from_archive(bytes, import_all.unwrap_or(false))) }
+
+        #[wasm_bindgen(js_name = etternaMetricsStrFromDir)]
+        pub fn metrics_str_from_dir(path: &str) -> String { read_str_from_path(path).ok().flatten().unwrap_or_default() }
+    }
 }
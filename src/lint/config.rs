@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::lint::{LintRule, Severity};
+use crate::utils::fluxis::FluXisDimensions;
+use crate::utils::osu::OsuDimensions;
+use crate::utils::quaver::QuaDimensions;
+
+/// which rules run and how bad they are, the presets only enable what matters for that game
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug)]
+pub struct LintConfig {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub rules: HashMap<LintRule, Severity>,
+    /// in pixels, for `LintRule::OversizedTexture`
+    pub max_resolution: u32,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self::generic()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl LintConfig {
+    /// no rules at all, add them with `with_rule`
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new(max_resolution: u32) -> Self {
+        Self { rules: HashMap::new(), max_resolution }
+    }
+
+    /// missing textures fall back to the default skin in osu!, so they are only warnings
+    pub fn osu() -> Self {
+        Self::new(OsuDimensions::MaxResolution.as_u32())
+            .with_rule(LintRule::MissingTexture, Severity::Warning)
+            .with_rule(LintRule::OversizedTexture, Severity::Warning)
+            .with_rule(LintRule::ColumnCount, Severity::Error)
+            .with_rule(LintRule::MissingHd, Severity::Info)
+    }
+
+    /// quaver has no texture paths or per column lists in its config, but it does use `@RxC` sheets
+    pub fn quaver() -> Self {
+        Self::new(QuaDimensions::MaxResolution.as_u32())
+            .with_rule(LintRule::OversizedTexture, Severity::Error)
+            .with_rule(LintRule::SheetSize, Severity::Error)
+    }
+
+    pub fn fluxis() -> Self {
+        Self::new(FluXisDimensions::MaxResolution.as_u32())
+            .with_rule(LintRule::MissingTexture, Severity::Warning)
+            .with_rule(LintRule::OversizedTexture, Severity::Error)
+            .with_rule(LintRule::ColumnCount, Severity::Warning)
+    }
+
+    /// a generic skin has nothing to fall back to, whatever is missing is not drawn
+    pub fn generic() -> Self {
+        Self::new(FluXisDimensions::MaxResolution.as_u32())
+            .with_rule(LintRule::MissingTexture, Severity::Error)
+            .with_rule(LintRule::OversizedTexture, Severity::Warning)
+            .with_rule(LintRule::ColumnCount, Severity::Error)
+            .with_rule(LintRule::SheetSize, Severity::Warning)
+    }
+
+    /// enables `rule`, or changes its severity if it already is
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = withRule))]
+    pub fn with_rule(mut self, rule: LintRule, severity: Severity) -> Self {
+        self.rules.insert(rule, severity);
+        self
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = withoutRule))]
+    pub fn without_rule(mut self, rule: LintRule) -> Self {
+        self.rules.remove(&rule);
+        self
+    }
+
+    /// `None` if the rule is disabled
+    pub fn severity(&self, rule: LintRule) -> Option<Severity> {
+        self.rules.get(&rule).copied()
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = isEnabled))]
+    pub fn is_enabled(&self, rule: LintRule) -> bool {
+        self.rules.contains_key(&rule)
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Info,
+    Warning,
+    /// the game will not show the element like the skin intends
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    /// a texture the config points to is not in the texture store
+    MissingTexture,
+    /// larger than `LintConfig::max_resolution` on either side
+    OversizedTexture,
+    /// a per column list that does not have one value per column
+    ColumnCount,
+    /// a texture without an `@2x` version
+    MissingHd,
+    /// an `@RxC` sheet that cannot be split into equally sized frames
+    SheetSize,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 5] = [
+        LintRule::MissingTexture,
+        LintRule::OversizedTexture,
+        LintRule::ColumnCount,
+        LintRule::MissingHd,
+        LintRule::SheetSize,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintRule::MissingTexture => "missing-texture",
+            LintRule::OversizedTexture => "oversized-texture",
+            LintRule::ColumnCount => "column-count",
+            LintRule::MissingHd => "missing-hd",
+            LintRule::SheetSize => "sheet-size",
+        }
+    }
+}
+
+/// one problem found by a lint rule
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintDiagnostic {
+    pub rule: LintRule,
+    pub severity: Severity,
+    /// where the problem is, e.g. `7K.receptor_images[2]` or `textures/mania-key1`
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub element: String,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub message: String,
+    /// what to change to make it go away
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub suggestion: String,
+}
+
+impl fmt::Display for LintDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} [{}] {}: {} ({})",
+            self.severity.as_str(),
+            self.rule.as_str(),
+            self.element,
+            self.message,
+            self.suggestion
+        )
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl LintDiagnostic {
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_wasm(&self) -> String {
+        self.to_string()
+    }
+}
@@ -0,0 +1,19 @@
+mod config;
+mod diagnostic;
+mod rules;
+mod skins;
+
+pub use config::LintConfig;
+pub use diagnostic::{LintDiagnostic, LintRule, Severity};
+
+/// checks a skin for things that import fine but look wrong or break in game
+pub trait Lint {
+    /// the rules that make sense for this game
+    fn lint_config() -> LintConfig;
+
+    fn lint_with(&self, config: &LintConfig) -> Vec<LintDiagnostic>;
+
+    fn lint(&self) -> Vec<LintDiagnostic> {
+        self.lint_with(&Self::lint_config())
+    }
+}
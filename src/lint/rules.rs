@@ -0,0 +1,163 @@
+use std::cmp::Reverse;
+use std::io::Cursor;
+use std::sync::PoisonError;
+
+use crate::io::texture::{Texture, TextureStore};
+use crate::lint::{LintConfig, LintDiagnostic, LintRule};
+use crate::traits::KeymodeInvariant;
+use crate::{BinaryState, Store, StringPattern};
+
+/// collects diagnostics for the rules that are enabled in `config`
+pub(crate) struct Linter<'a> {
+    config: &'a LintConfig,
+    diagnostics: Vec<LintDiagnostic>,
+}
+
+impl<'a> Linter<'a> {
+    pub fn new(config: &'a LintConfig) -> Self {
+        Self { config, diagnostics: Vec::new() }
+    }
+
+    /// most severe first, in the order they were found otherwise
+    pub fn finish(mut self) -> Vec<LintDiagnostic> {
+        self.diagnostics.sort_by_key(|d| Reverse(d.severity));
+        self.diagnostics
+    }
+
+    fn report(&mut self, rule: LintRule, element: String, message: String, suggestion: String) {
+        let Some(severity) = self.config.severity(rule) else { return };
+        self.diagnostics.push(LintDiagnostic { rule, severity, element, message, suggestion });
+    }
+
+    /// paths the keymode points to that are neither in the store nor animated as `path-0`
+    pub fn missing_textures<K: KeymodeInvariant>(&mut self, keymode: &K, textures: &TextureStore) {
+        if !self.config.is_enabled(LintRule::MissingTexture) {
+            return;
+        }
+
+        for (field, paths) in keymode_lists(keymode) {
+            for (i, path) in paths.iter().enumerate() {
+                if path.is_empty() || textures.contains(path) || textures.contains(&format!("{path}-0")) {
+                    continue;
+                }
+                self.report(
+                    LintRule::MissingTexture,
+                    format!("{}K.{field}[{i}]", keymode.get_keymode()),
+                    format!("'{path}' is not in the skin"),
+                    format!("add {path}.png or point {field}[{i}] at an existing texture"),
+                );
+            }
+        }
+    }
+
+    /// every per column texture list of the keymode, empty lists are left alone
+    pub fn keymode_columns<K: KeymodeInvariant>(&mut self, keymode: &K) {
+        let keys = keymode.get_keymode() as usize;
+        for (field, paths) in keymode_lists(keymode) {
+            if !paths.is_empty() {
+                self.column_count(keymode.get_keymode(), field, paths.len(), &[keys]);
+            }
+        }
+    }
+
+    /// `expected` is every length the game accepts, the first one is suggested
+    pub fn column_count(&mut self, keymode: u8, field: &str, len: usize, expected: &[usize]) {
+        if keymode == 0 || expected.contains(&len) {
+            return;
+        }
+
+        self.report(
+            LintRule::ColumnCount,
+            format!("{keymode}K.{field}"),
+            format!("{len} values for {keymode} keys"),
+            format!("give {field} exactly {} values", expected[0]),
+        );
+    }
+
+    pub fn texture_rules(&mut self, textures: &TextureStore) {
+        let mut entries = textures.iter();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        for (path, texture) in &entries {
+            let Some((width, height)) = dimensions(&texture.read().unwrap_or_else(PoisonError::into_inner)) else { continue };
+
+            self.oversized(path, width, height);
+            self.sheet_size(path, width, height);
+            if self.config.is_enabled(LintRule::MissingHd) {
+                self.missing_hd(path, textures);
+            }
+        }
+    }
+
+    fn oversized(&mut self, path: &str, width: u32, height: u32) {
+        let max = self.config.max_resolution;
+        if width <= max && height <= max {
+            return;
+        }
+
+        self.report(
+            LintRule::OversizedTexture,
+            format!("textures/{path}"),
+            format!("{width}x{height} is larger than {max}px"),
+            format!("downscale to at most {max}px on the longest side"),
+        );
+    }
+
+    fn sheet_size(&mut self, path: &str, width: u32, height: u32) {
+        let Some((rows, cols)) = StringPattern::from(path.to_string()).get_sheet_size() else { return };
+        if rows > 0 && cols > 0 && width.is_multiple_of(cols) && height.is_multiple_of(rows) {
+            return;
+        }
+
+        let suggestion = if rows > 0 && cols > 0 {
+            format!("resize to {}x{}", width.div_ceil(cols) * cols, height.div_ceil(rows) * rows)
+        } else {
+            "use at least one row and column in the @RxC suffix".to_string()
+        };
+        self.report(
+            LintRule::SheetSize,
+            format!("textures/{path}"),
+            format!("{width}x{height} does not split into {rows} rows and {cols} columns"),
+            suggestion,
+        );
+    }
+
+    /// an imported `@2x` pair keeps the SD image as its mipmap
+    fn missing_hd(&mut self, path: &str, textures: &TextureStore) {
+        if path.ends_with("@2x") || textures.has_mipmaps(path) || textures.contains(&format!("{path}@2x")) {
+            return;
+        }
+
+        self.report(
+            LintRule::MissingHd,
+            format!("textures/{path}"),
+            "no @2x version, it is upscaled on high resolutions".to_string(),
+            format!("add {path}@2x.png at twice the size"),
+        );
+    }
+}
+
+fn keymode_lists<K: KeymodeInvariant>(keymode: &K) -> [(&'static str, Vec<String>); 7] {
+    [
+        ("receptor_images", keymode.get_receptors()),
+        ("receptor_images_down", keymode.get_receptors_down()),
+        ("normal_note_images", keymode.get_normal_notes()),
+        ("long_note_head_images", keymode.get_long_note_heads()),
+        ("long_note_body_images", keymode.get_long_note_bodies()),
+        ("long_note_tail_images", keymode.get_long_note_tails()),
+        ("normal_mine_images", keymode.get_normal_mines()),
+    ]
+}
+
+/// unloaded textures only have their header read
+fn dimensions(texture: &Texture) -> Option<(u32, u32)> {
+    match &texture.data {
+        BinaryState::Loaded(image) => Some(image.dimensions()),
+        BinaryState::Unloaded(bytes) => image::ImageReader::new(Cursor::new(bytes))
+            .with_guessed_format()
+            .ok()?
+            .into_dimensions()
+            .ok(),
+        BinaryState::Empty => None,
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::lint::rules::Linter;
+use crate::lint::{Lint, LintConfig, LintDiagnostic};
+use crate::{FluXisSkin, GenericManiaSkin, OsuSkin, QuaSkin};
+
+impl Lint for OsuSkin {
+    fn lint_config() -> LintConfig {
+        LintConfig::osu()
+    }
+
+    fn lint_with(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut linter = Linter::new(config);
+
+        for keymode in &self.skin_ini.keymodes {
+            let keys = keymode.keymode as usize;
+            if keys == 0 {
+                continue;
+            }
+
+            linter.column_count(keymode.keymode, "column_width", keymode.column_width.len(), &[keys]);
+            linter.column_count(keymode.keymode, "column_line_width", keymode.column_line_width.len(), &[keys + 1]);
+            linter.column_count(keymode.keymode, "column_spacing", keymode.column_spacing.len(), &[keys - 1, keys]);
+            linter.keymode_columns(keymode);
+            linter.missing_textures(keymode, &self.textures);
+        }
+        linter.texture_rules(&self.textures);
+
+        linter.finish()
+    }
+}
+
+impl Lint for QuaSkin {
+    fn lint_config() -> LintConfig {
+        LintConfig::quaver()
+    }
+
+    fn lint_with(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut linter = Linter::new(config);
+        linter.texture_rules(&self.textures);
+        linter.finish()
+    }
+}
+
+impl Lint for FluXisSkin {
+    fn lint_config() -> LintConfig {
+        LintConfig::fluxis()
+    }
+
+    fn lint_with(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut linter = Linter::new(config);
+
+        for keymode in &self.skin_json.keymodes {
+            if !keymode.colors.is_empty() {
+                linter.column_count(keymode.keymode, "colors", keymode.colors.len(), &[keymode.keymode as usize]);
+            }
+            linter.keymode_columns(keymode);
+            linter.missing_textures(keymode, &self.textures);
+        }
+        linter.texture_rules(&self.textures);
+
+        linter.finish()
+    }
+}
+
+impl Lint for GenericManiaSkin {
+    fn lint_config() -> LintConfig {
+        LintConfig::generic()
+    }
+
+    fn lint_with(&self, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut linter = Linter::new(config);
+
+        for keymode in &self.keymodes {
+            let layout = &keymode.layout;
+            linter.column_count(keymode.keymode, "column_widths", layout.column_widths.len(), &[keymode.keymode as usize]);
+            linter.keymode_columns(keymode);
+            linter.missing_textures(keymode, &self.textures);
+        }
+        linter.texture_rules(&self.textures);
+
+        linter.finish()
+    }
+}
+
+macro_rules! impl_lint_wasm {
+    ($($skin:ty),*) => {
+        $(
+            #[cfg(target_arch = "wasm32")]
+            #[wasm_bindgen]
+            impl $skin {
+                /// uses the preset for the game when no config is given
+                #[wasm_bindgen(js_name = lint)]
+                pub fn lint_wasm(&self, config: Option<LintConfig>) -> Vec<LintDiagnostic> {
+                    match config {
+                        Some(config) => self.lint_with(&config),
+                        None => self.lint(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_lint_wasm!(OsuSkin, QuaSkin, FluXisSkin, GenericManiaSkin);
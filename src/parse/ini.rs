@@ -1,30 +1,447 @@
-pub fn from_ini<F>(string: &str, mut lambda: F) -> Result<(), Box<dyn std::error::Error>>
+use std::fmt;
+
+use std::collections::HashSet;
+
+use crate::error::{ParseDiagnostics, ParseError};
+
+/// errors coming out of `lambda` get the section and line of the offending key filled in
+pub fn from_ini<F>(string: &str, mut lambda: F) -> Result<(), ParseError>
+where
+    F: FnMut(&str, &str) -> Result<(), ParseError>,
+{
+    let document = IniDocument::parse(string);
+
+    for section in &document.sections {
+        let content = section.content();
+        if !content.is_empty() && !section.name.is_empty() {
+            lambda(&section.name, &content).map_err(|e| section.locate(e))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// `from_ini` for parsers that report what they skip to `diag`, warnings get located the same way errors do.
+/// keys that show up twice in a section and lines that are not a key and value are reported here
+pub fn from_ini_with<F>(string: &str, diag: &mut ParseDiagnostics, mut lambda: F) -> Result<(), ParseError>
 where
-    F: FnMut(&str, &str) -> Result<(), Box<dyn std::error::Error>>,
+    F: FnMut(&str, &str, &mut ParseDiagnostics) -> Result<(), ParseError>,
 {
-    let mut current_content = String::with_capacity(string.len());
-    let mut current_section = "";
-    
-    for line in string.lines().map(str::trim) {
-        if line.starts_with("//") || line.starts_with(";") { continue };
+    let document = IniDocument::parse(string);
+
+    for section in &document.sections {
+        let content = section.content();
+        if content.is_empty() || section.name.is_empty() {
+            continue;
+        }
+
+        let start = diag.warnings.len();
+        section.check(diag).map_err(|e| section.locate(e))?;
+        lambda(&section.name, &content, diag).map_err(|e| section.locate(e))?;
+        diag.map_since(start, |w| section.locate(w));
+    }
+
+    Ok(())
+}
+
+fn is_comment(line: &str) -> bool {
+    line.starts_with("//") || line.starts_with(';')
+}
+
+/// a single `key: value` / `key = value` line, split so the value can be swapped without touching
+/// the indentation, separator or line ending around it
+#[derive(Clone, Debug, PartialEq)]
+pub struct IniEntry {
+    pub key: String,
+    pub value: String,
+    prefix: String,
+    suffix: String,
+}
+
+impl IniEntry {
+    fn parse(line: &str) -> Option<Self> {
+        let separator = match (line.find(':'), line.find('=')) {
+            (Some(colon), Some(eq)) => colon.min(eq),
+            (Some(pos), None) | (None, Some(pos)) => pos,
+            (None, None) => return None,
+        };
+
+        let key = line[..separator].trim();
+        if key.is_empty() {
+            return None;
+        }
+
+        let after = &line[separator + 1..];
+        let value_start = separator + 1 + (after.len() - after.trim_start().len());
+        let value_end = line.trim_end().len().max(value_start);
+
+        Some(Self {
+            key: key.to_string(),
+            value: line[value_start..value_end].to_string(),
+            prefix: line[..value_start].to_string(),
+            suffix: line[value_end..].to_string(),
+        })
+    }
+
+    /// a new line using the indentation and separator of `template`
+    fn formatted_like(template: &IniEntry, key: &str, value: &str, crlf: bool) -> Self {
+        let unindented = template.prefix.trim_start();
+        let indent = &template.prefix[..template.prefix.len() - unindented.len()];
+        let separator = unindented.strip_prefix(template.key.as_str()).unwrap_or(": ");
+
+        Self {
+            key: key.to_string(),
+            value: value.to_string(),
+            prefix: format!("{indent}{key}{separator}"),
+            suffix: if crlf { "\r".to_string() } else { String::new() },
+        }
+    }
+}
+
+impl fmt::Display for IniEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.prefix, self.value, self.suffix)
+    }
+}
 
-        if line.starts_with('[') && line.ends_with(']') {
-            if !current_content.is_empty() && !current_section.is_empty() {
-                lambda(current_section, &current_content)?;
-                current_content.clear();
+#[derive(Clone, Debug, PartialEq)]
+pub enum IniLine {
+    Entry(IniEntry),
+    /// comments, blank lines and anything else that is written back untouched
+    Raw(String),
+}
+
+impl fmt::Display for IniLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IniLine::Entry(entry) => entry.fmt(f),
+            IniLine::Raw(line) => f.write_str(line),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IniSection {
+    pub name: String,
+    /// 1 based line of the header, 0 for sections that were added after parsing
+    pub line: u32,
+    header: String,
+    pub lines: Vec<IniLine>,
+}
+
+impl IniSection {
+    pub fn entries(&self) -> impl Iterator<Item = &IniEntry> {
+        self.lines.iter().filter_map(|line| match line {
+            IniLine::Entry(entry) => Some(entry),
+            IniLine::Raw(_) => None,
+        })
+    }
+
+    /// the last value wins, same as the typed parsers
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries().filter(|e| e.key == key).last().map(|e| e.value.as_str())
+    }
+
+    /// the `Keys` of an osu `[Mania]` section, `None` for everything else
+    pub fn keys(&self) -> Option<&str> {
+        self.get("Keys").map(str::trim)
+    }
+
+    /// the section without comments and blank lines, what `from_ini` hands to the typed parsers
+    pub fn content(&self) -> String {
+        let mut content = String::new();
+
+        for line in &self.lines {
+            let line = match line {
+                IniLine::Entry(entry) => entry.to_string(),
+                IniLine::Raw(raw) => raw.clone(),
+            };
+            let line = line.trim();
+
+            if line.is_empty() || is_comment(line) {
+                continue;
             }
-            current_section = &line[1..line.len()-1];
-        } else {
-            if !current_content.is_empty() {
-                current_content.push('\n');
+            if !content.is_empty() {
+                content.push('\n');
             }
-            current_content.push_str(line);
+            content.push_str(line);
         }
+
+        content
     }
-    
-    if !current_content.is_empty() && !current_section.is_empty() {
-        lambda(current_section, &current_content)?;
+
+    /// 1 based line of the last `key` entry, preferring one that holds `value`
+    pub fn line_of(&self, key: &str, value: Option<&str>) -> Option<u32> {
+        if self.line == 0 {
+            return None;
+        }
+
+        let matches = |e: &IniEntry| e.key == key && value.is_none_or(|v| e.value.contains(v));
+        let index = self.lines.iter()
+            .rposition(|line| matches!(line, IniLine::Entry(e) if matches(e)))
+            .or_else(|| self.lines.iter().rposition(|line| matches!(line, IniLine::Entry(e) if e.key == key)))?;
+
+        Some(self.line + 1 + index as u32)
     }
-    
-    Ok(())
-}
\ No newline at end of file
+
+    fn locate(&self, error: ParseError) -> ParseError {
+        let line = error.key.as_deref().and_then(|key| self.line_of(key, error.value.as_deref()));
+        let error = error.in_section(&self.name);
+        match line {
+            Some(line) => error.at_line(line),
+            None => error,
+        }
+    }
+
+    /// duplicate keys and lines that are neither an entry nor a comment
+    fn check(&self, diag: &mut ParseDiagnostics) -> Result<(), ParseError> {
+        let mut seen = HashSet::new();
+
+        for (index, line) in self.lines.iter().enumerate() {
+            let issue = match line {
+                IniLine::Entry(entry) if !seen.insert(entry.key.as_str()) => ParseError::duplicate_key(&entry.key, &entry.value),
+                IniLine::Raw(raw) if !raw.trim().is_empty() && !is_comment(raw.trim()) => ParseError {
+                    value: Some(raw.trim().to_string()),
+                    ..ParseError::new("expected a key and a value")
+                },
+                _ => continue,
+            };
+
+            let issue = match self.line {
+                0 => issue,
+                line => issue.at_line(line + 1 + index as u32),
+            };
+            diag.report(issue)?;
+        }
+
+        Ok(())
+    }
+
+    fn set(&mut self, template: &IniEntry, crlf: bool) {
+        if let Some(entry) = self.lines.iter_mut().rev().find_map(|line| match line {
+            IniLine::Entry(entry) if entry.key == template.key => Some(entry),
+            _ => None,
+        }) {
+            entry.value = template.value.clone();
+            return;
+        }
+
+        // new keys go right after the last entry so trailing comments and blank lines stay at the end
+        let position = self.lines.iter()
+            .rposition(|line| matches!(line, IniLine::Entry(_)))
+            .map_or(0, |i| i + 1);
+        let format = self.entries().last().unwrap_or(template);
+        let entry = IniEntry::formatted_like(format, &template.key, &template.value, crlf);
+
+        self.lines.insert(position, IniLine::Entry(entry));
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.lines.retain(|line| !matches!(line, IniLine::Entry(entry) if entry.key == key));
+    }
+}
+
+/// an ini file as written, comments, blank lines, key order and keys nobody knows about included.
+/// `to_string` reproduces the parsed text byte for byte
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IniDocument {
+    pub preamble: Vec<IniLine>,
+    pub sections: Vec<IniSection>,
+    crlf: bool,
+}
+
+impl IniDocument {
+    pub fn parse(string: &str) -> Self {
+        let mut document = Self {
+            crlf: string.contains("\r\n"),
+            ..Default::default()
+        };
+
+        for (index, raw) in string.split('\n').enumerate() {
+            let line = raw.trim().trim_start_matches('\u{feff}');
+
+            if line.starts_with('[') && line.ends_with(']') && line.len() >= 2 {
+                document.sections.push(IniSection {
+                    name: line[1..line.len() - 1].to_string(),
+                    line: index as u32 + 1,
+                    header: raw.to_string(),
+                    lines: Vec::new(),
+                });
+                continue;
+            }
+
+            let parsed = if line.is_empty() || is_comment(line) {
+                IniLine::Raw(raw.to_string())
+            } else {
+                IniEntry::parse(raw).map_or_else(|| IniLine::Raw(raw.to_string()), IniLine::Entry)
+            };
+
+            match document.sections.last_mut() {
+                Some(section) => section.lines.push(parsed),
+                None => document.preamble.push(parsed),
+            }
+        }
+
+        document
+    }
+
+    /// `occurrence` counts sections with the same name, e.g. the second `[Mania]` is occurrence 1
+    pub fn section(&self, name: &str, occurrence: usize) -> Option<&IniSection> {
+        self.position(name, occurrence).map(|i| &self.sections[i])
+    }
+
+    fn position(&self, name: &str, occurrence: usize) -> Option<usize> {
+        self.sections.iter()
+            .enumerate()
+            .filter(|(_, s)| s.name.eq_ignore_ascii_case(name))
+            .nth(occurrence)
+            .map(|(i, _)| i)
+    }
+
+    /// like `position` but also tells sections with a different `Keys` value apart,
+    /// osu has one `[Mania]` per keymode and their order means nothing
+    fn matching(&self, name: &str, keys: Option<&str>, occurrence: usize) -> Option<usize> {
+        self.sections.iter()
+            .enumerate()
+            .filter(|(_, s)| s.name.eq_ignore_ascii_case(name) && s.keys() == keys)
+            .nth(occurrence)
+            .map(|(i, _)| i)
+    }
+
+    fn indexed(&self) -> impl Iterator<Item = (usize, &IniSection)> {
+        self.sections.iter().enumerate().map(|(i, section)| {
+            let occurrence = self.sections[..i].iter()
+                .filter(|s| s.name.eq_ignore_ascii_case(&section.name) && s.keys() == section.keys())
+                .count();
+            (occurrence, section)
+        })
+    }
+
+    fn append_section(&mut self, name: &str) -> &mut IniSection {
+        let crlf = self.crlf;
+        let cr = if crlf { "\r" } else { "" };
+        let last_lines = match self.sections.last_mut() {
+            Some(section) => &mut section.lines,
+            None => &mut self.preamble,
+        };
+
+        // `split('\n')` leaves an empty last line when the file ends with a newline, keep it last
+        let final_newline = matches!(last_lines.last(), Some(IniLine::Raw(line)) if line.is_empty());
+        if final_newline {
+            last_lines.pop();
+        }
+        let ends_blank = matches!(last_lines.last(), Some(IniLine::Raw(line)) if line.trim().is_empty());
+        if !last_lines.is_empty() && !ends_blank {
+            last_lines.push(IniLine::Raw(cr.to_string()));
+        }
+
+        let mut lines = Vec::new();
+        if final_newline {
+            lines.push(IniLine::Raw(String::new()));
+        }
+
+        self.sections.push(IniSection {
+            name: name.to_string(),
+            line: 0,
+            header: format!("[{name}]{cr}"),
+            lines,
+        });
+        self.sections.last_mut().unwrap()
+    }
+
+    /// applies whatever changed between `before` and `after` to this document and leaves everything
+    /// else alone, sections are matched by name, `Keys` and occurrence
+    pub fn apply_changes(&mut self, before: &IniDocument, after: &IniDocument) {
+        let crlf = self.crlf;
+
+        for (occurrence, after_section) in after.indexed() {
+            let keys = after_section.keys();
+            let before_section = before.matching(&after_section.name, keys, occurrence).map(|i| &before.sections[i]);
+
+            let changed: Vec<&IniEntry> = after_section.entries()
+                .filter(|e| before_section.and_then(|s| s.get(&e.key)) != Some(e.value.as_str()))
+                .collect();
+            let removed: Vec<&str> = before_section
+                .map(|s| s.entries().filter(|e| after_section.get(&e.key).is_none()).map(|e| e.key.as_str()).collect())
+                .unwrap_or_default();
+
+            if changed.is_empty() && removed.is_empty() {
+                continue;
+            }
+
+            let section = match self.matching(&after_section.name, keys, occurrence) {
+                Some(i) => &mut self.sections[i],
+                None => self.append_section(&after_section.name),
+            };
+
+            for entry in changed {
+                section.set(entry, crlf);
+            }
+            for key in removed {
+                section.remove(key);
+            }
+        }
+
+        let mut stale: Vec<usize> = before.indexed()
+            .filter(|(occurrence, s)| after.matching(&s.name, s.keys(), *occurrence).is_none())
+            .filter_map(|(occurrence, s)| self.matching(&s.name, s.keys(), occurrence))
+            .collect();
+        stale.sort_unstable();
+
+        for i in stale.into_iter().rev() {
+            self.sections.remove(i);
+        }
+    }
+}
+
+impl fmt::Display for IniDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        let mut write_line = |f: &mut fmt::Formatter<'_>, line: &dyn fmt::Display| -> fmt::Result {
+            if !first {
+                f.write_str("\n")?;
+            }
+            first = false;
+            line.fmt(f)
+        };
+
+        for line in &self.preamble {
+            write_line(f, line)?;
+        }
+        for section in &self.sections {
+            write_line(f, &section.header)?;
+            for line in &section.lines {
+                write_line(f, line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// the text a typed config was parsed from together with what the typed config wrote right after parsing,
+/// lets serializing only touch the lines that were actually changed
+#[derive(Clone, Debug, Default)]
+pub struct IniSource {
+    document: IniDocument,
+    baseline: IniDocument,
+}
+
+impl IniSource {
+    pub fn new(original: &str, baseline: &str) -> Self {
+        Self {
+            document: IniDocument::parse(original),
+            baseline: IniDocument::parse(baseline),
+        }
+    }
+
+    pub fn document(&self) -> &IniDocument {
+        &self.document
+    }
+
+    pub fn render(&self, generated: &str) -> String {
+        let mut document = self.document.clone();
+        document.apply_changes(&self.baseline, &IniDocument::parse(generated));
+        document.to_string()
+    }
+}
@@ -5,6 +5,7 @@ use include_dir::{include_dir, Dir};
 use crate::utils::io::get_filename;
 
 static CURSORS: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/resources/cursors");
+static NOTESKINS: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/resources/noteskins");
 
 pub struct Resources;
 
@@ -19,4 +20,8 @@ impl Resources {
             .map(|f| get_filename(f.path().to_str().unwrap_or_default()))
             .collect()
     }
+
+    pub fn noteskin(name: &str) -> Option<std::borrow::Cow<'static, [u8]>> {
+        NOTESKINS.get_file(name).map(|f| std::borrow::Cow::Borrowed(f.contents()))
+    }
 }
\ No newline at end of file
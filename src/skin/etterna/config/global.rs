@@ -0,0 +1,61 @@
+use merge::Merge;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::utils::serde::{add_key_value, parse_key_value_eq};
+use crate::error::ParseError;
+use rgskin_derive::merge_for_all;
+
+#[merge_for_all(strategy = crate::utils::merge::any::overwrite)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = EtternaGlobal))]
+#[derive(Clone, Debug, Merge)]
+pub struct Global {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub fallback_noteskin: String,
+}
+
+impl Default for Global {
+    fn default() -> Self {
+        Self {
+            fallback_noteskin: "common".to_string(),
+        }
+    }
+}
+
+impl Global {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        let mut global = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") || line.starts_with('[') {
+                continue;
+            }
+            let (key_str, value_str) = parse_key_value_eq(line).unwrap_or_default();
+            if key_str == "FallbackNoteSkin" {
+                global.fallback_noteskin = value_str.to_string();
+            }
+        }
+
+        Ok(global)
+    }
+
+    pub fn to_string(&self) -> String {
+        let mut result = String::new();
+        add_key_value(&mut result, "FallbackNoteSkin", "=", &self.fallback_noteskin, "\n");
+        result
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_class = EtternaGlobal))]
+impl Global {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
+    pub fn wasm_from_str(content: &str) -> Result<Global, ParseError> {
+        Self::from_str(content)
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
+    pub fn wasm_to_string(&self) -> String {
+        self.to_string()
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::StringPattern;
+
+/// a column of the "dance" game type, noteskins usually only ship the `Down` sprites
+/// and rotate them for the other buttons
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Button {
+    Left,
+    Down,
+    Up,
+    Right,
+}
+
+impl Button {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Button::Left => "Left",
+            Button::Down => "Down",
+            Button::Up => "Up",
+            Button::Right => "Right",
+        }
+    }
+
+    /// clockwise quarter turns needed to turn a `Down` sprite into this button's sprite
+    pub fn quarter_turns_cw(&self) -> u8 {
+        match self {
+            Button::Down => 0,
+            Button::Left => 1,
+            Button::Up => 2,
+            Button::Right => 3,
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = EtternaKeymode))]
+#[derive(Clone, Debug)]
+pub struct Keymode {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub keymode: u8,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub buttons: Vec<Button>,
+}
+
+impl Keymode {
+    pub fn dance_single() -> Self {
+        Self {
+            keymode: 4,
+            buttons: vec![Button::Left, Button::Down, Button::Up, Button::Right],
+        }
+    }
+
+    pub fn dance_double() -> Self {
+        let mut buttons = Self::dance_single().buttons;
+        buttons.extend_from_within(..);
+
+        Self { keymode: 8, buttons }
+    }
+
+    /// replaces the {button} placeholder of an asset pattern
+    pub fn get_button_asset(pattern: &StringPattern, button: Button) -> StringPattern {
+        StringPattern::from(pattern.expand(&[("button", button.as_str())]))
+    }
+}
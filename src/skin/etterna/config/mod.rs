@@ -0,0 +1,7 @@
+pub mod global;
+pub mod note_display;
+pub mod keymode;
+
+pub use global::Global;
+pub use note_display::NoteDisplay;
+pub use keymode::{Button, Keymode};
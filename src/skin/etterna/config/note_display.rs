@@ -0,0 +1,92 @@
+use merge::Merge;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::utils::serde::{add_key_value, parse_bool, parse_key_value_eq, serialize_bool};
+use crate::error::ParseError;
+use rgskin_derive::merge_for_all;
+
+#[merge_for_all(strategy = crate::utils::merge::any::overwrite)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = EtternaNoteDisplay))]
+#[derive(Clone, Debug, Merge)]
+pub struct NoteDisplay {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub tap_note_animation_length: f32,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub tap_note_animation_is_vivid: bool,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub start_drawing_hold_body_offset_from_head: i32,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub stop_drawing_hold_body_offset_from_tail: i32,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub hold_let_go_gray_percent: f32,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub flip_head_and_tail_when_reverse: bool,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub draw_hold_head_for_taps_on_same_row: bool,
+}
+
+impl Default for NoteDisplay {
+    fn default() -> Self {
+        Self {
+            tap_note_animation_length: 1.0,
+            tap_note_animation_is_vivid: false,
+            start_drawing_hold_body_offset_from_head: 0,
+            stop_drawing_hold_body_offset_from_tail: 0,
+            hold_let_go_gray_percent: 0.25,
+            flip_head_and_tail_when_reverse: false,
+            draw_hold_head_for_taps_on_same_row: true,
+        }
+    }
+}
+
+impl NoteDisplay {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        let mut note_display = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") || line.starts_with('[') {
+                continue;
+            }
+            let (key_str, value_str) = parse_key_value_eq(line).unwrap_or_default();
+            match key_str {
+                "TapNoteAnimationLength" => note_display.tap_note_animation_length = value_str.parse().unwrap_or(1.0),
+                "TapNoteAnimationIsVivid" => note_display.tap_note_animation_is_vivid = parse_bool(value_str),
+                "StartDrawingHoldBodyOffsetFromHead" => note_display.start_drawing_hold_body_offset_from_head = value_str.parse().unwrap_or(0),
+                "StopDrawingHoldBodyOffsetFromTail" => note_display.stop_drawing_hold_body_offset_from_tail = value_str.parse().unwrap_or(0),
+                "HoldLetGoGrayPercent" => note_display.hold_let_go_gray_percent = value_str.parse().unwrap_or(0.25),
+                "FlipHeadAndTailWhenReverse" => note_display.flip_head_and_tail_when_reverse = parse_bool(value_str),
+                "DrawHoldHeadForTapsOnSameRow" => note_display.draw_hold_head_for_taps_on_same_row = parse_bool(value_str),
+                _ => {}
+            }
+        }
+
+        Ok(note_display)
+    }
+
+    pub fn to_string(&self) -> String {
+        let mut result = String::new();
+        add_key_value(&mut result, "TapNoteAnimationLength", "=", &self.tap_note_animation_length.to_string(), "\n");
+        add_key_value(&mut result, "TapNoteAnimationIsVivid", "=", &serialize_bool(self.tap_note_animation_is_vivid).to_string(), "\n");
+        add_key_value(&mut result, "StartDrawingHoldBodyOffsetFromHead", "=", &self.start_drawing_hold_body_offset_from_head.to_string(), "\n");
+        add_key_value(&mut result, "StopDrawingHoldBodyOffsetFromTail", "=", &self.stop_drawing_hold_body_offset_from_tail.to_string(), "\n");
+        add_key_value(&mut result, "HoldLetGoGrayPercent", "=", &self.hold_let_go_gray_percent.to_string(), "\n");
+        add_key_value(&mut result, "FlipHeadAndTailWhenReverse", "=", &serialize_bool(self.flip_head_and_tail_when_reverse).to_string(), "\n");
+        add_key_value(&mut result, "DrawHoldHeadForTapsOnSameRow", "=", &serialize_bool(self.draw_hold_head_for_taps_on_same_row).to_string(), "\n");
+        result
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_class = EtternaNoteDisplay))]
+impl NoteDisplay {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
+    pub fn wasm_from_str(content: &str) -> Result<NoteDisplay, ParseError> {
+        Self::from_str(content)
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
+    pub fn wasm_to_string(&self) -> String {
+        self.to_string()
+    }
+}
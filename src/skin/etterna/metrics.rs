@@ -0,0 +1,112 @@
+use indexmap::IndexMap;
+use merge::Merge;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use std::str::FromStr;
+use crate::ini::from_ini;
+use crate::etterna::config::{Global, NoteDisplay};
+use crate::etterna::static_assets;
+use crate::traits::SkinConfig;
+use crate::{ConstTypeEnum, StringPattern};
+use crate::error::ParseError;
+
+/// the `metrics.ini` of a noteskin
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Default, Merge)]
+pub struct EtternaMetrics {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub global: Global,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub note_display: NoteDisplay,
+    /// sections rgskin doesn't read (`[ReceptorArrow]`, `[TapNote]`, ...), kept as is so they survive a round trip
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    #[merge(strategy = crate::utils::merge::any::overwrite)]
+    pub other_sections: IndexMap<String, String>,
+}
+
+impl ToString for EtternaMetrics {
+    fn to_string(&self) -> String {
+        let mut result = String::new();
+
+        result.push_str("[Global]\n");
+        result.push_str(&self.global.to_string());
+        result.push('\n');
+
+        result.push_str("[NoteDisplay]\n");
+        result.push_str(&self.note_display.to_string());
+        result.push('\n');
+
+        for (section, content) in &self.other_sections {
+            result.push_str(&format!("[{section}]\n"));
+            result.push_str(content.trim_end());
+            result.push_str("\n\n");
+        }
+
+        result
+    }
+}
+
+impl FromStr for EtternaMetrics {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut global = Global::default();
+        let mut note_display = NoteDisplay::default();
+        let mut other_sections = IndexMap::new();
+
+        from_ini(s, |section, content| {
+            match section {
+                "Global" => global = Global::from_str(content)?,
+                "NoteDisplay" => note_display = NoteDisplay::from_str(content)?,
+                _ => {
+                    other_sections.insert(section.to_string(), content.to_string());
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(EtternaMetrics { global, note_display, other_sections })
+    }
+}
+
+impl SkinConfig for EtternaMetrics {
+    fn get_required_texture_paths(&self) -> Vec<StringPattern> {
+        let mut result: Vec<StringPattern> = Vec::new();
+
+        result.extend(static_assets::Receptor::iter_mapped(|t| t));
+        result.extend(static_assets::Tap::iter_mapped(|t| t));
+        result.extend(static_assets::Hold::iter_mapped(|t| t));
+
+        let hidden: Vec<StringPattern> = result.iter()
+            .map(|t| StringPattern::from(format!("_{t}")))
+            .collect();
+        result.extend(hidden);
+
+        let sheets: Vec<StringPattern> = result.iter()
+            .map(|t| StringPattern::from(format!("{t} {{frames}}")))
+            .collect();
+        result.extend(sheets);
+
+        result
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl EtternaMetrics {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        EtternaMetrics::default()
+    }
+
+    #[wasm_bindgen(js_name = fromStr)]
+    pub fn from_str_wasm(ini_str: &str) -> Result<Self, ParseError> {
+        Self::from_str(ini_str)
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_wasm(&self) -> String {
+        self.to_string()
+    }
+}
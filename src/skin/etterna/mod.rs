@@ -0,0 +1,8 @@
+pub mod config;
+pub mod metrics;
+pub mod skin;
+pub mod static_assets;
+
+pub use config::{Button, Keymode};
+pub use metrics::EtternaMetrics;
+pub use skin::EtternaNoteskin;
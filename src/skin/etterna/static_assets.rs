@@ -0,0 +1,66 @@
+use crate::{common::{alignment::Anchor, skin::{AnimationSpriteType, AssetAttribute}}, def_const_type_enum};
+
+// every sprite can also come as a "{cols}x{rows}" sheet, e.g. "Down Tap Note 4x8"
+
+def_const_type_enum!(pub Receptor => StringPattern {
+    RECEPTOR => "{button} Receptor" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+});
+
+def_const_type_enum!(pub Tap => StringPattern {
+    NOTE => "{button} Tap Note" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    MINE => "{button} Tap Mine" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    EXPLOSION_DIM => "{button} Tap Explosion Dim" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    EXPLOSION_BRIGHT => "{button} Tap Explosion Bright" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+});
+
+def_const_type_enum!(pub Hold => StringPattern {
+    HEAD_ACTIVE => "{button} Hold Head Active" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    HEAD_INACTIVE => "{button} Hold Head Inactive" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    BODY_ACTIVE => "{button} Hold Body Active" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::TopCentre)
+    ],
+    BODY_INACTIVE => "{button} Hold Body Inactive" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::TopCentre)
+    ],
+    BOTTOM_CAP_ACTIVE => "{button} Hold BottomCap Active" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::TopCentre)
+    ],
+    BOTTOM_CAP_INACTIVE => "{button} Hold BottomCap Inactive" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::TopCentre)
+    ],
+    EXPLOSION => "{button} Hold Explosion" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+});
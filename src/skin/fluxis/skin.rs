@@ -8,6 +8,9 @@ use crate::common::vector::Vector2;
 use crate::converting::fluxis::{from_generic_mania, to_generic_mania};
 use crate::fluxis::skin_json::Keymode;
 use crate::sample::SampleStore;
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::ConversionReport;
 use crate::skin::generic::GenericManiaSkin;
 use crate::skin::fluxis::{FluXisLayout, SkinJson};
 use crate::io::texture::TextureStore;
@@ -46,12 +49,16 @@ impl<'a> ManiaSkin<'a> for FluXisSkin {
     type ToParams = Option<&'a FluXisLayout>;
     type FromReturn = (FluXisSkin, FluXisLayout);
 
-    fn to_generic_mania(&self, params: Self::ToParams) -> Result<GenericManiaSkin, Box<dyn std::error::Error>> {
-        to_generic_mania(self, params)
+    fn to_generic_mania_with(&self, params: Self::ToParams, options: &ConversionOptions) -> Result<(GenericManiaSkin, ConversionReport), ConvertError> {
+        let mut report = ConversionReport::new();
+        let skin = to_generic_mania(self, params, options, &mut report)?;
+        Ok((skin, report))
     }
 
-    fn from_generic_mania(skin: &GenericManiaSkin) -> Result<Self::FromReturn, Box<dyn std::error::Error>> {
-        from_generic_mania(skin)
+    fn from_generic_mania_with(skin: &GenericManiaSkin, options: &ConversionOptions) -> Result<(Self::FromReturn, ConversionReport), ConvertError> {
+        let mut report = ConversionReport::new();
+        let skin = from_generic_mania(skin, options, &mut report)?;
+        Ok((skin, report))
     }
 
     fn get_keymode(&self, keymode: u8) -> Option<&Keymode> {
@@ -74,24 +81,40 @@ impl<'a> ManiaSkin<'a> for FluXisSkin {
 #[wasm_bindgen]
 impl FluXisSkin {
     #[wasm_bindgen(js_name = toGenericMania)]
-    pub fn to_generic_mania_wasm(&self) -> Result<GenericManiaSkin, JsValue> {
-        self.to_generic_mania(None)
+    pub fn to_generic_mania_wasm(&self, options: Option<ConversionOptions>) -> Result<GenericManiaSkin, JsValue> {
+        self.to_generic_mania_with_options(None, &options.unwrap_or_default())
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
     #[wasm_bindgen(js_name = toGenericManiaWithLayout)]
-    pub fn to_generic_mania_with_layout_wasm(&self, layout: &FluXisLayout) -> Result<GenericManiaSkin, JsValue> {
-        self.to_generic_mania(Some(layout))
+    pub fn to_generic_mania_with_layout_wasm(&self, layout: &FluXisLayout, options: Option<ConversionOptions>) -> Result<GenericManiaSkin, JsValue> {
+        self.to_generic_mania_with_options(Some(layout), &options.unwrap_or_default())
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
     #[wasm_bindgen(js_name = fromGenericMania)]
-    pub fn from_generic_mania_wasm(skin: &GenericManiaSkin) -> Result<FluXisSkinWithLayout, JsValue> {
-        Self::from_generic_mania(skin)
+    pub fn from_generic_mania_wasm(skin: &GenericManiaSkin, options: Option<ConversionOptions>) -> Result<FluXisSkinWithLayout, JsValue> {
+        Self::from_generic_mania_with_options(skin, &options.unwrap_or_default())
             .map(|(skin, layout)| FluXisSkinWithLayout { skin, layout })
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// `[skin, report]`
+    #[wasm_bindgen(js_name = toGenericManiaWithReport)]
+    pub fn to_generic_mania_with_report_wasm(&self, layout: Option<FluXisLayout>, options: Option<ConversionOptions>) -> Result<js_sys::Array, JsValue> {
+        self.to_generic_mania_with(layout.as_ref(), &options.unwrap_or_default())
+            .map(|(skin, report)| crate::utils::wasm::with_report(skin, report))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// `[skinWithLayout, report]`
+    #[wasm_bindgen(js_name = fromGenericManiaWithReport)]
+    pub fn from_generic_mania_with_report_wasm(skin: &GenericManiaSkin, options: Option<ConversionOptions>) -> Result<js_sys::Array, JsValue> {
+        Self::from_generic_mania_with(skin, &options.unwrap_or_default())
+            .map(|((skin, layout), report)| crate::utils::wasm::with_report(FluXisSkinWithLayout { skin, layout }, report))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
     #[wasm_bindgen(js_name = getKeymode)]
     pub fn get_keymode_wasm(&self, keymode: u8) -> Option<Keymode> {
         self.get_keymode(keymode).cloned()
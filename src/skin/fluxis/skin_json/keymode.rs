@@ -19,6 +19,7 @@ define_keymode!(
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = FluXisKeymode))]
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Keymode {
     #[serde(skip)]
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
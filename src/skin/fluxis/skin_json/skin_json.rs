@@ -17,6 +17,7 @@ use serde::{
     Deserialize, Serialize,
 };
 use crate::fluxis::skin_json::overrides::extract_keymode_column;
+use crate::error::{ParseDiagnostics, ParseError, ParseMode, ParseWarning};
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Clone, Debug, Default, Deserialize, Merge)]
@@ -53,8 +54,15 @@ impl SkinJson {
     }
 
     #[wasm_bindgen(js_name = fromStr)]
-    pub fn from_str_wasm(json_str: &str) -> Result<Self, JsError> {
-        Self::from_str(json_str).map_err(|e| JsError::new(&e.to_string()))
+    pub fn from_str_wasm(json_str: &str) -> Result<Self, ParseError> {
+        Self::from_str(json_str)
+    }
+
+    /// `[skinJson, warnings]`
+    #[wasm_bindgen(js_name = fromStrWith)]
+    pub fn from_str_with_wasm(json_str: &str, mode: ParseMode) -> Result<js_sys::Array, ParseError> {
+        let (skin, warnings) = Self::from_str_with(json_str, mode)?;
+        Ok(utils::wasm::with_warnings(skin, warnings))
     }
 
     #[wasm_bindgen(js_name = toString)]
@@ -102,20 +110,63 @@ impl Serialize for SkinJson {
 }
 
 impl SkinJson {
-    fn parse(json_str: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    /// `from_str` is lenient and drops the warnings.
+    /// broken json and values of the wrong type are errors in either mode
+    pub fn from_str_with(json_str: &str, mode: ParseMode) -> Result<(Self, Vec<ParseWarning>), ParseError> {
+        let mut diag = ParseDiagnostics::new(mode);
         let mut skin: SkinJson = serde_json::from_str(json_str)?;
+        let raw: IndexMap<String, serde_json::Value> = serde_json::from_str(json_str)?;
+
+        for (key, value) in &raw {
+            let known = match key.as_str() {
+                "info" => serde_json::to_value(Info::default())?,
+                "judgements" => serde_json::to_value(JudgementColors::default())?,
+                "snap-colors" => serde_json::to_value(SnapColors::default())?,
+                // anything goes in here
+                "overrides" => continue,
+                _ => match key.strip_suffix('k').and_then(|k| k.parse::<u8>().ok()) {
+                    Some(k @ 1..=10) => {
+                        let mut keymode: Keymode = serde_json::from_value(value.clone())?;
+                        keymode.keymode = k;
+                        skin.keymodes.push(keymode);
+                        serde_json::to_value(Keymode::default())?
+                    }
+                    Some(_) => {
+                        diag.report(ParseError::out_of_range(key, &json_value(value), "1k-10k"))?;
+                        continue;
+                    }
+                    None => {
+                        diag.unknown_key(key, &json_value(value))?;
+                        continue;
+                    }
+                },
+            };
+
+            let Some(fields) = value.as_object() else { continue };
+            for (field, value) in fields {
+                if known.get(field).is_none() {
+                    diag.report(ParseError::unknown_key(field, &json_value(value)).in_section(key))?;
+                }
+            }
+        }
+
         skin.parse_keymodes_from_overrides();
-        Ok(skin)
+        Ok((skin, diag.warnings))
+    }
+
+    fn parse(json_str: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(json_str, ParseMode::Lenient).map(|(skin, _)| skin)
     }
 
     fn serialize(&self) -> Result<String, Box<dyn std::error::Error>> {
         Ok(serde_json::to_string_pretty(&self)?)
     }
 
+    /// keymodes that came with their own `4k` style object keep its settings
     pub fn parse_keymodes_from_overrides(&mut self) {
-        self.keymodes.clear();
+        let parsed = std::mem::take(&mut self.keymodes);
         for k in 1..=10 {
-            let mut km = Keymode::default();
+            let mut km = parsed.iter().find(|km| km.keymode == k).cloned().unwrap_or_default();
             km.keymode = k;
             Self::alloc_vecs(&mut km, k as usize);
             self.keymodes.push(km);
@@ -204,6 +255,11 @@ impl SkinJson {
     }
 }
 
+/// strings without their quotes
+fn json_value(value: &serde_json::Value) -> String {
+    value.as_str().map_or_else(|| value.to_string(), str::to_string)
+}
+
 impl ToString for SkinJson {
     fn to_string(&self) -> String {
         self.serialize().unwrap_or_else(|_| String::new())
@@ -211,7 +267,7 @@ impl ToString for SkinJson {
 }
 
 impl FromStr for SkinJson {
-    type Err = Box<dyn std::error::Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Self::parse(s)
@@ -1,5 +1,5 @@
 use rgskin_derive::GetAllTextures;
-use crate::{common::color::Rgba, io::texture::Texture, Binary};
+use crate::{common::color::Rgba, io::texture::Texture, io::traits::{MapTextures, TextureMapper}, Binary};
 use image::imageops::FilterType;
 use std::sync::{Arc, RwLock};
 
@@ -151,11 +151,11 @@ macro_rules! skin_element {
             $(
                 paste::paste! {
                     pub fn [<as_ $extra>](&self) -> Option<std::sync::RwLockReadGuard<'_, Texture>> {
-                        self.$extra.as_ref().map(|t| t.read().unwrap())
+                        self.$extra.as_ref().map(|t| t.read().unwrap_or_else(std::sync::PoisonError::into_inner))
                     }
                     
                     pub fn [<as_ $extra _mut>](&self) -> Option<std::sync::RwLockWriteGuard<'_, Texture>> {
-                        self.$extra.as_ref().map(|t| t.write().unwrap())
+                        self.$extra.as_ref().map(|t| t.write().unwrap_or_else(std::sync::PoisonError::into_inner))
                     }
                     
                     pub fn [<with_ $extra>]<F, R>(&self, f: F) -> Option<R>
@@ -189,13 +189,20 @@ macro_rules! skin_element {
             )*
         }
 
+        impl MapTextures for $name {
+            fn map_textures(&mut self, f: &mut TextureMapper) {
+                self.$primary.map_textures(f);
+                $(self.$extra.map_textures(f);)*
+            }
+        }
+
         impl SkinElement for $name {
             fn as_texture(&self) -> Option<std::sync::RwLockReadGuard<'_, Texture>> {
-                self.$primary.as_ref().map(|t| t.read().unwrap())
+                self.$primary.as_ref().map(|t| t.read().unwrap_or_else(std::sync::PoisonError::into_inner))
             }
             
             fn as_texture_mut(&self) -> Option<std::sync::RwLockWriteGuard<'_, Texture>> {
-                self.$primary.as_ref().map(|t| t.write().unwrap())
+                self.$primary.as_ref().map(|t| t.write().unwrap_or_else(std::sync::PoisonError::into_inner))
             }
         }
     };
@@ -280,26 +287,26 @@ macro_rules! skin_element_sheet {
                 paste::paste! {
                     pub fn [<as_ $extra _frame>](&self, index: usize) -> Option<std::sync::RwLockReadGuard<'_, Texture>> {
                         if self.$extra.len() <= 1 {
-                            self.$extra.first().map(|t| t.read().unwrap())
+                            self.$extra.first().map(|t| t.read().unwrap_or_else(std::sync::PoisonError::into_inner))
                         } else {
-                            self.$extra.get(index).map(|t| t.read().unwrap())
+                            self.$extra.get(index).map(|t| t.read().unwrap_or_else(std::sync::PoisonError::into_inner))
                         }
                     }
                     
                     pub fn [<as_ $extra _frame_mut>](&self, index: usize) -> Option<std::sync::RwLockWriteGuard<'_, Texture>> {
                         if self.$extra.len() <= 1 {
-                            self.$extra.first().map(|t| t.write().unwrap())
+                            self.$extra.first().map(|t| t.write().unwrap_or_else(std::sync::PoisonError::into_inner))
                         } else {
-                            self.$extra.get(index).map(|t| t.write().unwrap())
+                            self.$extra.get(index).map(|t| t.write().unwrap_or_else(std::sync::PoisonError::into_inner))
                         }
                     }
                     
                     pub fn [<as_ $extra _frames>](&self) -> Vec<std::sync::RwLockReadGuard<'_, Texture>> {
-                        self.$extra.iter().map(|t| t.read().unwrap()).collect()
+                        self.$extra.iter().map(|t| t.read().unwrap_or_else(std::sync::PoisonError::into_inner)).collect()
                     }
                     
                     pub fn [<as_ $extra _frames_mut>](&self) -> Vec<std::sync::RwLockWriteGuard<'_, Texture>> {
-                        self.$extra.iter().map(|t| t.write().unwrap()).collect()
+                        self.$extra.iter().map(|t| t.write().unwrap_or_else(std::sync::PoisonError::into_inner)).collect()
                     }
                     
                     pub fn [<with_ $extra _frame>]<F, R>(&self, index: usize, f: F) -> Option<R>
@@ -333,13 +340,20 @@ macro_rules! skin_element_sheet {
             )*
         }
 
+        impl MapTextures for $name {
+            fn map_textures(&mut self, f: &mut TextureMapper) {
+                self.$primary.map_textures(f);
+                $(self.$extra.map_textures(f);)*
+            }
+        }
+
         impl SkinElement for $name {
             fn as_texture(&self) -> Option<std::sync::RwLockReadGuard<'_, Texture>> {
-                self.$primary.first().map(|t| t.read().unwrap())
+                self.$primary.first().map(|t| t.read().unwrap_or_else(std::sync::PoisonError::into_inner))
             }
             
             fn as_texture_mut(&self) -> Option<std::sync::RwLockWriteGuard<'_, Texture>> {
-                self.$primary.first().map(|t| t.write().unwrap())
+                self.$primary.first().map(|t| t.write().unwrap_or_else(std::sync::PoisonError::into_inner))
             }
 
             fn is_sheet(&self) -> bool { true }
@@ -364,26 +378,26 @@ macro_rules! skin_element_sheet {
             
             fn as_texture_frame(&self, index: usize) -> Option<std::sync::RwLockReadGuard<'_, Texture>> {
                 if self.$primary.len() <= 1 {
-                    self.$primary.first().map(|t| t.read().unwrap())
+                    self.$primary.first().map(|t| t.read().unwrap_or_else(std::sync::PoisonError::into_inner))
                 } else {
-                    self.$primary.get(index).map(|t| t.read().unwrap())
+                    self.$primary.get(index).map(|t| t.read().unwrap_or_else(std::sync::PoisonError::into_inner))
                 }
             }
             
             fn as_texture_frame_mut(&self, index: usize) -> Option<std::sync::RwLockWriteGuard<'_, Texture>> {
                 if self.$primary.len() <= 1 {
-                    self.$primary.first().map(|t| t.write().unwrap())
+                    self.$primary.first().map(|t| t.write().unwrap_or_else(std::sync::PoisonError::into_inner))
                 } else {
-                    self.$primary.get(index).map(|t| t.write().unwrap())
+                    self.$primary.get(index).map(|t| t.write().unwrap_or_else(std::sync::PoisonError::into_inner))
                 }
             }
 
             fn as_texture_frames(&self) -> Vec<std::sync::RwLockReadGuard<'_, Texture>> {
-                self.$primary.iter().map(|t| t.read().unwrap()).collect()
+                self.$primary.iter().map(|t| t.read().unwrap_or_else(std::sync::PoisonError::into_inner)).collect()
             }
 
             fn as_texture_frames_mut(&self) -> Vec<std::sync::RwLockWriteGuard<'_, Texture>> {
-                self.$primary.iter().map(|t| t.write().unwrap()).collect()
+                self.$primary.iter().map(|t| t.write().unwrap_or_else(std::sync::PoisonError::into_inner)).collect()
             }
         }
     };
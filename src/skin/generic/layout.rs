@@ -22,3 +22,10 @@ pub struct HUDLayout {
     pub score: (Vector3<f32>, Alignment),
     pub judgement: (Vector3<f32>, Alignment),
 }
+
+impl HUDLayout {
+    /// converters leave elements their source doesn't position at the origin with a scale of 1
+    pub fn is_placed(element: &(Vector3<f32>, Alignment)) -> bool {
+        element.0 != Vector3::new(0.0, 0.0, 1.0)
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, PoisonError, RwLock};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::common::traits::ManiaSkin;
+use crate::error::MergePlanError;
+use crate::generic::sound::Sounds;
+use crate::generic::{GenericManiaSkin, Keymode};
+use crate::io::traits::MapTextures;
+use crate::sample::SampleStore;
+use crate::texture::{Texture, TextureStore};
+use crate::{EtternaNoteskin, FluXisSkin, MalodySkin, OsuSkin, QuaSkin, Store};
+
+/// the parts of a skin a merge plan can take from different sources
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ElementGroup {
+    /// notes, long notes and mines, with their snap colours
+    Notes,
+    Receptors,
+    /// hit and column lighting
+    Lighting,
+    /// the stage and the judgement line
+    Stage,
+    Judgements,
+    HealthBar,
+    Sounds,
+    Cursor,
+}
+
+impl ElementGroup {
+    /// the groups every keymode has its own of
+    pub const PER_KEYMODE: [ElementGroup; 4] = [
+        ElementGroup::Notes,
+        ElementGroup::Receptors,
+        ElementGroup::Lighting,
+        ElementGroup::Stage,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ElementGroup::Notes => "notes",
+            ElementGroup::Receptors => "receptors",
+            ElementGroup::Lighting => "lighting",
+            ElementGroup::Stage => "stage",
+            ElementGroup::Judgements => "judgements",
+            ElementGroup::HealthBar => "health bar",
+            ElementGroup::Sounds => "sounds",
+            ElementGroup::Cursor => "cursor",
+        }
+    }
+
+    pub fn is_per_keymode(&self) -> bool {
+        Self::PER_KEYMODE.contains(self)
+    }
+}
+
+/// which source skin each keymode and element group is taken from, sources are indexes into the
+/// slice handed to `merge_with_plan`. the most specific choice wins: a group picked for one keymode,
+/// then a group picked for all of them, then the keymode, then `base`
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Default)]
+pub struct MergePlan {
+    /// everything nothing else was picked for, including the metadata, layout and resolution
+    pub base: usize,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub keymodes: HashMap<u8, usize>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub elements: HashMap<ElementGroup, usize>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub keymode_elements: HashMap<(u8, ElementGroup), usize>,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl MergePlan {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new(base: usize) -> Self {
+        Self { base, ..Default::default() }
+    }
+
+    /// the whole keymode, layout included, also adds it if `base` doesn't have it
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = withKeymode))]
+    pub fn with_keymode(mut self, keymode: u8, source: usize) -> Self {
+        self.keymodes.insert(keymode, source);
+        self
+    }
+
+    /// per keymode groups are only taken for the keymodes `source` has
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = withElement))]
+    pub fn with_element(mut self, group: ElementGroup, source: usize) -> Self {
+        self.elements.insert(group, source);
+        self
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = withKeymodeElement))]
+    pub fn with_keymode_element(mut self, keymode: u8, group: ElementGroup, source: usize) -> Self {
+        self.keymode_elements.insert((keymode, group), source);
+        self
+    }
+}
+
+impl MergePlan {
+    fn validate(&self, sources: usize) -> Result<(), MergePlanError> {
+        let used = std::iter::once(self.base)
+            .chain(self.keymodes.values().copied())
+            .chain(self.elements.values().copied())
+            .chain(self.keymode_elements.values().copied());
+        for index in used {
+            if index >= sources {
+                return Err(MergePlanError::MissingSource { index, sources });
+            }
+        }
+
+        match self.keymode_elements.keys().find(|(_, group)| !group.is_per_keymode()) {
+            Some((_, group)) => Err(MergePlanError::NotPerKeymode { group: *group }),
+            None => Ok(()),
+        }
+    }
+
+    fn element_source(&self, group: ElementGroup) -> usize {
+        self.elements.get(&group).copied().unwrap_or(self.base)
+    }
+}
+
+/// builds one skin out of parts of several
+pub trait MergeWithPlan: Sized {
+    fn merge_with_plan(sources: &[&Self], plan: &MergePlan) -> Result<Self, MergePlanError>;
+}
+
+/// copies textures and samples from the other sources into the merged skin's stores. paths that are
+/// taken by something else get a `_1`, `_2`, ... suffix from `Store::make_unique`, the same content
+/// under the same path is shared instead
+struct Carrier<'a> {
+    base: usize,
+    textures: &'a mut TextureStore,
+    samples: &'a mut SampleStore,
+    carried: HashMap<(usize, *const RwLock<Texture>), Arc<RwLock<Texture>>>,
+}
+
+impl Carrier<'_> {
+    fn texture(&mut self, source: usize, texture: &Arc<RwLock<Texture>>) -> Arc<RwLock<Texture>> {
+        if source == self.base {
+            return Arc::clone(texture);
+        }
+        if let Some(carried) = self.carried.get(&(source, Arc::as_ptr(texture))) {
+            return Arc::clone(carried);
+        }
+
+        let copy = texture.read().unwrap_or_else(PoisonError::into_inner).clone();
+        let existing = self.textures.get(&copy.path);
+        let same = existing.as_ref()
+            .is_some_and(|existing| existing.read().unwrap_or_else(PoisonError::into_inner).content_hash() == copy.content_hash());
+
+        let carried = match existing {
+            Some(existing) if same => existing,
+            _ => {
+                let path = copy.path.clone();
+                let new_path = self.textures.make_unique(&path, copy);
+                self.textures.get(&new_path).unwrap_or_else(|| Arc::clone(texture))
+            }
+        };
+
+        self.carried.insert((source, Arc::as_ptr(texture)), Arc::clone(&carried));
+        carried
+    }
+
+    fn element<T: MapTextures + Clone>(&mut self, source: usize, element: &T) -> T {
+        let mut element = element.clone();
+        element.map_textures(&mut |texture| self.texture(source, texture));
+        element
+    }
+
+    /// the path stays as it is if `source` doesn't have the sample either
+    fn sample(&mut self, source: usize, from: &SampleStore, path: &str) -> String {
+        let Some(sample) = from.get(path).filter(|_| source != self.base) else { return path.to_string() };
+        let sample = sample.read().unwrap_or_else(PoisonError::into_inner).clone();
+
+        let same = self.samples.get(path)
+            .is_some_and(|existing| existing.read().unwrap_or_else(PoisonError::into_inner).content_hash() == sample.content_hash());
+        if same {
+            return path.to_string();
+        }
+        self.samples.make_unique(path, sample)
+    }
+}
+
+fn sound_paths(sounds: &mut Sounds) -> [&mut Option<String>; 8] {
+    [
+        &mut sounds.ui.menu_back_click,
+        &mut sounds.ui.ui_click,
+        &mut sounds.ui.ui_select,
+        &mut sounds.ui.ui_hover,
+        &mut sounds.gameplay.miss,
+        &mut sounds.gameplay.fail,
+        &mut sounds.gameplay.restart,
+        &mut sounds.mania.hit,
+    ]
+}
+
+/// replaces `group` in `into` with the one from `from`
+fn take_group(carrier: &mut Carrier, source: usize, group: ElementGroup, into: &mut Keymode, from: &Keymode) {
+    match group {
+        ElementGroup::Notes => {
+            into.normal_notes = carrier.element(source, &from.normal_notes);
+            into.long_note_heads = carrier.element(source, &from.long_note_heads);
+            into.long_note_bodies = carrier.element(source, &from.long_note_bodies);
+            into.long_note_tails = carrier.element(source, &from.long_note_tails);
+            into.normal_mines = carrier.element(source, &from.normal_mines);
+            into.base_normal_note = carrier.element(source, &from.base_normal_note);
+            into.base_long_note = carrier.element(source, &from.base_long_note);
+            into.base_normal_mine = carrier.element(source, &from.base_normal_mine);
+            into.normal_notes_snap_colored = carrier.element(source, &from.normal_notes_snap_colored);
+            into.long_note_heads_snap_colored = carrier.element(source, &from.long_note_heads_snap_colored);
+            into.normal_mines_snap_colored = carrier.element(source, &from.normal_mines_snap_colored);
+            into.use_snap_color = from.use_snap_color;
+            into.snap_colors = from.snap_colors.clone();
+        }
+        ElementGroup::Receptors => {
+            into.receptor_up = carrier.element(source, &from.receptor_up);
+            into.receptor_down = carrier.element(source, &from.receptor_down);
+        }
+        ElementGroup::Lighting => {
+            into.hit_lighting_normal = carrier.element(source, &from.hit_lighting_normal);
+            into.hit_lighting_hold = carrier.element(source, &from.hit_lighting_hold);
+            into.column_lighting = carrier.element(source, &from.column_lighting);
+        }
+        ElementGroup::Stage => {
+            into.stage = carrier.element(source, &from.stage);
+            into.judgement_line = carrier.element(source, &from.judgement_line);
+        }
+        _ => {}
+    }
+}
+
+impl MergeWithPlan for GenericManiaSkin {
+    /// starts from `base` and swaps in what the plan takes from elsewhere
+    fn merge_with_plan(sources: &[&Self], plan: &MergePlan) -> Result<Self, MergePlanError> {
+        plan.validate(sources.len())?;
+        let base = sources[plan.base];
+        let mut merged = base.clone();
+
+        let mut carrier = Carrier {
+            base: plan.base,
+            textures: &mut merged.textures,
+            samples: &mut merged.samples,
+            carried: HashMap::new(),
+        };
+
+        let source = plan.element_source(ElementGroup::Judgements);
+        merged.gameplay.judgement = carrier.element(source, &sources[source].gameplay.judgement);
+        let source = plan.element_source(ElementGroup::HealthBar);
+        merged.gameplay.health_bar = carrier.element(source, &sources[source].gameplay.health_bar);
+        let source = plan.element_source(ElementGroup::Cursor);
+        merged.ui.cursor = carrier.element(source, &sources[source].ui.cursor);
+
+        let source = plan.element_source(ElementGroup::Sounds);
+        merged.sounds = sources[source].sounds.clone();
+        for path in sound_paths(&mut merged.sounds).into_iter().flatten() {
+            *path = carrier.sample(source, &sources[source].samples, path);
+        }
+
+        let keymodes: BTreeSet<u8> = base.keymodes.iter().map(|k| k.keymode).chain(plan.keymodes.keys().copied()).collect();
+        let find = |source: usize, keymode: u8| sources[source].get_keymode(keymode);
+
+        merged.keymodes = Vec::with_capacity(keymodes.len());
+        for k in keymodes {
+            let keymode_source = plan.keymodes.get(&k).copied().unwrap_or(plan.base);
+            let from = find(keymode_source, k).ok_or(MergePlanError::MissingKeymode { source: keymode_source, keymode: k })?;
+
+            let mut keymode = from.clone();
+            for group in ElementGroup::PER_KEYMODE {
+                let (source, from) = match (plan.keymode_elements.get(&(k, group)), plan.elements.get(&group)) {
+                    (Some(&source), _) => (source, find(source, k).ok_or(MergePlanError::MissingKeymode { source, keymode: k })?),
+                    (None, Some(&source)) => match find(source, k) {
+                        Some(from) => (source, from),
+                        None => (keymode_source, from),
+                    },
+                    (None, None) => (keymode_source, from),
+                };
+                take_group(&mut carrier, source, group, &mut keymode, from);
+            }
+            merged.keymodes.push(keymode);
+        }
+
+        Ok(merged)
+    }
+}
+
+// the game specific skins are merged as generic skins and converted back, so only what survives
+// the conversion is kept
+macro_rules! impl_merge_with_plan {
+    ($($skin:ty),*) => {
+        $(
+            impl MergeWithPlan for $skin {
+                fn merge_with_plan(sources: &[&Self], plan: &MergePlan) -> Result<Self, MergePlanError> {
+                    let generic = sources.iter().map(|skin| skin.to_generic_mania(())).collect::<Result<Vec<_>, _>>()?;
+                    let generic: Vec<&GenericManiaSkin> = generic.iter().collect();
+                    Ok(Self::from_generic_mania(&GenericManiaSkin::merge_with_plan(&generic, plan)?)?)
+                }
+            }
+        )*
+    };
+}
+
+impl_merge_with_plan!(OsuSkin, QuaSkin, MalodySkin, EtternaNoteskin);
+
+/// the layouts aren't merged, the skins are converted without one
+impl MergeWithPlan for FluXisSkin {
+    fn merge_with_plan(sources: &[&Self], plan: &MergePlan) -> Result<Self, MergePlanError> {
+        let generic = sources.iter().map(|skin| skin.to_generic_mania(None)).collect::<Result<Vec<_>, _>>()?;
+        let generic: Vec<&GenericManiaSkin> = generic.iter().collect();
+        let (skin, _) = FluXisSkin::from_generic_mania(&GenericManiaSkin::merge_with_plan(&generic, plan)?)?;
+        Ok(skin)
+    }
+}
+
+/// collects the skins a merge plan picks from, JS can't hand over a slice of them
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct SkinMerger {
+    sources: Vec<GenericManiaSkin>,
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl SkinMerger {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// the index the plan refers to this skin by
+    #[wasm_bindgen(js_name = addSource)]
+    pub fn add_source(&mut self, skin: &GenericManiaSkin) -> usize {
+        self.sources.push(skin.clone());
+        self.sources.len() - 1
+    }
+
+    pub fn merge(&self, plan: &MergePlan) -> Result<GenericManiaSkin, JsValue> {
+        let sources: Vec<&GenericManiaSkin> = self.sources.iter().collect();
+        GenericManiaSkin::merge_with_plan(&sources, plan)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
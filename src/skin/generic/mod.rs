@@ -8,9 +8,11 @@ mod ui;
 pub mod sound;
 pub mod elements;
 pub mod layout;
+pub mod merge_plan;
 
 pub use metadata::Metadata;
 pub use keymode::Keymode;
 pub use skin::GenericManiaSkin;
 pub use gameplay::Gameplay;
-pub use ui::UI;
\ No newline at end of file
+pub use ui::UI;
+pub use merge_plan::{ElementGroup, MergePlan, MergeWithPlan};
\ No newline at end of file
@@ -1,3 +1,4 @@
+use image::RgbaImage;
 use merge::Merge;
 use rgskin_derive::GetAllTextures;
 #[cfg(target_arch = "wasm32")]
@@ -11,9 +12,12 @@ use crate::generic::sound::Sounds;
 use crate::generic::UI;
 use crate::io::texture::TextureStore;
 use crate::io::traits::GetAllTextures;
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::ConversionReport;
 use crate::sample::SampleStore;
 use crate::skin::generic::{Keymode, Metadata};
-use crate::{Binary, BinaryArcExt, Store, StringPattern, texture, utils};
+use crate::{Binary, BinaryArcExt, Store, StringPattern, image_proc, texture, utils};
 use crate::extensions::TextureArcExt;
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
@@ -44,12 +48,12 @@ impl<'a> ManiaSkin<'a> for GenericManiaSkin {
     type ToParams = ();
     type FromReturn = Self;
 
-    fn to_generic_mania(&self, _params: Self::ToParams) -> Result<GenericManiaSkin, Box<dyn std::error::Error>> {
-        Ok(self.clone())
+    fn to_generic_mania_with(&self, _params: Self::ToParams, _options: &ConversionOptions) -> Result<(GenericManiaSkin, ConversionReport), ConvertError> {
+        Ok((self.clone(), ConversionReport::new()))
     }
 
-    fn from_generic_mania(skin: &GenericManiaSkin) -> Result<Self::FromReturn, Box<dyn std::error::Error>> {
-        Ok(skin.clone())
+    fn from_generic_mania_with(skin: &GenericManiaSkin, _options: &ConversionOptions) -> Result<(Self::FromReturn, ConversionReport), ConvertError> {
+        Ok((skin.clone(), ConversionReport::new()))
     }
 
     fn get_keymode(&self, keymode: u8) -> Option<&Keymode> {
@@ -81,6 +85,15 @@ impl GenericManiaSkin {
             self.textures.insert_shared(texture_arc);
         }
     }
+
+    pub fn generate_preview(&self, keymode: u8, width: u32, height: u32) -> Result<RgbaImage, ConvertError> {
+        image_proc::generate_preview(self, keymode, width, height)
+    }
+
+    /// APNG bytes
+    pub fn generate_animated_preview(&self, keymode: u8, width: u32, height: u32) -> Result<Vec<u8>, ConvertError> {
+        image_proc::generate_animated_preview(self, keymode, width, height)
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -103,6 +116,19 @@ impl GenericManiaSkin {
         self.get_keymode(keymode).cloned()
     }
 
+    #[wasm_bindgen(js_name = generatePreview)]
+    pub fn generate_preview_wasm(&self, keymode: u8, width: u32, height: u32) -> Result<texture::Texture, JsValue> {
+        self.generate_preview(keymode, width, height)
+            .map(|img| texture::Texture::with_data("preview".to_string(), img))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    #[wasm_bindgen(js_name = generateAnimatedPreview)]
+    pub fn generate_animated_preview_wasm(&self, keymode: u8, width: u32, height: u32) -> Result<Vec<u8>, JsValue> {
+        self.generate_animated_preview(keymode, width, height)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
     #[wasm_bindgen(js_name = getRequiredTexturePaths)]
     pub fn get_required_texture_paths_wasm(&self) -> Vec<String> {
         self.get_required_texture_paths().into_iter().map(|p| p.to_string()).collect()
@@ -0,0 +1,154 @@
+use merge::Merge;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use indexmap::IndexMap;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ConstTypeEnum, StringPattern,
+    common::traits::{KeymodeInvariant, ManiaSkinConfig, SkinConfig},
+    malody::{config::{Keymode, Meta}, dynamic_assets, static_assets},
+    utils,
+};
+use crate::error::ParseError;
+
+/// keymodes malody lets you play in key mode
+pub const SUPPORTED_KEYMODES: std::ops::RangeInclusive<u8> = 4..=10;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Merge)]
+pub struct ConfJson {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    #[merge(skip)]
+    pub meta: Meta,
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    #[merge(strategy = utils::merge::skin::overwrite_keymode)]
+    pub keymodes: Vec<Keymode>,
+}
+
+/// `conf.json` keeps the keymodes in a `"key": { "4k": {...} }` object
+#[derive(Serialize, Deserialize)]
+struct ConfJsonSerialized<T> {
+    #[serde(default)]
+    meta: Meta,
+    #[serde(default)]
+    key: IndexMap<String, T>,
+}
+
+impl Default for ConfJson {
+    fn default() -> Self {
+        Self {
+            meta: Meta::default(),
+            keymodes: SUPPORTED_KEYMODES.map(Keymode::new).collect(),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl ConfJson {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        ConfJson::default()
+    }
+
+    #[wasm_bindgen(js_name = fromStr)]
+    pub fn from_str_wasm(json_str: &str) -> Result<Self, ParseError> {
+        Self::from_str(json_str)
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string_wasm(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ConfJson {
+    fn parse(json_str: &str) -> Result<Self, ParseError> {
+        let raw: ConfJsonSerialized<Keymode> = serde_json::from_str(json_str)?;
+        let mut conf = ConfJson { meta: raw.meta, keymodes: Vec::new() };
+
+        for (key, mut keymode) in raw.key {
+            let Some(keymode_num) = key.trim_end_matches(['k', 'K']).parse::<u8>().ok() else { continue };
+            keymode.keymode = keymode_num;
+            conf.keymodes.push(keymode);
+        }
+
+        // keymodes missing from the config still get drawn by the game with the default textures
+        for k in SUPPORTED_KEYMODES {
+            if conf.get_keymode(k).is_none() {
+                conf.keymodes.push(Keymode::new(k));
+            }
+        }
+        conf.keymodes.sort_by_key(|k| k.keymode);
+
+        Ok(conf)
+    }
+
+    fn serialize(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let temp = ConfJsonSerialized {
+            meta: self.meta.clone(),
+            key: self.keymodes
+                .iter()
+                .map(|km| (format!("{}k", km.keymode), km))
+                .collect(),
+        };
+
+        Ok(serde_json::to_string_pretty(&temp)?)
+    }
+}
+
+impl ToString for ConfJson {
+    fn to_string(&self) -> String {
+        self.serialize().unwrap_or_else(|_| String::new())
+    }
+}
+
+impl FromStr for ConfJson {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl SkinConfig for ConfJson {
+    fn get_required_texture_paths(&self) -> Vec<StringPattern> {
+        let mut paths: Vec<StringPattern> = Vec::new();
+
+        for keymode in &self.keymodes {
+            paths.extend(keymode.get_receptors().into_iter().map(StringPattern::from));
+            paths.extend(keymode.get_receptors_down().into_iter().map(StringPattern::from));
+            paths.extend(keymode.get_normal_notes().into_iter().map(StringPattern::from));
+            paths.extend(keymode.get_long_note_heads().into_iter().map(StringPattern::from));
+            paths.extend(keymode.get_long_note_bodies().into_iter().map(StringPattern::from));
+            paths.extend(keymode.get_long_note_tails().into_iter().map(StringPattern::from));
+        }
+
+        paths.extend(dynamic_assets::Lighting::iter_mapped(|t| t));
+        paths.extend(dynamic_assets::Stage::iter_mapped(|t| t));
+        paths.extend(dynamic_assets::Shared::iter_mapped(|t| t));
+        paths.extend(static_assets::Judgement::iter_mapped(|t| t));
+        paths.extend(static_assets::Health::iter_mapped(|t| t));
+
+        paths.sort_unstable();
+        paths.dedup();
+
+        paths
+    }
+
+    fn get_required_sample_paths(&self) -> Vec<StringPattern> {
+        static_assets::Samples::iter_mapped(|s| s).collect()
+    }
+}
+
+impl ManiaSkinConfig for ConfJson {
+    type Keymode = Keymode;
+
+    fn get_keymode(&self, keymode: u8) -> Option<&Self::Keymode> {
+        self.keymodes.iter().find(|k| k.keymode == keymode)
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+use crate::malody::dynamic_assets;
+use crate::traits::{KeymodeInvariant, LaneFallback, LaneType};
+use crate::StringPattern;
+
+/// per keymode section of `conf.json`, the image lists are optional overrides
+/// and any lane left empty uses the default `key/{keys}k/...` texture
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = MalodyKeymode))]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Keymode {
+    #[serde(skip)]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub keymode: u8,
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub column_width: u32,
+
+    /// distance of the judge line from the bottom of the screen
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub judge_line: i32,
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub receptor_offset: i32,
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub receptor_above_notes: bool,
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub show_judge_line: bool,
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub effect_fps: u32,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub key: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub key_pressed: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub note: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub hold_head: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub hold_body: Vec<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub hold_tail: Vec<String>,
+}
+
+impl Default for Keymode {
+    fn default() -> Self {
+        Self {
+            keymode: 0,
+            column_width: 90,
+            judge_line: 120,
+            receptor_offset: 0,
+            receptor_above_notes: false,
+            show_judge_line: true,
+            effect_fps: 60,
+            key: Vec::new(),
+            key_pressed: Vec::new(),
+            note: Vec::new(),
+            hold_head: Vec::new(),
+            hold_body: Vec::new(),
+            hold_tail: Vec::new(),
+        }
+    }
+}
+
+impl Keymode {
+    pub fn new(keymode: u8) -> Self {
+        Self { keymode, ..Default::default() }
+    }
+
+    fn saturate_per_lane(&self, overrides: &[String], pattern: &StringPattern) -> Vec<String> {
+        (1..=self.keymode as usize)
+            .map(|lane| {
+                overrides.get(lane - 1)
+                    .filter(|o| !o.is_empty())
+                    .cloned()
+                    .unwrap_or_else(|| self.get_generic(pattern.clone(), lane).to_string())
+            })
+            .collect()
+    }
+
+    fn shared_for_lane_type(lane_type: LaneType) -> LaneFallback {
+        let type_str = match lane_type {
+            LaneType::Primary => "primary",
+            LaneType::Secondary => "secondary",
+            LaneType::Middle => "middle",
+        };
+        let saturate = |pattern: &StringPattern| pattern.replace("{type}", type_str);
+
+        LaneFallback {
+            receptor: saturate(&dynamic_assets::Shared::RECEPTOR_UP),
+            receptor_down: saturate(&dynamic_assets::Shared::RECEPTOR_DOWN),
+            normal_note: saturate(&dynamic_assets::Shared::NOTE),
+            long_note_head: saturate(&dynamic_assets::Shared::HOLD_HEAD),
+            long_note_body: saturate(&dynamic_assets::Shared::HOLD_BODY),
+            long_note_tail: saturate(&dynamic_assets::Shared::HOLD_TAIL),
+            // malody has no mines, notes stand in for them
+            normal_mine: saturate(&dynamic_assets::Shared::NOTE),
+        }
+    }
+}
+
+impl KeymodeInvariant for Keymode {
+    fn get_keymode(&self) -> u8 { self.keymode }
+
+    fn get_receptors(&self) -> Vec<String> {
+        self.saturate_per_lane(&self.key, &dynamic_assets::Receptors::UP)
+    }
+    fn get_receptors_down(&self) -> Vec<String> {
+        self.saturate_per_lane(&self.key_pressed, &dynamic_assets::Receptors::DOWN)
+    }
+    fn get_normal_notes(&self) -> Vec<String> {
+        self.saturate_per_lane(&self.note, &dynamic_assets::Notes::NOTE)
+    }
+    fn get_long_note_heads(&self) -> Vec<String> {
+        self.saturate_per_lane(&self.hold_head, &dynamic_assets::Notes::HOLD_HEAD)
+    }
+    fn get_long_note_bodies(&self) -> Vec<String> {
+        self.saturate_per_lane(&self.hold_body, &dynamic_assets::Notes::HOLD_BODY)
+    }
+    fn get_long_note_tails(&self) -> Vec<String> {
+        self.saturate_per_lane(&self.hold_tail, &dynamic_assets::Notes::HOLD_TAIL)
+    }
+    fn get_normal_mines(&self) -> Vec<String> {
+        self.get_normal_notes()
+    }
+
+    fn primary_fallback(&self, _lane: usize) -> LaneFallback { Self::shared_for_lane_type(LaneType::Primary) }
+    fn secondary_fallback(&self, _lane: usize) -> LaneFallback { Self::shared_for_lane_type(LaneType::Secondary) }
+    fn middle_fallback(&self, _lane: usize) -> LaneFallback { Self::shared_for_lane_type(LaneType::Middle) }
+}
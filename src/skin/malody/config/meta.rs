@@ -0,0 +1,21 @@
+use merge::Merge;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use serde::{Deserialize, Serialize};
+use rgskin_derive::merge_for_all;
+
+#[merge_for_all(strategy = crate::utils::merge::any::overwrite)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = MalodyMeta))]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Merge)]
+#[serde(default)]
+pub struct Meta {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub name: String,
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub creator: String,
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub version: String,
+}
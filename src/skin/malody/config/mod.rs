@@ -0,0 +1,5 @@
+pub mod keymode;
+pub mod meta;
+
+pub use keymode::Keymode;
+pub use meta::Meta;
@@ -0,0 +1,83 @@
+use crate::{common::{alignment::Anchor, skin::{AnimationSpriteType, AssetAttribute}}, def_const_type_enum};
+
+def_const_type_enum!(pub Receptors => StringPattern {
+    UP => "key/{keys}k/key-{lane}" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomLeft)
+    ],
+    DOWN => "key/{keys}k/key-{lane}-pressed" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomLeft)
+    ],
+});
+
+def_const_type_enum!(pub Notes => StringPattern {
+    NOTE => "key/{keys}k/note-{lane}" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomLeft)
+    ],
+    HOLD_HEAD => "key/{keys}k/hold-head-{lane}" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomLeft)
+    ],
+    HOLD_BODY => "key/{keys}k/hold-body-{lane}" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomLeft)
+    ],
+    HOLD_TAIL => "key/{keys}k/hold-tail-{lane}" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomLeft)
+    ],
+});
+
+def_const_type_enum!(pub Lighting => StringPattern {
+    HIT => "key/{keys}k/effect-hit" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    HIT_SHEET => "key/{keys}k/effect-hit@{rows}x{cols}" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    HOLD => "key/{keys}k/effect-hold" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    HOLD_SHEET => "key/{keys}k/effect-hold@{rows}x{cols}" [
+        AssetAttribute::Texture,
+        AssetAttribute::Animatable(AnimationSpriteType::SpriteSheet),
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+});
+
+def_const_type_enum!(pub Stage => StringPattern {
+    BACKGROUND => "key/{keys}k/stage" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomCentre)
+    ],
+    BORDER_LEFT => "key/{keys}k/stage-left" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomRight)
+    ],
+    BORDER_RIGHT => "key/{keys}k/stage-right" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomLeft)
+    ],
+    JUDGE_LINE => "key/{keys}k/judge-line" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::BottomCentre)
+    ],
+});
+
+// lanes without their own texture use the one shared by every lane of the same type (see `LaneType`)
+def_const_type_enum!(pub Shared => StringPattern {
+    RECEPTOR_UP => "key/shared/{type}/key" [AssetAttribute::Texture],
+    RECEPTOR_DOWN => "key/shared/{type}/key-pressed" [AssetAttribute::Texture],
+    NOTE => "key/shared/{type}/note" [AssetAttribute::Texture],
+    HOLD_HEAD => "key/shared/{type}/hold-head" [AssetAttribute::Texture],
+    HOLD_BODY => "key/shared/{type}/hold-body" [AssetAttribute::Texture],
+    HOLD_TAIL => "key/shared/{type}/hold-tail" [AssetAttribute::Texture],
+});
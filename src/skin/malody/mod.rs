@@ -0,0 +1,9 @@
+pub mod config;
+pub mod conf_json;
+pub mod skin;
+pub mod static_assets;
+pub mod dynamic_assets;
+
+pub use conf_json::ConfJson;
+pub use config::Keymode;
+pub use skin::MalodySkin;
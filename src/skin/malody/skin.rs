@@ -0,0 +1,119 @@
+use merge::Merge;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::StringPattern;
+use crate::common::traits::{ManiaSkin, ManiaSkinConfig};
+use crate::common::vector::Vector2;
+use crate::converting::malody::{from_generic_mania, to_generic_mania};
+use crate::malody::{ConfJson, Keymode};
+use crate::sample::SampleStore;
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::ConversionReport;
+use crate::skin::generic::GenericManiaSkin;
+use crate::texture::TextureStore;
+use crate::traits::SkinConfig;
+use crate::utils::malody::MalodyDimensions;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Merge)]
+pub struct MalodySkin {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub resolution: Vector2<u32>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub conf_json: ConfJson,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub textures: TextureStore,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub samples: SampleStore
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl MalodySkin {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new(conf_json: ConfJson, textures: Option<TextureStore>, samples: Option<SampleStore>) -> Self {
+        Self { conf_json,
+            textures: textures.unwrap_or_default(),
+            samples: samples.unwrap_or_default(),
+            resolution: Vector2::new(MalodyDimensions::X.as_u32(), MalodyDimensions::Y.as_u32())
+        }
+    }
+}
+
+impl<'a> ManiaSkin<'a> for MalodySkin {
+    type Keymode = Keymode;
+    type ToParams = ();
+    type FromReturn = Self;
+
+    fn to_generic_mania_with(&self, _params: Self::ToParams, options: &ConversionOptions) -> Result<(GenericManiaSkin, ConversionReport), ConvertError> {
+        let mut report = ConversionReport::new();
+        let skin = to_generic_mania(self, options, &mut report)?;
+        Ok((skin, report))
+    }
+
+    fn from_generic_mania_with(skin: &GenericManiaSkin, options: &ConversionOptions) -> Result<(Self::FromReturn, ConversionReport), ConvertError> {
+        let mut report = ConversionReport::new();
+        let skin = from_generic_mania(skin, options, &mut report)?;
+        Ok((skin, report))
+    }
+
+    fn get_keymode(&self, keymode: u8) -> Option<&Keymode> {
+        self.conf_json.get_keymode(keymode)
+    }
+
+    fn get_required_texture_paths(&self) -> Vec<StringPattern> {
+        self.conf_json.get_required_texture_paths()
+    }
+
+    fn get_required_sample_paths(&self) -> Vec<StringPattern> {
+        self.conf_json.get_required_sample_paths()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+impl MalodySkin {
+    #[wasm_bindgen(js_name = toGenericMania)]
+    pub fn to_generic_mania_wasm(&self, options: Option<ConversionOptions>) -> Result<GenericManiaSkin, JsValue> {
+        self.to_generic_mania_with_options((), &options.unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    #[wasm_bindgen(js_name = fromGenericMania)]
+    pub fn from_generic_mania_wasm(skin: &GenericManiaSkin, options: Option<ConversionOptions>) -> Result<MalodySkin, JsValue> {
+        Self::from_generic_mania_with_options(skin, &options.unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// `[skin, report]`
+    #[wasm_bindgen(js_name = toGenericManiaWithReport)]
+    pub fn to_generic_mania_with_report_wasm(&self, options: Option<ConversionOptions>) -> Result<js_sys::Array, JsValue> {
+        self.to_generic_mania_with((), &options.unwrap_or_default())
+            .map(|(skin, report)| crate::utils::wasm::with_report(skin, report))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// `[skin, report]`
+    #[wasm_bindgen(js_name = fromGenericManiaWithReport)]
+    pub fn from_generic_mania_with_report_wasm(skin: &GenericManiaSkin, options: Option<ConversionOptions>) -> Result<js_sys::Array, JsValue> {
+        Self::from_generic_mania_with(skin, &options.unwrap_or_default())
+            .map(|(skin, report)| crate::utils::wasm::with_report(skin, report))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    #[wasm_bindgen(js_name = getKeymode)]
+    pub fn get_keymode_wasm(&self, keymode: u8) -> Option<Keymode> {
+        self.get_keymode(keymode).cloned()
+    }
+
+    #[wasm_bindgen(js_name = getRequiredTexturePaths)]
+    pub fn get_required_texture_paths_wasm(&self) -> Vec<String> {
+        self.get_required_texture_paths().into_iter().map(|p| p.to_string()).collect()
+    }
+
+    #[wasm_bindgen(js_name = getRequiredSamplePaths)]
+    pub fn get_required_sample_paths_wasm(&self) -> Vec<String> {
+        self.get_required_sample_paths().into_iter().map(|p| p.to_string()).collect()
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{common::{alignment::Anchor, skin::AssetAttribute}, def_const_type_enum};
+
+def_const_type_enum!(pub Judgement => StringPattern {
+    BEST => "judge/best" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    COOL => "judge/cool" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    GOOD => "judge/good" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+    MISS => "judge/miss" [
+        AssetAttribute::Texture,
+        AssetAttribute::Alignment(Anchor::Centre)
+    ],
+});
+
+def_const_type_enum!(pub Health => StringPattern {
+    BACKGROUND => "hud/hp-bg" [AssetAttribute::Texture],
+    FOREGROUND => "hud/hp-fg" [AssetAttribute::Texture],
+});
+
+def_const_type_enum!(pub Samples => StringPattern {
+    HIT => "sound/hit" [AssetAttribute::Sample],
+    MISS => "sound/miss" [AssetAttribute::Sample],
+    FAIL => "sound/fail" [AssetAttribute::Sample],
+    RESTART => "sound/restart" [AssetAttribute::Sample],
+    UI_CLICK => "sound/click" [AssetAttribute::Sample],
+    UI_BACK => "sound/back" [AssetAttribute::Sample],
+});
@@ -1,4 +1,5 @@
 pub mod generic;
 pub mod osu;
 pub mod fluxis;
-pub mod quaver;
\ No newline at end of file
+pub mod quaver;
+pub mod etterna;pub mod malody;
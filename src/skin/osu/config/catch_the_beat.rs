@@ -0,0 +1,85 @@
+use merge::Merge;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::common::color::Rgba;
+use crate::skin::osu::Colours;
+use crate::utils::serde::{add_key_value, parse_key_value, strip_inline_comment};
+use crate::error::{ParseDiagnostics, ParseError};
+use rgskin_derive::merge_for_all;
+
+/// osu! draws hyperdash fruits and after images in `HyperDash` unless they are set
+#[merge_for_all(strategy = crate::utils::merge::any::overwrite)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Default, Merge)]
+pub struct CatchTheBeat {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub hyper_dash: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub hyper_dash_fruit: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub hyper_dash_after_image: Option<Rgba>,
+}
+
+impl CatchTheBeat {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
+        let mut catch = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with("//") || line.starts_with('[') {
+                continue;
+            }
+
+            let (key_str, value_str) = parse_key_value(line).unwrap_or_default();
+            let value_str = strip_inline_comment(value_str);
+
+            match key_str {
+                "HyperDash" => catch.hyper_dash = Colours::parse_optional(key_str, value_str, catch.hyper_dash, diag)?,
+                "HyperDashFruit" => catch.hyper_dash_fruit = Colours::parse_optional(key_str, value_str, catch.hyper_dash_fruit, diag)?,
+                "HyperDashAfterImage" => {
+                    catch.hyper_dash_after_image = Colours::parse_optional(key_str, value_str, catch.hyper_dash_after_image, diag)?
+                }
+                _ => diag.unknown_key(key_str, value_str)?,
+            }
+        }
+
+        Ok(catch)
+    }
+
+    pub fn to_string(&self) -> String {
+        let mut result = String::new();
+
+        let colors = [
+            ("HyperDash", &self.hyper_dash),
+            ("HyperDashFruit", &self.hyper_dash_fruit),
+            ("HyperDashAfterImage", &self.hyper_dash_after_image),
+        ];
+
+        for (key, color) in colors {
+            if let Some(color) = color {
+                add_key_value(&mut result, key, ": ", &Colours::serialize_color(color), "\n");
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl CatchTheBeat {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
+    pub fn wasm_from_str(content: &str) -> Result<CatchTheBeat, ParseError> {
+        Self::from_str(content)
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
+    pub fn wasm_to_string(&self) -> String {
+        self.to_string()
+    }
+}
@@ -0,0 +1,139 @@
+use merge::Merge;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::common::color::Rgba;
+use crate::utils::serde::{add_key_value, parse_key_value, strip_inline_comment};
+use crate::error::{ParseDiagnostics, ParseError};
+use rgskin_derive::merge_for_all;
+
+/// colours that are left out (`None`) fall back to whatever osu! uses by default
+#[merge_for_all(strategy = crate::utils::merge::any::overwrite)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Default, Merge)]
+pub struct Colours {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    /// `Combo1` - `Combo8` by index, only the ones the skin sets
+    pub combos: [Option<Rgba>; Colours::MAX_COMBOS],
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub input_overlay_text: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub menu_glow: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub slider_ball: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub slider_border: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub slider_track_override: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub song_select_active_text: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub song_select_inactive_text: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub spinner_background: Option<Rgba>,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    pub star_break_additive: Option<Rgba>,
+}
+
+impl Colours {
+    pub const MAX_COMBOS: usize = 8;
+
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
+        let mut colours = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with("//") || line.starts_with('[') {
+                continue;
+            }
+
+            let (key_str, value_str) = parse_key_value(line).unwrap_or_default();
+            let value_str = strip_inline_comment(value_str);
+            let mut optional = |current: Option<Rgba>| Self::parse_optional(key_str, value_str, current, diag);
+
+            match key_str {
+                "InputOverlayText" => colours.input_overlay_text = optional(colours.input_overlay_text)?,
+                "MenuGlow" => colours.menu_glow = optional(colours.menu_glow)?,
+                "SliderBall" => colours.slider_ball = optional(colours.slider_ball)?,
+                "SliderBorder" => colours.slider_border = optional(colours.slider_border)?,
+                "SliderTrackOverride" => colours.slider_track_override = optional(colours.slider_track_override)?,
+                "SongSelectActiveText" => colours.song_select_active_text = optional(colours.song_select_active_text)?,
+                "SongSelectInactiveText" => colours.song_select_inactive_text = optional(colours.song_select_inactive_text)?,
+                "SpinnerBackground" => colours.spinner_background = optional(colours.spinner_background)?,
+                "StarBreakAdditive" => colours.star_break_additive = optional(colours.star_break_additive)?,
+                _ if key_str.starts_with("Combo") => {
+                    let Some(index) = Self::parse_combo_index(key_str) else {
+                        diag.report(ParseError::out_of_range(key_str, value_str, "Combo1-Combo8"))?;
+                        continue;
+                    };
+                    colours.combos[index] = Self::parse_optional(key_str, value_str, colours.combos[index], diag)?;
+                }
+                _ => diag.unknown_key(key_str, value_str)?,
+            }
+        }
+
+        Ok(colours)
+    }
+
+    pub fn to_string(&self) -> String {
+        let mut result = String::new();
+
+        for (i, color) in self.combos.iter().enumerate() {
+            let Some(color) = color else { continue };
+            add_key_value(&mut result, &format!("Combo{}", i + 1), ": ", &Self::serialize_color(color), "\n");
+        }
+
+        let optional = [
+            ("InputOverlayText", &self.input_overlay_text),
+            ("MenuGlow", &self.menu_glow),
+            ("SliderBall", &self.slider_ball),
+            ("SliderBorder", &self.slider_border),
+            ("SliderTrackOverride", &self.slider_track_override),
+            ("SongSelectActiveText", &self.song_select_active_text),
+            ("SongSelectInactiveText", &self.song_select_inactive_text),
+            ("SpinnerBackground", &self.spinner_background),
+            ("StarBreakAdditive", &self.star_break_additive),
+        ];
+
+        for (key, color) in optional {
+            if let Some(color) = color {
+                add_key_value(&mut result, key, ": ", &Self::serialize_color(color), "\n");
+            }
+        }
+
+        result
+    }
+
+    /// `Combo1` - `Combo8`, returned zero based
+    fn parse_combo_index(key: &str) -> Option<usize> {
+        let index: usize = key.strip_prefix("Combo")?.parse().ok()?;
+        (1..=Self::MAX_COMBOS).contains(&index).then(|| index - 1)
+    }
+
+    /// a colour that is not understood keeps whatever was there before
+    pub(crate) fn parse_optional(key: &str, value: &str, current: Option<Rgba>, diag: &mut ParseDiagnostics) -> Result<Option<Rgba>, ParseError> {
+        diag.accept(key, value, Rgba::from_str(value).map(Some), "colour", current)
+    }
+
+    pub(crate) fn serialize_color(color: &Rgba) -> String {
+        if color.alpha == 255 { color.to_str_rgb() } else { color.to_str() }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl Colours {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
+    pub fn wasm_from_str(content: &str) -> Result<Colours, ParseError> {
+        Self::from_str(content)
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
+    pub fn wasm_to_string(&self) -> String {
+        self.to_string()
+    }
+}
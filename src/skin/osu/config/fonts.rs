@@ -0,0 +1,111 @@
+use merge::Merge;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::utils::serde::{add_key_value, parse_key_value, strip_inline_comment};
+use crate::error::{ParseDiagnostics, ParseError};
+use rgskin_derive::merge_for_all;
+
+#[merge_for_all(strategy = crate::utils::merge::any::overwrite)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug, Merge)]
+pub struct Fonts {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub hit_circle_prefix: String,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub hit_circle_overlap: i32,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub score_prefix: String,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub score_overlap: i32,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub combo_prefix: String,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub combo_overlap: i32,
+}
+
+impl Default for Fonts {
+    fn default() -> Self {
+        Self {
+            hit_circle_prefix: "default".to_string(),
+            hit_circle_overlap: -2,
+            score_prefix: "score".to_string(),
+            score_overlap: 0,
+            combo_prefix: "score".to_string(),
+            combo_overlap: 0,
+        }
+    }
+}
+
+impl Fonts {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
+        let mut fonts = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with("//") || line.starts_with('[') {
+                continue;
+            }
+
+            let (key_str, value_str) = parse_key_value(line).unwrap_or_default();
+            let value_str = strip_inline_comment(value_str);
+
+            match key_str {
+                "HitCirclePrefix" => fonts.hit_circle_prefix = value_str.to_string(),
+                "HitCircleOverlap" => fonts.hit_circle_overlap = diag.number(key_str, value_str, fonts.hit_circle_overlap)?,
+                "ScorePrefix" => fonts.score_prefix = value_str.to_string(),
+                "ScoreOverlap" => fonts.score_overlap = diag.number(key_str, value_str, fonts.score_overlap)?,
+                "ComboPrefix" => fonts.combo_prefix = value_str.to_string(),
+                "ComboOverlap" => fonts.combo_overlap = diag.number(key_str, value_str, fonts.combo_overlap)?,
+                _ => diag.unknown_key(key_str, value_str)?,
+            }
+        }
+
+        Ok(fonts)
+    }
+
+    /// only the keys that differ from osu!'s defaults, so a skin without custom fonts has no `[Fonts]` section
+    pub fn to_string(&self) -> String {
+        let mut result = String::new();
+        let default = Self::default();
+
+        if self.hit_circle_prefix != default.hit_circle_prefix {
+            add_key_value(&mut result, "HitCirclePrefix", ": ", &self.hit_circle_prefix, "\n");
+        }
+        if self.hit_circle_overlap != default.hit_circle_overlap {
+            add_key_value(&mut result, "HitCircleOverlap", ": ", &self.hit_circle_overlap.to_string(), "\n");
+        }
+        if self.score_prefix != default.score_prefix {
+            add_key_value(&mut result, "ScorePrefix", ": ", &self.score_prefix, "\n");
+        }
+        if self.score_overlap != default.score_overlap {
+            add_key_value(&mut result, "ScoreOverlap", ": ", &self.score_overlap.to_string(), "\n");
+        }
+        if self.combo_prefix != default.combo_prefix {
+            add_key_value(&mut result, "ComboPrefix", ": ", &self.combo_prefix, "\n");
+        }
+        if self.combo_overlap != default.combo_overlap {
+            add_key_value(&mut result, "ComboOverlap", ": ", &self.combo_overlap.to_string(), "\n");
+        }
+
+        result
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl Fonts {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
+    pub fn wasm_from_str(content: &str) -> Result<Fonts, ParseError> {
+        Self::from_str(content)
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
+    pub fn wasm_to_string(&self) -> String {
+        self.to_string()
+    }
+}
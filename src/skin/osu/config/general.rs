@@ -3,8 +3,9 @@ use merge::Merge;
 use wasm_bindgen::prelude::*;
 
 use crate::utils::serde::{
-    add_key_value, parse_bool, parse_key_value, parse_u16_list, serialize_bool, serialize_u16_slice
+    add_key_value, parse_key_value, parse_u16_list, try_parse_list, serialize_bool, serialize_u16_slice
 };
+use crate::error::{ParseDiagnostics, ParseError};
 use rgskin_derive::merge_for_all;
 
 #[merge_for_all(strategy = crate::utils::merge::any::overwrite)]
@@ -75,7 +76,11 @@ impl Default for General {
 }
 
 impl General {
-    pub fn from_str(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
         let mut general = Self::default();
 
         for line in content.lines() {
@@ -91,23 +96,25 @@ impl General {
                 "Name" => general.name = value_str.to_string(),
                 "Author" => general.author = value_str.to_string(),
                 "Version" => general.version = value_str.to_string(),
-                "AnimationFramerate" => general.animation_framerate = value_str.parse()?,
-                "AllowSliderBallTint" => general.allow_slider_ball_tint = parse_bool(value_str),
-                "ComboBurstRandom" => general.combo_burst_random = parse_bool(value_str),
-                "CursorCentre" => general.cursor_centre = parse_bool(value_str),
-                "CursorExpand" => general.cursor_expand = parse_bool(value_str),
-                "CursorRotate" => general.cursor_rotate = parse_bool(value_str),
-                "CursorTrailRotate" => general.cursor_trail_rotate = parse_bool(value_str),
-                "CustomComboBurstSounds" => general.custom_combo_burst_sounds = parse_u16_list(value_str),
+                "AnimationFramerate" => general.animation_framerate = diag.number(key_str, value_str, general.animation_framerate)?,
+                "AllowSliderBallTint" => general.allow_slider_ball_tint = diag.bool(key_str, value_str, general.allow_slider_ball_tint)?,
+                "ComboBurstRandom" => general.combo_burst_random = diag.bool(key_str, value_str, general.combo_burst_random)?,
+                "CursorCentre" => general.cursor_centre = diag.bool(key_str, value_str, general.cursor_centre)?,
+                "CursorExpand" => general.cursor_expand = diag.bool(key_str, value_str, general.cursor_expand)?,
+                "CursorRotate" => general.cursor_rotate = diag.bool(key_str, value_str, general.cursor_rotate)?,
+                "CursorTrailRotate" => general.cursor_trail_rotate = diag.bool(key_str, value_str, general.cursor_trail_rotate)?,
+                "CustomComboBurstSounds" => {
+                    general.custom_combo_burst_sounds = diag.recover(try_parse_list(key_str, value_str), || parse_u16_list(value_str))?
+                },
                 "HitCircleOverlayAboveNumber" | "HitCircleOverlayAboveNumer" => {
-                    general.hit_circle_overlay_above_number = parse_bool(value_str)
+                    general.hit_circle_overlay_above_number = diag.bool(key_str, value_str, general.hit_circle_overlay_above_number)?
                 },
-                "LayeredHitSounds" => general.layered_hit_sounds = parse_bool(value_str),
-                "SliderBallFlip" => general.slider_ball_flip = parse_bool(value_str),
-                "SpinnerFadePlayfield" => general.spinner_fade_playfield = parse_bool(value_str),
-                "SpinnerFrequencyModulate" => general.spinner_frequency_modulate = parse_bool(value_str),
-                "SpinnerNoBlink" => general.spinner_no_blink = parse_bool(value_str),
-                _ => { }
+                "LayeredHitSounds" => general.layered_hit_sounds = diag.bool(key_str, value_str, general.layered_hit_sounds)?,
+                "SliderBallFlip" => general.slider_ball_flip = diag.bool(key_str, value_str, general.slider_ball_flip)?,
+                "SpinnerFadePlayfield" => general.spinner_fade_playfield = diag.bool(key_str, value_str, general.spinner_fade_playfield)?,
+                "SpinnerFrequencyModulate" => general.spinner_frequency_modulate = diag.bool(key_str, value_str, general.spinner_frequency_modulate)?,
+                "SpinnerNoBlink" => general.spinner_no_blink = diag.bool(key_str, value_str, general.spinner_no_blink)?,
+                _ => diag.unknown_key(key_str, value_str)?,
             }
         }
 
@@ -146,8 +153,8 @@ impl General {
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 impl General {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
-    pub fn wasm_from_str(content: &str) -> Result<General, String> {
-        Self::from_str(content).map_err(|e| e.to_string())
+    pub fn wasm_from_str(content: &str) -> Result<General, ParseError> {
+        Self::from_str(content)
     }
 
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
@@ -8,9 +8,10 @@ use crate::traits::{LaneFallback, KeymodeInvariant};
 use crate::utils::serde::{
     add_key_value,
     add_key_value_if_not_default,
-    parse_bool,
-    parse_key_value,
     parse_f32_list,
+    parse_key_value,
+    strip_inline_comment,
+    try_parse_list,
     serialize_bool,
     serialize_bool_vec_if_not_empty,
     serialize_f32_slice,
@@ -18,6 +19,7 @@ use crate::utils::serde::{
 };
 use crate::utils::io::{path_to_unix, path_to_win};
 use crate::common::color::Rgba;
+use crate::error::{ParseDiagnostics, ParseError};
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = OsuKeymode))]
 #[derive(Clone, Debug)]
@@ -156,8 +158,8 @@ pub struct Keymode {
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 impl Keymode {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
-    pub fn wasm_from_str(content: &str) -> Result<Keymode, String> {
-        Self::from_str(content).map_err(|e| e.to_string())
+    pub fn wasm_from_str(content: &str) -> Result<Keymode, ParseError> {
+        Self::from_str(content)
     }
 
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toStr))]
@@ -240,59 +242,96 @@ impl Default for Keymode {
 }
 
 impl Keymode {
-    fn parse_indexed_image(key: &str, value: &str, prefix: &str, suffix: &str, target: &mut Vec<String>) {
-        if let Some(index_str) = key.strip_prefix(prefix).and_then(|s| s.strip_suffix(suffix)) {
-            let numeric_part: String = index_str.chars().take_while(|c| c.is_ascii_digit()).collect();
-            if let Ok(index) = numeric_part.parse::<usize>() {
-                if index < target.len() {
-                    let no_2x = value.strip_suffix("@2x").unwrap_or(value);
-                    target[index] = no_2x.to_string();
-                }
-            }
+    fn parse_indexed_image(key: &str, value: &str, prefix: &str, suffix: &str, target: &mut [String], diag: &mut ParseDiagnostics) -> Result<(), ParseError> {
+        let Some(index) = Self::column_index(key, prefix, suffix) else {
+            return diag.unknown_key(key, value);
+        };
+
+        if index < target.len() {
+            let no_2x = value.strip_suffix("@2x").unwrap_or(value);
+            target[index] = no_2x.to_string();
+        } else {
+            diag.report(ParseError::out_of_range(key, value, &format!("a column below {}", target.len())))?;
         }
+
+        Ok(())
     }
 
-    fn parse_indexed_bool(key: &str, value: &str, prefix: &str, suffix: &str, target: &mut Vec<bool>) {
-        if let Some(index_str) = key.strip_prefix(prefix).and_then(|s| s.strip_suffix(suffix)) {
-            let numeric_part: String = index_str.chars().take_while(|c| c.is_ascii_digit()).collect();
-            if let Ok(index) = numeric_part.parse::<usize>() {
-                while target.len() <= index {
-                    target.push(false);
-                }
-                target[index] = parse_bool(value);
+    fn parse_indexed_bool(key: &str, value: &str, prefix: &str, suffix: &str, target: &mut Vec<bool>, diag: &mut ParseDiagnostics) -> Result<(), ParseError> {
+        let Some(index) = Self::column_index(key, prefix, suffix) else {
+            return diag.unknown_key(key, value);
+        };
+
+        while target.len() <= index {
+            target.push(false);
+        }
+        target[index] = diag.bool(key, value, target[index])?;
+
+        Ok(())
+    }
+
+    fn parse_indexed_u8(key: &str, value: &str, prefix: &str, suffix: &str, target: &mut Vec<u8>, diag: &mut ParseDiagnostics) -> Result<(), ParseError> {
+        let Some(index) = Self::column_index(key, prefix, suffix) else {
+            return diag.unknown_key(key, value);
+        };
+
+        if let Some(val) = diag.accept(key, value, value.parse::<u8>().map(Some), "number", None)? {
+            while target.len() <= index {
+                target.push(0);
             }
+            target[index] = val;
         }
+
+        Ok(())
     }
 
-    fn parse_indexed_u8(key: &str, value: &str, prefix: &str, suffix: &str, target: &mut Vec<u8>) {
-        if let Some(index_str) = key.strip_prefix(prefix).and_then(|s| s.strip_suffix(suffix)) {
-            let numeric_part: String = index_str.chars().take_while(|c| c.is_ascii_digit()).collect();
-            if let Ok(index) = numeric_part.parse::<usize>() {
-                if let Ok(val) = value.parse::<u8>() {
-                    while target.len() <= index {
-                        target.push(0);
-                    }
-                    target[index] = val;
-                }
+    fn parse_indexed_color(key: &str, value: &str, prefix: &str, target: &mut Vec<Rgba>, diag: &mut ParseDiagnostics) -> Result<(), ParseError> {
+        let index = match Self::column_index(key, prefix, "") {
+            Some(index) if index > 0 => index,
+            Some(_) => return diag.report(ParseError::out_of_range(key, value, &format!("{prefix}1 or above"))),
+            None => return diag.unknown_key(key, value),
+        };
+
+        if let Some(color) = diag.accept(key, value, Rgba::from_str(value).map(Some), "colour", None)? {
+            let array_index = index - 1;
+            while target.len() <= array_index {
+                target.push(Rgba { red: 0, green: 0, blue: 0, alpha: 255 });
             }
+            target[array_index] = color;
         }
+
+        Ok(())
     }
 
-    fn parse_indexed_color(key: &str, value: &str, prefix: &str, target: &mut Vec<Rgba>) {
-        if let Some(index_str) = key.strip_prefix(prefix) {
-            let numeric_part: String = index_str.chars().take_while(|c| c.is_ascii_digit()).collect();
-            if let Ok(index) = numeric_part.parse::<usize>() {
-                if index > 0 {
-                    let array_index = index - 1;
-                    if let Ok(color) = Rgba::from_str(value) {
-                        while target.len() <= array_index {
-                            target.push(Rgba { red: 0, green: 0, blue: 0, alpha: 255 });
-                        }
-                        target[array_index] = color;
-                    }
-                }
+    /// `KeyImage2D` -> 2
+    fn column_index(key: &str, prefix: &str, suffix: &str) -> Option<usize> {
+        let index_str = key.strip_prefix(prefix).and_then(|s| s.strip_suffix(suffix))?;
+        let numeric_part: String = index_str.chars().take_while(|c| c.is_ascii_digit()).collect();
+        numeric_part.parse().ok()
+    }
+
+    /// osu! quietly uses its defaults for columns a list is missing, which is rarely what was meant
+    fn check_list_lengths(&self, lists: &[(&str, &str)], diag: &mut ParseDiagnostics) -> Result<(), ParseError> {
+        let keys = self.keymode as usize;
+        if keys == 0 {
+            return Ok(());
+        }
+
+        for &(key, value) in lists {
+            let expected: &[usize] = match key {
+                "ColumnLineWidth" => &[keys + 1],
+                // osu! wants one less, one per column is what gets written back
+                "ColumnSpacing" => &[keys - 1, keys],
+                _ => &[keys],
+            };
+            let len = value.trim_end_matches(',').split(',').count();
+
+            if !expected.contains(&len) {
+                diag.report(ParseError::wrong_list_length(key, value, expected[0], self.keymode))?;
             }
         }
+
+        Ok(())
     }
 
     fn alloc_vecs(&mut self) {
@@ -310,8 +349,13 @@ impl Keymode {
         self.colour_lights = vec![Rgba { red: 55, green: 255, blue: 255, alpha: 255 }; key_count];
     }
 
-    pub fn from_str(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
         let mut keymode = Self::default();
+        let mut lists = Vec::new();
 
         for line in content.lines() {
             let line = line.trim();
@@ -321,74 +365,68 @@ impl Keymode {
             }
 
             let (key_str, value_str) = parse_key_value(line).unwrap_or_default();
+            let value_str = strip_inline_comment(value_str);
 
             match key_str {
                 "Keys" => {
-                    keymode.keymode = value_str.parse()?;
+                    keymode.keymode = diag.number_in(key_str, value_str, 1..=18, keymode.keymode)?;
                     keymode.alloc_vecs();
                 }
-                "KeysUnderNotes" => keymode.keys_under_notes = parse_bool(value_str),
-                "JudgementLine" => keymode.judgement_line = parse_bool(value_str),
-                "UpsideDown" => keymode.upside_down = parse_bool(value_str),
-                "SpecialStyle" => keymode.special_style = value_str.parse().unwrap_or(0),
+                "KeysUnderNotes" => keymode.keys_under_notes = diag.bool(key_str, value_str, keymode.keys_under_notes)?,
+                "JudgementLine" => keymode.judgement_line = diag.bool(key_str, value_str, keymode.judgement_line)?,
+                "UpsideDown" => keymode.upside_down = diag.bool(key_str, value_str, keymode.upside_down)?,
+                "SpecialStyle" => keymode.special_style = diag.number_in(key_str, value_str, 0..=2, keymode.special_style)?,
                 "ComboBurstStyle" => {
                     keymode.combo_burst_style = match value_str.to_lowercase().as_str() {
                         "left" => 0,
                         "right" => 1,
                         "both" => 2,
-                        _ => value_str.parse().unwrap_or(1),
+                        _ => diag.number_in(key_str, value_str, 0..=2, keymode.combo_burst_style)?,
                     }
                 }
-                "SplitStages" => keymode.split_stages = Some(parse_bool(value_str)),
-                "StageSeparation" => keymode.stage_separation = value_str.parse().unwrap_or(40.0),
-                "SeparateScore" => keymode.separate_score = parse_bool(value_str),
-                "HitPosition" => keymode.hit_position = value_str.parse()?,
-                "LightPosition" => keymode.light_position = value_str.parse().unwrap_or(413),
-                "ScorePosition" => keymode.score_position = Some(value_str.parse()?),
-                "ComboPosition" => keymode.combo_position = Some(value_str.parse()?),
-                "ColumnStart" => keymode.column_start = value_str.parse()?,
-                "ColumnRight" => keymode.column_right = value_str.parse()?,
-                "ColumnLineWidth" => keymode.column_line_width = parse_f32_list(value_str),
-                "ColumnWidth" => keymode.column_width = parse_f32_list(value_str),
-                "ColumnSpacing" => keymode.column_spacing = parse_f32_list(value_str),
-                "BarlineHeight" => keymode.barline_height = value_str.parse().unwrap_or(1.2),
-                "LightingNWidth" => keymode.lighting_n_width = parse_f32_list(value_str),
-                "LightingLWidth" => keymode.lighting_l_width = parse_f32_list(value_str),
-                "WidthForNoteHeightScale" => keymode.width_for_note_height_scale = Some(value_str.parse()?),
-                "LightFramePerSecond" => keymode.light_frame_per_second = value_str.parse().unwrap_or(24),
-                "KeyFlipWhenUpsideDown" => keymode.key_flip_when_upside_down = parse_bool(value_str),
-                "NoteFlipWhenUpsideDown" => keymode.note_flip_when_upside_down = parse_bool(value_str),
-                "NoteBodyStyle" => keymode.note_body_style = value_str.parse().unwrap_or(1),
-                "ColourColumnLine" => {
-                    if let Ok(color) = Rgba::from_str(value_str) {
-                        keymode.colour_column_line = color;
-                    }
+                "SplitStages" => keymode.split_stages = Some(diag.bool(key_str, value_str, keymode.split_stages.unwrap_or_default())?),
+                "StageSeparation" => keymode.stage_separation = diag.number(key_str, value_str, keymode.stage_separation)?,
+                "SeparateScore" => keymode.separate_score = diag.bool(key_str, value_str, keymode.separate_score)?,
+                "HitPosition" => keymode.hit_position = diag.number(key_str, value_str, keymode.hit_position)?,
+                "LightPosition" => keymode.light_position = diag.number(key_str, value_str, keymode.light_position)?,
+                "ScorePosition" => keymode.score_position = diag.accept(key_str, value_str, value_str.parse().map(Some), "number", keymode.score_position)?,
+                "ComboPosition" => keymode.combo_position = diag.accept(key_str, value_str, value_str.parse().map(Some), "number", keymode.combo_position)?,
+                "ColumnStart" => keymode.column_start = diag.number(key_str, value_str, keymode.column_start)?,
+                "ColumnRight" => keymode.column_right = diag.number(key_str, value_str, keymode.column_right)?,
+                "ColumnLineWidth" => {
+                    keymode.column_line_width = diag.recover(try_parse_list(key_str, value_str), || parse_f32_list(value_str))?;
+                    lists.push((key_str, value_str));
                 }
-                "ColourBarline" => {
-                    if let Ok(color) = Rgba::from_str(value_str) {
-                        keymode.colour_barline = color;
-                    }
+                "ColumnWidth" => {
+                    keymode.column_width = diag.recover(try_parse_list(key_str, value_str), || parse_f32_list(value_str))?;
+                    lists.push((key_str, value_str));
                 }
-                "ColourJudgementLine" => {
-                    if let Ok(color) = Rgba::from_str(value_str) {
-                        keymode.colour_judgement_line = color;
-                    }
+                "ColumnSpacing" => {
+                    keymode.column_spacing = diag.recover(try_parse_list(key_str, value_str), || parse_f32_list(value_str))?;
+                    lists.push((key_str, value_str));
                 }
-                "ColourKeyWarning" => {
-                    if let Ok(color) = Rgba::from_str(value_str) {
-                        keymode.colour_key_warning = color;
-                    }
+                "BarlineHeight" => keymode.barline_height = diag.number(key_str, value_str, keymode.barline_height)?,
+                "LightingNWidth" => {
+                    keymode.lighting_n_width = diag.recover(try_parse_list(key_str, value_str), || parse_f32_list(value_str))?;
+                    lists.push((key_str, value_str));
                 }
-                "ColourHold" => {
-                    if let Ok(color) = Rgba::from_str(value_str) {
-                        keymode.colour_hold = color;
-                    }
+                "LightingLWidth" => {
+                    keymode.lighting_l_width = diag.recover(try_parse_list(key_str, value_str), || parse_f32_list(value_str))?;
+                    lists.push((key_str, value_str));
                 }
-                "ColourBreak" => {
-                    if let Ok(color) = Rgba::from_str(value_str) {
-                        keymode.colour_break = color;
-                    }
+                "WidthForNoteHeightScale" => {
+                    keymode.width_for_note_height_scale = diag.accept(key_str, value_str, value_str.parse().map(Some), "number", keymode.width_for_note_height_scale)?
                 }
+                "LightFramePerSecond" => keymode.light_frame_per_second = diag.number(key_str, value_str, keymode.light_frame_per_second)?,
+                "KeyFlipWhenUpsideDown" => keymode.key_flip_when_upside_down = diag.bool(key_str, value_str, keymode.key_flip_when_upside_down)?,
+                "NoteFlipWhenUpsideDown" => keymode.note_flip_when_upside_down = diag.bool(key_str, value_str, keymode.note_flip_when_upside_down)?,
+                "NoteBodyStyle" => keymode.note_body_style = diag.number_in(key_str, value_str, 0..=2, keymode.note_body_style)?,
+                "ColourColumnLine" => keymode.colour_column_line = diag.colour(key_str, value_str, keymode.colour_column_line)?,
+                "ColourBarline" => keymode.colour_barline = diag.colour(key_str, value_str, keymode.colour_barline)?,
+                "ColourJudgementLine" => keymode.colour_judgement_line = diag.colour(key_str, value_str, keymode.colour_judgement_line)?,
+                "ColourKeyWarning" => keymode.colour_key_warning = diag.colour(key_str, value_str, keymode.colour_key_warning)?,
+                "ColourHold" => keymode.colour_hold = diag.colour(key_str, value_str, keymode.colour_hold)?,
+                "ColourBreak" => keymode.colour_break = diag.colour(key_str, value_str, keymode.colour_break)?,
                 "StageLeft" => keymode.stage_left = path_to_unix(&value_str.to_string()),
                 "StageRight" => keymode.stage_right = path_to_unix(&value_str.to_string()),
                 "StageBottom" => keymode.stage_bottom = path_to_unix(&value_str.to_string()),
@@ -406,47 +444,51 @@ impl Keymode {
                 _ => {
                     if key_str.starts_with("KeyImage") {
                         if key_str.ends_with("D") {
-                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "KeyImage", "D", &mut keymode.receptor_images_down);
+                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "KeyImage", "D", &mut keymode.receptor_images_down, diag)?;
                         } else {
-                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "KeyImage", "", &mut keymode.receptor_images);
+                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "KeyImage", "", &mut keymode.receptor_images, diag)?;
                         }
                     } else if key_str.starts_with("NoteImage") {
                         if key_str.contains("H") {
-                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "NoteImage", "H", &mut keymode.long_note_head_images);
+                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "NoteImage", "H", &mut keymode.long_note_head_images, diag)?;
                         } else if key_str.contains("L") {
-                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "NoteImage", "L", &mut keymode.long_note_body_images);
+                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "NoteImage", "L", &mut keymode.long_note_body_images, diag)?;
                         } else if key_str.contains("T") {
-                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "NoteImage", "T", &mut keymode.long_note_tail_images);
+                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "NoteImage", "T", &mut keymode.long_note_tail_images, diag)?;
                         } else {
-                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "NoteImage", "", &mut keymode.normal_note_images);
+                            Self::parse_indexed_image(key_str, &path_to_unix(value_str), "NoteImage", "", &mut keymode.normal_note_images, diag)?;
                         }
                     } else if key_str.starts_with("Colour") && !key_str.contains("Light") {
-                        Self::parse_indexed_color(key_str, value_str, "Colour", &mut keymode.colours);
+                        Self::parse_indexed_color(key_str, value_str, "Colour", &mut keymode.colours, diag)?;
                     } else if key_str.starts_with("ColourLight") {
-                        Self::parse_indexed_color(key_str, value_str, "ColourLight", &mut keymode.colour_lights);
+                        Self::parse_indexed_color(key_str, value_str, "ColourLight", &mut keymode.colour_lights, diag)?;
                     } else if key_str.starts_with("KeyFlipWhenUpsideDown") {
                         if key_str.ends_with("D") {
-                            Self::parse_indexed_bool(key_str, value_str, "KeyFlipWhenUpsideDown", "D", &mut keymode.key_flip_when_upside_down_down_columns);
+                            Self::parse_indexed_bool(key_str, value_str, "KeyFlipWhenUpsideDown", "D", &mut keymode.key_flip_when_upside_down_down_columns, diag)?;
                         } else {
-                            Self::parse_indexed_bool(key_str, value_str, "KeyFlipWhenUpsideDown", "", &mut keymode.key_flip_when_upside_down_columns);
+                            Self::parse_indexed_bool(key_str, value_str, "KeyFlipWhenUpsideDown", "", &mut keymode.key_flip_when_upside_down_columns, diag)?;
                         }
                     } else if key_str.starts_with("NoteFlipWhenUpsideDown") {
                         if key_str.contains("H") {
-                            Self::parse_indexed_bool(key_str, value_str, "NoteFlipWhenUpsideDown", "H", &mut keymode.note_flip_when_upside_down_h_columns);
+                            Self::parse_indexed_bool(key_str, value_str, "NoteFlipWhenUpsideDown", "H", &mut keymode.note_flip_when_upside_down_h_columns, diag)?;
                         } else if key_str.contains("L") {
-                            Self::parse_indexed_bool(key_str, value_str, "NoteFlipWhenUpsideDown", "L", &mut keymode.note_flip_when_upside_down_l_columns);
+                            Self::parse_indexed_bool(key_str, value_str, "NoteFlipWhenUpsideDown", "L", &mut keymode.note_flip_when_upside_down_l_columns, diag)?;
                         } else if key_str.contains("T") {
-                            Self::parse_indexed_bool(key_str, value_str, "NoteFlipWhenUpsideDown", "T", &mut keymode.note_flip_when_upside_down_t_columns);
+                            Self::parse_indexed_bool(key_str, value_str, "NoteFlipWhenUpsideDown", "T", &mut keymode.note_flip_when_upside_down_t_columns, diag)?;
                         } else {
-                            Self::parse_indexed_bool(key_str, value_str, "NoteFlipWhenUpsideDown", "", &mut keymode.note_flip_when_upside_down_columns);
+                            Self::parse_indexed_bool(key_str, value_str, "NoteFlipWhenUpsideDown", "", &mut keymode.note_flip_when_upside_down_columns, diag)?;
                         }
                     } else if key_str.starts_with("NoteBodyStyle") {
-                        Self::parse_indexed_u8(key_str, value_str, "NoteBodyStyle", "", &mut keymode.note_body_style_columns);
+                        Self::parse_indexed_u8(key_str, value_str, "NoteBodyStyle", "", &mut keymode.note_body_style_columns, diag)?;
+                    } else {
+                        diag.unknown_key(key_str, value_str)?;
                     }
                 }
             }
         }
 
+        keymode.check_list_lengths(&lists, diag)?;
+
         Ok(keymode)
     }
 
@@ -650,8 +692,15 @@ impl Keymode {
         insert_with_fallback(&mut result, &self.stage_hint, &static_assets::Mania::STAGE_HINT);
         insert_with_fallback(&mut result, &self.stage_bottom, &static_assets::Mania::STAGE_BOTTOM);
         
-        insert_with_fallback(&mut result, &self.lighting_n, &static_assets::Mania::LIGHTINGN);
-        insert_with_fallback(&mut result, &self.lighting_l, &static_assets::Mania::LIGHTINGL);
+        // `{path}-0`, `{path}-1`, ... when animated
+        let insert_animated = |result: &mut HashSet<String>, custom: &str, fallback: &str| {
+            let path = if custom.trim().is_empty() { fallback } else { custom };
+            result.insert(path.to_string());
+            result.insert(format!("{path}-{{frame}}"));
+        };
+
+        insert_animated(&mut result, &self.lighting_n, &static_assets::Mania::LIGHTINGN);
+        insert_animated(&mut result, &self.lighting_l, &static_assets::Mania::LIGHTINGL);
         
         insert_with_fallback(&mut result, &self.hit0, &static_assets::Mania::HIT0);
         insert_with_fallback(&mut result, &self.hit50, &static_assets::Mania::HIT50);
@@ -1,2 +1,5 @@
 pub mod keymode;
 pub mod general;
+pub mod colours;
+pub mod fonts;
+pub mod catch_the_beat;
\ No newline at end of file
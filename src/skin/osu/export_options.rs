@@ -0,0 +1,47 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::image_proc::ResizeFilter;
+
+/// which versions of each texture are written
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MipmapMode {
+    /// every texture as it is in the store
+    #[default]
+    Off,
+    /// `name@2x.png` plus `name.png`
+    HdAndSd,
+    /// only `name@2x.png`
+    HdOnly,
+}
+
+/// textures imported from `@2x` files are the HD version and get a downscaled SD one unless the import kept the original,
+/// every other texture is the SD version and gets an upscaled HD one
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OsuExportOptions {
+    pub mipmaps: MipmapMode,
+    /// used to scale the generated versions
+    pub filter: ResizeFilter,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl OsuExportOptions {
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new(mipmaps: MipmapMode, filter: ResizeFilter) -> Self {
+        Self { mipmaps, filter }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = withMipmaps))]
+    pub fn with_mipmaps(mut self, mipmaps: MipmapMode) -> Self {
+        self.mipmaps = mipmaps;
+        self
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = withFilter))]
+    pub fn with_filter(mut self, filter: ResizeFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
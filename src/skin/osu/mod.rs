@@ -3,8 +3,13 @@ mod config;
 mod skin_ini;
 pub mod static_assets;
 mod macros;
+mod export_options;
 
 pub use config::keymode::Keymode;
 pub use skin_ini::OsuSkinIni;
 pub use config::general::General;
-pub use skin::OsuSkin;
\ No newline at end of file
+pub use config::colours::Colours;
+pub use config::fonts::Fonts;
+pub use config::catch_the_beat::CatchTheBeat;
+pub use skin::OsuSkin;
+pub use export_options::{OsuExportOptions, MipmapMode};
@@ -8,6 +8,9 @@ use crate::common::vector::Vector2;
 use crate::converting::osu::{from_generic_mania, to_generic_mania};
 use crate::osu::Keymode;
 use crate::sample::SampleStore;
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::ConversionReport;
 use crate::skin::generic::GenericManiaSkin;
 use crate::skin::osu::OsuSkinIni;
 use crate::io::texture::TextureStore;
@@ -44,12 +47,16 @@ impl<'a> ManiaSkin<'a> for OsuSkin {
     type ToParams = ();
     type FromReturn = Self;
 
-    fn to_generic_mania(&self, _params: Self::ToParams) -> Result<GenericManiaSkin, Box<dyn std::error::Error>> {
-        to_generic_mania(self)
+    fn to_generic_mania_with(&self, _params: Self::ToParams, options: &ConversionOptions) -> Result<(GenericManiaSkin, ConversionReport), ConvertError> {
+        let mut report = ConversionReport::new();
+        let skin = to_generic_mania(self, options, &mut report)?;
+        Ok((skin, report))
     }
 
-    fn from_generic_mania(skin: &GenericManiaSkin) -> Result<Self::FromReturn, Box<dyn std::error::Error>> {
-        from_generic_mania(skin)
+    fn from_generic_mania_with(skin: &GenericManiaSkin, options: &ConversionOptions) -> Result<(Self::FromReturn, ConversionReport), ConvertError> {
+        let mut report = ConversionReport::new();
+        let skin = from_generic_mania(skin, options, &mut report)?;
+        Ok((skin, report))
     }
 
     fn get_keymode(&self, keymode: u8) -> Option<&Keymode> {
@@ -72,14 +79,30 @@ impl<'a> ManiaSkin<'a> for OsuSkin {
 #[wasm_bindgen]
 impl OsuSkin {
     #[wasm_bindgen(js_name = toGenericMania)]
-    pub fn to_generic_mania_wasm(&self) -> Result<GenericManiaSkin, JsValue> {
-        self.to_generic_mania(())
+    pub fn to_generic_mania_wasm(&self, options: Option<ConversionOptions>) -> Result<GenericManiaSkin, JsValue> {
+        self.to_generic_mania_with_options((), &options.unwrap_or_default())
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
     #[wasm_bindgen(js_name = fromGenericMania)]
-    pub fn from_generic_mania_wasm(skin: &GenericManiaSkin) -> Result<OsuSkin, JsValue> {
-        Self::from_generic_mania(skin)
+    pub fn from_generic_mania_wasm(skin: &GenericManiaSkin, options: Option<ConversionOptions>) -> Result<OsuSkin, JsValue> {
+        Self::from_generic_mania_with_options(skin, &options.unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// `[skin, report]`
+    #[wasm_bindgen(js_name = toGenericManiaWithReport)]
+    pub fn to_generic_mania_with_report_wasm(&self, options: Option<ConversionOptions>) -> Result<js_sys::Array, JsValue> {
+        self.to_generic_mania_with((), &options.unwrap_or_default())
+            .map(|(skin, report)| crate::utils::wasm::with_report(skin, report))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// `[skin, report]`
+    #[wasm_bindgen(js_name = fromGenericManiaWithReport)]
+    pub fn from_generic_mania_with_report_wasm(skin: &GenericManiaSkin, options: Option<ConversionOptions>) -> Result<js_sys::Array, JsValue> {
+        Self::from_generic_mania_with(skin, &options.unwrap_or_default())
+            .map(|(skin, report)| crate::utils::wasm::with_report(skin, report))
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
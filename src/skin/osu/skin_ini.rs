@@ -7,18 +7,31 @@ use wasm_bindgen::prelude::*;
 use crate::common::traits::{ManiaSkinConfig, SkinConfig};
 use crate::osu::static_assets;
 use crate::skin::osu::Keymode;
-use crate::skin::osu::General;
-use crate::ini::from_ini;
+use crate::skin::osu::{General, Colours, Fonts, CatchTheBeat};
+use crate::ini::{from_ini_with, IniSource};
+use crate::utils::serde::parse_key_value;
 use crate::{ConstTypeEnum, StringPattern, utils};
+use crate::error::{ParseDiagnostics, ParseError, ParseMode, ParseWarning};
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Clone, Debug, Default, Merge)]
 pub struct OsuSkinIni {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
     pub general: General,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub colours: Colours,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub fonts: Fonts,
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
+    pub catch_the_beat: CatchTheBeat,
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))] // TODO: maybe not a good idea to use getter_with_clone
     #[merge(strategy = utils::merge::skin::overwrite_keymode)]
-    pub keymodes: Vec<Keymode>
+    pub keymodes: Vec<Keymode>,
+    /// the file this was parsed from, `to_string` only rewrites the lines that changed since.
+    /// set to `None` to write a clean file
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    #[merge(skip)]
+    pub source: Option<IniSource>,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -30,8 +43,15 @@ impl OsuSkinIni {
     }
 
     #[wasm_bindgen(js_name = fromStr)]
-    pub fn from_str_wasm(json_str: &str) -> Result<Self, JsError> {
-        Self::from_str(json_str).map_err(|e| JsError::new(&e.to_string()))
+    pub fn from_str_wasm(json_str: &str) -> Result<Self, ParseError> {
+        Self::from_str(json_str)
+    }
+
+    /// `[skinIni, warnings]`
+    #[wasm_bindgen(js_name = fromStrWith)]
+    pub fn from_str_with_wasm(str: &str, mode: ParseMode) -> Result<js_sys::Array, ParseError> {
+        let (skin_ini, warnings) = Self::from_str_with(str, mode)?;
+        Ok(utils::wasm::with_warnings(skin_ini, warnings))
     }
 
     #[wasm_bindgen(js_name = toString)]
@@ -55,14 +75,35 @@ impl OsuSkinIni {
     }
 }
 
-impl ToString for OsuSkinIni {
-    fn to_string(&self) -> String {
+impl OsuSkinIni {
+    fn to_typed_string(&self) -> String {
         let mut result = String::new();
 
         result.push_str("[General]\n");
         result.push_str(&self.general.to_string());
         result.push('\n');
 
+        let colours = self.colours.to_string();
+        if !colours.is_empty() {
+            result.push_str("[Colours]\n");
+            result.push_str(&colours);
+            result.push('\n');
+        }
+
+        let fonts = self.fonts.to_string();
+        if !fonts.is_empty() {
+            result.push_str("[Fonts]\n");
+            result.push_str(&fonts);
+            result.push('\n');
+        }
+
+        let catch_the_beat = self.catch_the_beat.to_string();
+        if !catch_the_beat.is_empty() {
+            result.push_str("[CatchTheBeat]\n");
+            result.push_str(&catch_the_beat);
+            result.push('\n');
+        }
+
         for keymode in &self.keymodes {
             result.push_str("[Mania]\n");
             result.push_str(&keymode.to_str());
@@ -73,26 +114,72 @@ impl ToString for OsuSkinIni {
     }
 }
 
-impl FromStr for OsuSkinIni {
-    type Err = Box<dyn std::error::Error>;
+impl ToString for OsuSkinIni {
+    fn to_string(&self) -> String {
+        let generated = self.to_typed_string();
+        match &self.source {
+            Some(source) => source.render(&generated),
+            None => generated,
+        }
+    }
+}
 
-    fn from_str(str: &str) -> Result<Self, Self::Err> {
+impl OsuSkinIni {
+    /// `from_str` is lenient and drops the warnings
+    pub fn from_str_with(str: &str, mode: ParseMode) -> Result<(Self, Vec<ParseWarning>), ParseError> {
+        let mut diag = ParseDiagnostics::new(mode);
         let mut general = General::default();
+        let mut colours = Colours::default();
+        let mut fonts = Fonts::default();
+        let mut catch_the_beat = CatchTheBeat::default();
         let mut keymodes = Vec::new();
 
-        from_ini(str, |section, content| {
+        from_ini_with(str, &mut diag, |section, content, diag| {
             match section {
-                "General" => general = General::from_str(content)?,
-                "Mania" => keymodes.push(Keymode::from_str(content)?),
+                "General" => general = General::from_str_with(content, diag)?,
+                "Colours" => colours = Colours::from_str_with(content, diag)?,
+                "Fonts" => fonts = Fonts::from_str_with(content, diag)?,
+                "CatchTheBeat" => catch_the_beat = CatchTheBeat::from_str_with(content, diag)?,
+                "Mania" => {
+                    let keys = keys_in(content);
+                    let in_keymode = |e: ParseError| match keys {
+                        Some(keys) => e.in_keymode(keys),
+                        None => e,
+                    };
+
+                    let start = diag.warnings.len();
+                    let keymode = Keymode::from_str_with(content, diag).map_err(in_keymode)?;
+                    diag.map_since(start, in_keymode);
+                    keymodes.push(keymode);
+                },
                 _ => { },
             }
             Ok(())
         })?;
 
-        Ok(OsuSkinIni { general, keymodes })
+        let mut skin_ini = OsuSkinIni { general, colours, fonts, catch_the_beat, keymodes, source: None };
+        skin_ini.source = Some(IniSource::new(str, &skin_ini.to_typed_string()));
+
+        Ok((skin_ini, diag.warnings))
     }
 }
 
+impl FromStr for OsuSkinIni {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(str, ParseMode::Lenient).map(|(skin_ini, _)| skin_ini)
+    }
+}
+
+/// the `Keys` of a `[Mania]` section, so errors anywhere in it can say which keymode they belong to
+fn keys_in(content: &str) -> Option<u8> {
+    content.lines()
+        .filter_map(|line| parse_key_value(line.trim()))
+        .find(|(key, _)| *key == "Keys")
+        .and_then(|(_, value)| value.parse().ok())
+}
+
 impl SkinConfig for OsuSkinIni {
     fn get_required_texture_paths(&self) -> Vec<StringPattern> {
         let mut result = Vec::new();
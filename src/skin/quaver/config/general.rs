@@ -3,7 +3,8 @@ use merge::Merge;
 use wasm_bindgen::prelude::*;
 
 use crate::utils;
-use crate::utils::serde::{add_key_value, parse_bool, parse_key_value_eq, serialize_bool};
+use crate::utils::serde::{add_key_value, parse_key_value_eq, serialize_bool};
+use crate::error::{ParseDiagnostics, ParseError};
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = QuaGeneral))]
 #[derive(Clone, Debug, Merge)]
@@ -42,7 +43,11 @@ impl Default for General {
 }
 
 impl General {
-    pub fn from_str(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
         let mut general = Self::default();
 
         for line in content.lines() {
@@ -55,9 +60,9 @@ impl General {
                 "Name" => general.name = value_str.to_string(),
                 "Author" => general.author = value_str.to_string(),
                 "Version" => general.version = value_str.to_string(),
-                "CenterCursor" => general.center_cursor = parse_bool(value_str),
-                "UseSkinBackgrounds" => general.use_skin_backgrounds = parse_bool(value_str),
-                _ => {}
+                "CenterCursor" => general.center_cursor = diag.bool(key_str, value_str, general.center_cursor)?,
+                "UseSkinBackgrounds" => general.use_skin_backgrounds = diag.bool(key_str, value_str, general.use_skin_backgrounds)?,
+                _ => diag.unknown_key(key_str, value_str)?,
             }
         }
 
@@ -78,8 +83,8 @@ impl General {
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_class = QuaGeneral))]
 impl General {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
-    pub fn wasm_from_str(content: &str) -> Result<General, String> {
-        Self::from_str(content).map_err(|e| e.to_string())
+    pub fn wasm_from_str(content: &str) -> Result<General, ParseError> {
+        Self::from_str(content)
     }
 
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
@@ -7,12 +7,9 @@ use crate::common::color::Rgba;
 use crate::quaver::dynamic_assets;
 use crate::traits::{KeymodeInvariant, LaneFallback};
 use crate::utils::serde::{
-    add_key_value, add_key_value_if_not_default, parse_key_value_eq, serialize_bool,
+    add_key_value, add_key_value_if_not_default, parse_key_value_eq, serialize_bool, try_parse_list,
 };
-
-fn parse_bool(value: &str) -> Result<bool, std::str::ParseBoolError> {
-    value.trim().to_lowercase().parse()
-}
+use crate::error::{ParseDiagnostics, ParseError};
 
 fn parse_list<T: std::str::FromStr>(value: &str) -> Vec<T> {
     value.split(',').filter_map(|s| s.trim().parse::<T>().ok()).collect()
@@ -504,7 +501,11 @@ impl Default for Keymode {
 }
 
 impl Keymode {
-    pub fn from_str(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
         let mut km = Self::default();
 
         for line in content.lines() {
@@ -517,169 +518,169 @@ impl Keymode {
             match key_str {
                 // notes
                 "DefaultSkin" => km.default_skin = DefaultSkin::from_str(value_str),
-                "ColorObjectsBySnapDistance" => km.color_objects_by_snap_distance = parse_bool(value_str).unwrap_or(km.color_objects_by_snap_distance),
-                "UseHitObjectSheet" => km.use_hit_object_sheet = parse_bool(value_str).unwrap_or(km.use_hit_object_sheet),
-                "RotateHitObjectsByColumn" => km.rotate_hit_objects_by_column = parse_bool(value_str).unwrap_or(km.rotate_hit_objects_by_column),
-                "FlipNoteImagesOnUpscroll" => km.flip_note_images_on_upscroll = parse_bool(value_str).unwrap_or(km.flip_note_images_on_upscroll),
-                "FlipNoteEndImagesOnUpscroll" => km.flip_note_end_images_on_upscroll = parse_bool(value_str).unwrap_or(km.flip_note_end_images_on_upscroll),
-                "DeadNoteColor" => { if let Ok(c) = Rgba::from_str(value_str) { km.dead_note_color = c; } }
-                "DrawLongNoteEnd" => km.draw_long_note_end = parse_bool(value_str).unwrap_or(km.draw_long_note_end),
-                "NotePadding" => km.note_padding = value_str.parse().unwrap_or(km.note_padding),
-                "WidthForNoteHeightScale" => km.width_for_note_height_scale = value_str.parse().unwrap_or(km.width_for_note_height_scale),
+                "ColorObjectsBySnapDistance" => km.color_objects_by_snap_distance = diag.bool(key_str, value_str, km.color_objects_by_snap_distance)?,
+                "UseHitObjectSheet" => km.use_hit_object_sheet = diag.bool(key_str, value_str, km.use_hit_object_sheet)?,
+                "RotateHitObjectsByColumn" => km.rotate_hit_objects_by_column = diag.bool(key_str, value_str, km.rotate_hit_objects_by_column)?,
+                "FlipNoteImagesOnUpscroll" => km.flip_note_images_on_upscroll = diag.bool(key_str, value_str, km.flip_note_images_on_upscroll)?,
+                "FlipNoteEndImagesOnUpscroll" => km.flip_note_end_images_on_upscroll = diag.bool(key_str, value_str, km.flip_note_end_images_on_upscroll)?,
+                "DeadNoteColor" => { km.dead_note_color = diag.colour(key_str, value_str, km.dead_note_color)?; }
+                "DrawLongNoteEnd" => km.draw_long_note_end = diag.bool(key_str, value_str, km.draw_long_note_end)?,
+                "NotePadding" => km.note_padding = diag.number(key_str, value_str, km.note_padding)?,
+                "WidthForNoteHeightScale" => km.width_for_note_height_scale = diag.number(key_str, value_str, km.width_for_note_height_scale)?,
 
                 // playfield
-                "BgMaskAlpha" => km.bg_mask_alpha = value_str.parse().unwrap_or(km.bg_mask_alpha),
-                "BgMaskPadding" => km.bg_mask_padding = value_str.parse().unwrap_or(km.bg_mask_padding),
-                "ColumnAlignment" => km.column_alignment = value_str.parse().unwrap_or(km.column_alignment),
-                "ColumnSize" => km.column_size = value_str.parse().unwrap_or(km.column_size),
-                "ColumnLightingOffsetY" => km.column_lighting_offset_y = value_str.parse().unwrap_or(km.column_lighting_offset_y),
-                "ColumnLightingScale" => km.column_lighting_scale = value_str.parse().unwrap_or(km.column_lighting_scale),
-                "HitPosOffsetY" => km.hit_pos_offset_y = value_str.parse().unwrap_or(km.hit_pos_offset_y),
-                "ReceptorPosOffsetY" => km.receptor_pos_offset_y = value_str.parse().unwrap_or(km.receptor_pos_offset_y),
-                "ReceptorsOverHitObjects" => km.receptors_over_hit_objects = parse_bool(value_str).unwrap_or(km.receptors_over_hit_objects),
-                "StageReceptorPadding" => km.stage_receptor_padding = value_str.parse().unwrap_or(km.stage_receptor_padding),
-                "CoopPlayfieldPadding" => km.coop_playfield_padding = value_str.parse().unwrap_or(km.coop_playfield_padding),
+                "BgMaskAlpha" => km.bg_mask_alpha = diag.number(key_str, value_str, km.bg_mask_alpha)?,
+                "BgMaskPadding" => km.bg_mask_padding = diag.number(key_str, value_str, km.bg_mask_padding)?,
+                "ColumnAlignment" => km.column_alignment = diag.number(key_str, value_str, km.column_alignment)?,
+                "ColumnSize" => km.column_size = diag.number(key_str, value_str, km.column_size)?,
+                "ColumnLightingOffsetY" => km.column_lighting_offset_y = diag.number(key_str, value_str, km.column_lighting_offset_y)?,
+                "ColumnLightingScale" => km.column_lighting_scale = diag.number(key_str, value_str, km.column_lighting_scale)?,
+                "HitPosOffsetY" => km.hit_pos_offset_y = diag.number(key_str, value_str, km.hit_pos_offset_y)?,
+                "ReceptorPosOffsetY" => km.receptor_pos_offset_y = diag.number(key_str, value_str, km.receptor_pos_offset_y)?,
+                "ReceptorsOverHitObjects" => km.receptors_over_hit_objects = diag.bool(key_str, value_str, km.receptors_over_hit_objects)?,
+                "StageReceptorPadding" => km.stage_receptor_padding = diag.number(key_str, value_str, km.stage_receptor_padding)?,
+                "CoopPlayfieldPadding" => km.coop_playfield_padding = diag.number(key_str, value_str, km.coop_playfield_padding)?,
 
                 k if k.starts_with("ColumnColor") => {
                     let idx_str = &k["ColumnColor".len()..];
-                    if let Ok(idx) = idx_str.parse::<usize>() {
-                        if idx >= 1 {
+                    match idx_str.parse::<usize>() {
+                        Ok(idx) if idx >= 1 => {
                             let i = idx - 1;
                             while km.column_colors.len() <= i {
                                 km.column_colors.push(Rgba { red: 255, green: 255, blue: 255, alpha: 255 });
                             }
-                            if let Ok(c) = Rgba::from_str(value_str) {
-                                km.column_colors[i] = c;
-                            }
+                            km.column_colors[i] = diag.colour(key_str, value_str, km.column_colors[i])?;
                         }
+                        Ok(_) => diag.report(ParseError::out_of_range(key_str, value_str, "ColumnColor1 or above"))?,
+                        Err(_) => diag.unknown_key(key_str, value_str)?,
                     }
                 }
 
                 // judgement / hit error
-                "HitErrorChevronSize" => km.hit_error_chevron_size = value_str.parse().unwrap_or(km.hit_error_chevron_size),
-                "HitErrorHeight" => km.hit_error_height = value_str.parse().unwrap_or(km.hit_error_height),
-                "HitErrorPosX" => km.hit_error_pos_x = value_str.parse().unwrap_or(km.hit_error_pos_x),
-                "HitErrorPosY" => km.hit_error_pos_y = value_str.parse().unwrap_or(km.hit_error_pos_y),
-                "HitErrorAlpha" => km.hit_error_alpha = value_str.parse().unwrap_or(km.hit_error_alpha),
-                "JudgementHitBurstFps" => km.judgement_hit_burst_fps = value_str.parse().unwrap_or(km.judgement_hit_burst_fps),
-                "JudgementBurstPosY" => km.judgement_burst_pos_y = value_str.parse().unwrap_or(km.judgement_burst_pos_y),
-                "JudgementHitBurstBumpY" => km.judgement_hit_burst_bump_y = value_str.parse().unwrap_or(km.judgement_hit_burst_bump_y),
-                "JudgementHitBurstBumpTime" => km.judgement_hit_burst_bump_time = value_str.parse().unwrap_or(km.judgement_hit_burst_bump_time),
-                "JudgementHitBurstScale" => km.judgement_hit_burst_scale = value_str.parse().unwrap_or(km.judgement_hit_burst_scale),
+                "HitErrorChevronSize" => km.hit_error_chevron_size = diag.number(key_str, value_str, km.hit_error_chevron_size)?,
+                "HitErrorHeight" => km.hit_error_height = diag.number(key_str, value_str, km.hit_error_height)?,
+                "HitErrorPosX" => km.hit_error_pos_x = diag.number(key_str, value_str, km.hit_error_pos_x)?,
+                "HitErrorPosY" => km.hit_error_pos_y = diag.number(key_str, value_str, km.hit_error_pos_y)?,
+                "HitErrorAlpha" => km.hit_error_alpha = diag.number(key_str, value_str, km.hit_error_alpha)?,
+                "JudgementHitBurstFps" => km.judgement_hit_burst_fps = diag.number(key_str, value_str, km.judgement_hit_burst_fps)?,
+                "JudgementBurstPosY" => km.judgement_burst_pos_y = diag.number(key_str, value_str, km.judgement_burst_pos_y)?,
+                "JudgementHitBurstBumpY" => km.judgement_hit_burst_bump_y = diag.number(key_str, value_str, km.judgement_hit_burst_bump_y)?,
+                "JudgementHitBurstBumpTime" => km.judgement_hit_burst_bump_time = diag.number(key_str, value_str, km.judgement_hit_burst_bump_time)?,
+                "JudgementHitBurstScale" => km.judgement_hit_burst_scale = diag.number(key_str, value_str, km.judgement_hit_burst_scale)?,
 
                 // lighting
-                "HitLightingX" => km.hit_lighting_x = value_str.parse().unwrap_or(km.hit_lighting_x),
-                "HitLightingY" => km.hit_lighting_y = value_str.parse().unwrap_or(km.hit_lighting_y),
-                "HitLightingFps" => km.hit_lighting_fps = value_str.parse().unwrap_or(km.hit_lighting_fps),
-                "HitLightingScale" => km.hit_lighting_scale = value_str.parse().unwrap_or(km.hit_lighting_scale),
-                "HitLightingColumnRotation" => km.hit_lighting_column_rotation = parse_bool(value_str).unwrap_or(km.hit_lighting_column_rotation),
-                "HoldLightingFps" => km.hold_lighting_fps = value_str.parse().unwrap_or(km.hold_lighting_fps),
-                "HoldLightingScale" => km.hold_lighting_scale = value_str.parse().unwrap_or(km.hold_lighting_scale),
-                "HoldLightingColumnRotation" => km.hold_lighting_column_rotation = parse_bool(value_str).unwrap_or(km.hold_lighting_column_rotation),
+                "HitLightingX" => km.hit_lighting_x = diag.number(key_str, value_str, km.hit_lighting_x)?,
+                "HitLightingY" => km.hit_lighting_y = diag.number(key_str, value_str, km.hit_lighting_y)?,
+                "HitLightingFps" => km.hit_lighting_fps = diag.number(key_str, value_str, km.hit_lighting_fps)?,
+                "HitLightingScale" => km.hit_lighting_scale = diag.number(key_str, value_str, km.hit_lighting_scale)?,
+                "HitLightingColumnRotation" => km.hit_lighting_column_rotation = diag.bool(key_str, value_str, km.hit_lighting_column_rotation)?,
+                "HoldLightingFps" => km.hold_lighting_fps = diag.number(key_str, value_str, km.hold_lighting_fps)?,
+                "HoldLightingScale" => km.hold_lighting_scale = diag.number(key_str, value_str, km.hold_lighting_scale)?,
+                "HoldLightingColumnRotation" => km.hold_lighting_column_rotation = diag.bool(key_str, value_str, km.hold_lighting_column_rotation)?,
 
                 // health bar
                 "HealthBarKeysAlignment" => km.health_bar_keys_alignment = HealthBarKeysAlignment::from_str(value_str),
                 "HealthBarType" => km.health_bar_type = HealthBarType::from_str(value_str),
-                "HealthBarPosOffsetX" => km.health_bar_pos_offset_x = value_str.parse().unwrap_or(km.health_bar_pos_offset_x),
-                "HealthBarPosOffsetY" => km.health_bar_pos_offset_y = value_str.parse().unwrap_or(km.health_bar_pos_offset_y),
-                "HealthBarScale" => km.health_bar_scale = value_str.parse().unwrap_or(km.health_bar_scale),
+                "HealthBarPosOffsetX" => km.health_bar_pos_offset_x = diag.number(key_str, value_str, km.health_bar_pos_offset_x)?,
+                "HealthBarPosOffsetY" => km.health_bar_pos_offset_y = diag.number(key_str, value_str, km.health_bar_pos_offset_y)?,
+                "HealthBarScale" => km.health_bar_scale = diag.number(key_str, value_str, km.health_bar_scale)?,
 
                 // hit bubbles
                 "HitBubblesAlignment" => km.hit_bubbles_alignment = HitBubblesAlignment::from_str(value_str),
                 "HitBubblesType" => km.hit_bubbles_type = HitBubblesType::from_str(value_str),
-                "HitBubblesPosX" => km.hit_bubbles_pos_x = value_str.parse().unwrap_or(km.hit_bubbles_pos_x),
-                "HitBubblesPosY" => km.hit_bubbles_pos_y = value_str.parse().unwrap_or(km.hit_bubbles_pos_y),
-                "HitBubblesScale" => km.hit_bubbles_scale = value_str.parse().unwrap_or(km.hit_bubbles_scale),
-                "HitBubbleScale" => km.hit_bubble_scale = value_str.parse().unwrap_or(km.hit_bubble_scale),
-                "HitBubbleBorderPadding" => km.hit_bubble_border_padding = value_str.parse().unwrap_or(km.hit_bubble_border_padding),
-                "HitBubblePadding" => km.hit_bubble_padding = value_str.parse().unwrap_or(km.hit_bubble_padding),
+                "HitBubblesPosX" => km.hit_bubbles_pos_x = diag.number(key_str, value_str, km.hit_bubbles_pos_x)?,
+                "HitBubblesPosY" => km.hit_bubbles_pos_y = diag.number(key_str, value_str, km.hit_bubbles_pos_y)?,
+                "HitBubblesScale" => km.hit_bubbles_scale = diag.number(key_str, value_str, km.hit_bubbles_scale)?,
+                "HitBubbleScale" => km.hit_bubble_scale = diag.number(key_str, value_str, km.hit_bubble_scale)?,
+                "HitBubbleBorderPadding" => km.hit_bubble_border_padding = diag.number(key_str, value_str, km.hit_bubble_border_padding)?,
+                "HitBubblePadding" => km.hit_bubble_padding = diag.number(key_str, value_str, km.hit_bubble_padding)?,
                 "HitBubblesRecordedJudgements" => {
                     km.hit_bubbles_recorded_judgements = HitBubblesRecordedJudgements::from_str(value_str);
                 }
 
                 // combo
-                "ComboDisplayScale" => km.combo_display_scale = value_str.parse().unwrap_or(km.combo_display_scale),
-                "ComboPosX" => km.combo_pos_x = value_str.parse().unwrap_or(km.combo_pos_x),
-                "ComboPosY" => km.combo_pos_y = value_str.parse().unwrap_or(km.combo_pos_y),
-                "ComboDisplayBumpY" => km.combo_display_bump_y = value_str.parse().unwrap_or(km.combo_display_bump_y),
-                "ComboDisplayBumpTime" => km.combo_display_bump_time = value_str.parse().unwrap_or(km.combo_display_bump_time),
+                "ComboDisplayScale" => km.combo_display_scale = diag.number(key_str, value_str, km.combo_display_scale)?,
+                "ComboPosX" => km.combo_pos_x = diag.number(key_str, value_str, km.combo_pos_x)?,
+                "ComboPosY" => km.combo_pos_y = diag.number(key_str, value_str, km.combo_pos_y)?,
+                "ComboDisplayBumpY" => km.combo_display_bump_y = diag.number(key_str, value_str, km.combo_display_bump_y)?,
+                "ComboDisplayBumpTime" => km.combo_display_bump_time = diag.number(key_str, value_str, km.combo_display_bump_time)?,
 
                 // rating
-                "RatingDisplayScale" => km.rating_display_scale = value_str.parse().unwrap_or(km.rating_display_scale),
-                "RatingDisplayPosX" => km.rating_display_pos_x = value_str.parse().unwrap_or(km.rating_display_pos_x),
-                "RatingDisplayPosY" => km.rating_display_pos_y = value_str.parse().unwrap_or(km.rating_display_pos_y),
+                "RatingDisplayScale" => km.rating_display_scale = diag.number(key_str, value_str, km.rating_display_scale)?,
+                "RatingDisplayPosX" => km.rating_display_pos_x = diag.number(key_str, value_str, km.rating_display_pos_x)?,
+                "RatingDisplayPosY" => km.rating_display_pos_y = diag.number(key_str, value_str, km.rating_display_pos_y)?,
 
                 // accuracy
-                "AccuracyDisplayScale" => km.accuracy_display_scale = value_str.parse().unwrap_or(km.accuracy_display_scale),
-                "AccuracyDisplayPosX" => km.accuracy_display_pos_x = value_str.parse().unwrap_or(km.accuracy_display_pos_x),
-                "AccuracyDisplayPosY" => km.accuracy_display_pos_y = value_str.parse().unwrap_or(km.accuracy_display_pos_y),
+                "AccuracyDisplayScale" => km.accuracy_display_scale = diag.number(key_str, value_str, km.accuracy_display_scale)?,
+                "AccuracyDisplayPosX" => km.accuracy_display_pos_x = diag.number(key_str, value_str, km.accuracy_display_pos_x)?,
+                "AccuracyDisplayPosY" => km.accuracy_display_pos_y = diag.number(key_str, value_str, km.accuracy_display_pos_y)?,
 
                 // kps
-                "KpsDisplayScale" => km.kps_display_scale = value_str.parse().unwrap_or(km.kps_display_scale),
-                "KpsDisplayPosX" => km.kps_display_pos_x = value_str.parse().unwrap_or(km.kps_display_pos_x),
-                "KpsDisplayPosY" => km.kps_display_pos_y = value_str.parse().unwrap_or(km.kps_display_pos_y),
+                "KpsDisplayScale" => km.kps_display_scale = diag.number(key_str, value_str, km.kps_display_scale)?,
+                "KpsDisplayPosX" => km.kps_display_pos_x = diag.number(key_str, value_str, km.kps_display_pos_x)?,
+                "KpsDisplayPosY" => km.kps_display_pos_y = diag.number(key_str, value_str, km.kps_display_pos_y)?,
 
                 // score
-                "ScoreDisplayScale" => km.score_display_scale = value_str.parse().unwrap_or(km.score_display_scale),
-                "ScoreDisplayPosX" => km.score_display_pos_x = value_str.parse().unwrap_or(km.score_display_pos_x),
-                "ScoreDisplayPosY" => km.score_display_pos_y = value_str.parse().unwrap_or(km.score_display_pos_y),
+                "ScoreDisplayScale" => km.score_display_scale = diag.number(key_str, value_str, km.score_display_scale)?,
+                "ScoreDisplayPosX" => km.score_display_pos_x = diag.number(key_str, value_str, km.score_display_pos_x)?,
+                "ScoreDisplayPosY" => km.score_display_pos_y = diag.number(key_str, value_str, km.score_display_pos_y)?,
 
                 // multiplayer
-                "BattleRoyaleAlertPosX" => km.battle_royale_alert_pos_x = value_str.parse().unwrap_or(km.battle_royale_alert_pos_x),
-                "BattleRoyaleAlertPosY" => km.battle_royale_alert_pos_y = value_str.parse().unwrap_or(km.battle_royale_alert_pos_y),
-                "BattleRoyaleAlertScale" => km.battle_royale_alert_scale = value_str.parse().unwrap_or(km.battle_royale_alert_scale),
-                "BattleRoyaleEliminatedPosX" => km.battle_royale_eliminated_pos_x = value_str.parse().unwrap_or(km.battle_royale_eliminated_pos_x),
-                "BattleRoyaleEliminatedPosY" => km.battle_royale_eliminated_pos_y = value_str.parse().unwrap_or(km.battle_royale_eliminated_pos_y),
+                "BattleRoyaleAlertPosX" => km.battle_royale_alert_pos_x = diag.number(key_str, value_str, km.battle_royale_alert_pos_x)?,
+                "BattleRoyaleAlertPosY" => km.battle_royale_alert_pos_y = diag.number(key_str, value_str, km.battle_royale_alert_pos_y)?,
+                "BattleRoyaleAlertScale" => km.battle_royale_alert_scale = diag.number(key_str, value_str, km.battle_royale_alert_scale)?,
+                "BattleRoyaleEliminatedPosX" => km.battle_royale_eliminated_pos_x = diag.number(key_str, value_str, km.battle_royale_eliminated_pos_x)?,
+                "BattleRoyaleEliminatedPosY" => km.battle_royale_eliminated_pos_y = diag.number(key_str, value_str, km.battle_royale_eliminated_pos_y)?,
 
                 // judgement counter
-                "JudgementCounterAlpha" => km.judgement_counter_alpha = value_str.parse().unwrap_or(km.judgement_counter_alpha),
+                "JudgementCounterAlpha" => km.judgement_counter_alpha = diag.number(key_str, value_str, km.judgement_counter_alpha)?,
                 "JudgementCounterFontColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { km.judgement_counter_font_color = c; }
+                    km.judgement_counter_font_color = diag.colour(key_str, value_str, km.judgement_counter_font_color)?;
                 }
-                "JudgementCounterSize" => km.judgement_counter_size = value_str.parse().unwrap_or(km.judgement_counter_size),
-                "JudgeColorMarv" => { if let Ok(c) = Rgba::from_str(value_str) { km.judge_color_marv = c; } }
-                "JudgeColorPerf" => { if let Ok(c) = Rgba::from_str(value_str) { km.judge_color_perf = c; } }
-                "JudgeColorGreat" => { if let Ok(c) = Rgba::from_str(value_str) { km.judge_color_great = c; } }
-                "JudgeColorGood" => { if let Ok(c) = Rgba::from_str(value_str) { km.judge_color_good = c; } }
-                "JudgeColorOkay" => { if let Ok(c) = Rgba::from_str(value_str) { km.judge_color_okay = c; } }
-                "JudgeColorMiss" => { if let Ok(c) = Rgba::from_str(value_str) { km.judge_color_miss = c; } }
-                "JudgementCounterPosX" => km.judgement_counter_pos_x = value_str.parse().unwrap_or(km.judgement_counter_pos_x),
-                "JudgementCounterPosY" => km.judgement_counter_pos_y = value_str.parse().unwrap_or(km.judgement_counter_pos_y),
-                "JudgementCounterPadding" => km.judgement_counter_padding = value_str.parse().unwrap_or(km.judgement_counter_padding),
-                "JudgementCounterHorizontal" => km.judgement_counter_horizontal = parse_bool(value_str).unwrap_or(km.judgement_counter_horizontal),
-                "JudgementCounterFadeToAlpha" => km.judgement_counter_fade_to_alpha = parse_bool(value_str).unwrap_or(km.judgement_counter_fade_to_alpha),
-                "UseJudgementColorForNumbers" => km.use_judgement_color_for_numbers = parse_bool(value_str).unwrap_or(km.use_judgement_color_for_numbers),
+                "JudgementCounterSize" => km.judgement_counter_size = diag.number(key_str, value_str, km.judgement_counter_size)?,
+                "JudgeColorMarv" => { km.judge_color_marv = diag.colour(key_str, value_str, km.judge_color_marv)?; }
+                "JudgeColorPerf" => { km.judge_color_perf = diag.colour(key_str, value_str, km.judge_color_perf)?; }
+                "JudgeColorGreat" => { km.judge_color_great = diag.colour(key_str, value_str, km.judge_color_great)?; }
+                "JudgeColorGood" => { km.judge_color_good = diag.colour(key_str, value_str, km.judge_color_good)?; }
+                "JudgeColorOkay" => { km.judge_color_okay = diag.colour(key_str, value_str, km.judge_color_okay)?; }
+                "JudgeColorMiss" => { km.judge_color_miss = diag.colour(key_str, value_str, km.judge_color_miss)?; }
+                "JudgementCounterPosX" => km.judgement_counter_pos_x = diag.number(key_str, value_str, km.judgement_counter_pos_x)?,
+                "JudgementCounterPosY" => km.judgement_counter_pos_y = diag.number(key_str, value_str, km.judgement_counter_pos_y)?,
+                "JudgementCounterPadding" => km.judgement_counter_padding = diag.number(key_str, value_str, km.judgement_counter_padding)?,
+                "JudgementCounterHorizontal" => km.judgement_counter_horizontal = diag.bool(key_str, value_str, km.judgement_counter_horizontal)?,
+                "JudgementCounterFadeToAlpha" => km.judgement_counter_fade_to_alpha = diag.bool(key_str, value_str, km.judgement_counter_fade_to_alpha)?,
+                "UseJudgementColorForNumbers" => km.use_judgement_color_for_numbers = diag.bool(key_str, value_str, km.use_judgement_color_for_numbers)?,
 
                 // stage timing bar
                 "SongTimeProgressActiveColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { km.song_time_progress_active_color = c; }
+                    km.song_time_progress_active_color = diag.colour(key_str, value_str, km.song_time_progress_active_color)?;
                 }
                 "SongTimeProgressInactiveColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { km.song_time_progress_inactive_color = c; }
+                    km.song_time_progress_inactive_color = diag.colour(key_str, value_str, km.song_time_progress_inactive_color)?;
                 }
-                "SongTimeProgressScale" => km.song_time_progress_scale = value_str.parse().unwrap_or(km.song_time_progress_scale),
-                "SongTimeProgressPositionAtTop" => km.song_time_progress_position_at_top = parse_bool(value_str).unwrap_or(km.song_time_progress_position_at_top),
+                "SongTimeProgressScale" => km.song_time_progress_scale = diag.number(key_str, value_str, km.song_time_progress_scale)?,
+                "SongTimeProgressPositionAtTop" => km.song_time_progress_position_at_top = diag.bool(key_str, value_str, km.song_time_progress_position_at_top)?,
                 "TimingLineColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { km.timing_line_color = c; }
+                    km.timing_line_color = diag.colour(key_str, value_str, km.timing_line_color)?;
                 }
 
                 // mini progress bar
-                "ShowMiniSongBar" => km.show_mini_song_bar = parse_bool(value_str).unwrap_or(km.show_mini_song_bar),
-                "MiniSongBarDisplayPosX" => km.mini_song_bar_display_pos_x = value_str.parse().unwrap_or(km.mini_song_bar_display_pos_x),
-                "MiniSongBarDisplayPosY" => km.mini_song_bar_display_pos_y = value_str.parse().unwrap_or(km.mini_song_bar_display_pos_y),
-                "MiniSongBarDisplayWidthFactor" => km.mini_song_bar_display_width_factor = value_str.parse().unwrap_or(km.mini_song_bar_display_width_factor),
-                "MiniSongBarDisplayHeight" => km.mini_song_bar_display_height = value_str.parse().unwrap_or(km.mini_song_bar_display_height),
+                "ShowMiniSongBar" => km.show_mini_song_bar = diag.bool(key_str, value_str, km.show_mini_song_bar)?,
+                "MiniSongBarDisplayPosX" => km.mini_song_bar_display_pos_x = diag.number(key_str, value_str, km.mini_song_bar_display_pos_x)?,
+                "MiniSongBarDisplayPosY" => km.mini_song_bar_display_pos_y = diag.number(key_str, value_str, km.mini_song_bar_display_pos_y)?,
+                "MiniSongBarDisplayWidthFactor" => km.mini_song_bar_display_width_factor = diag.number(key_str, value_str, km.mini_song_bar_display_width_factor)?,
+                "MiniSongBarDisplayHeight" => km.mini_song_bar_display_height = diag.number(key_str, value_str, km.mini_song_bar_display_height)?,
 
                 // fallbacks
-                "UseFallback" => km.use_fallback = parse_bool(value_str).unwrap_or(km.use_fallback),
-                "HitObjectFallbacks" => km.hitobject_fallbacks = parse_list::<u8>(value_str),
-                "HoldBodyFallbacks" => km.holdbody_fallbacks = parse_list::<u8>(value_str),
-                "HoldEndFallbacks" => km.holdend_fallbacks = parse_list::<u8>(value_str),
-                "ReceptorFallbacks" => km.receptor_fallbacks = parse_list::<u8>(value_str),
-                "HitObjectRotations" => km.hitobject_rotations = parse_list::<u8>(value_str),
-                "ReceptorRotations" => km.receptor_rotations = parse_list::<u8>(value_str),
-
-                _ => {}
+                "UseFallback" => km.use_fallback = diag.bool(key_str, value_str, km.use_fallback)?,
+                "HitObjectFallbacks" => km.hitobject_fallbacks = diag.recover(try_parse_list(key_str, value_str), || parse_list(value_str))?,
+                "HoldBodyFallbacks" => km.holdbody_fallbacks = diag.recover(try_parse_list(key_str, value_str), || parse_list(value_str))?,
+                "HoldEndFallbacks" => km.holdend_fallbacks = diag.recover(try_parse_list(key_str, value_str), || parse_list(value_str))?,
+                "ReceptorFallbacks" => km.receptor_fallbacks = diag.recover(try_parse_list(key_str, value_str), || parse_list(value_str))?,
+                "HitObjectRotations" => km.hitobject_rotations = diag.recover(try_parse_list(key_str, value_str), || parse_list(value_str))?,
+                "ReceptorRotations" => km.receptor_rotations = diag.recover(try_parse_list(key_str, value_str), || parse_list(value_str))?,
+
+                _ => diag.unknown_key(key_str, value_str)?,
             }
         }
 
@@ -877,8 +878,8 @@ impl Keymode {
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_class = QuaKeymode))]
 impl Keymode {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
-    pub fn wasm_from_str(content: &str) -> Result<Keymode, String> {
-        Self::from_str(content).map_err(|e| e.to_string())
+    pub fn wasm_from_str(content: &str) -> Result<Keymode, ParseError> {
+        Self::from_str(content)
     }
 
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toStr))]
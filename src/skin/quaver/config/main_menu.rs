@@ -5,6 +5,7 @@ use wasm_bindgen::prelude::*;
 use crate::common::color::Rgba;
 use crate::utils;
 use crate::utils::serde::{add_key_value, add_key_value_if_not_default, parse_key_value_eq};
+use crate::error::{ParseDiagnostics, ParseError};
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Clone, Debug, Merge)]
@@ -68,7 +69,11 @@ impl Default for MainMenu {
 }
 
 impl MainMenu {
-    pub fn from_str(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
         let mut menu = Self::default();
 
         for line in content.lines() {
@@ -79,42 +84,42 @@ impl MainMenu {
             let (key_str, value_str) = parse_key_value_eq(line).unwrap_or_default();
             match key_str {
                 "NavigationButtonTextColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { menu.navigation_button_text_color = c; }
+                    menu.navigation_button_text_color = diag.colour(key_str, value_str, menu.navigation_button_text_color)?;
                 }
                 "NavigationQuitButtonTextColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { menu.navigation_quit_button_text_color = c; }
+                    menu.navigation_quit_button_text_color = diag.colour(key_str, value_str, menu.navigation_quit_button_text_color)?;
                 }
                 "NavigationButtonHoveredAlpha" => {
-                    menu.navigation_button_hovered_alpha = value_str.parse().unwrap_or(0.35);
+                    menu.navigation_button_hovered_alpha = diag.number(key_str, value_str, menu.navigation_button_hovered_alpha)?;
                 }
                 "TipTitleColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { menu.tip_title_color = c; }
+                    menu.tip_title_color = diag.colour(key_str, value_str, menu.tip_title_color)?;
                 }
                 "TipTextColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { menu.tip_text_color = c; }
+                    menu.tip_text_color = diag.colour(key_str, value_str, menu.tip_text_color)?;
                 }
                 "NewsTitleColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { menu.news_title_color = c; }
+                    menu.news_title_color = diag.colour(key_str, value_str, menu.news_title_color)?;
                 }
                 "NewsDateColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { menu.news_date_color = c; }
+                    menu.news_date_color = diag.colour(key_str, value_str, menu.news_date_color)?;
                 }
                 "NewsTextColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { menu.news_text_color = c; }
+                    menu.news_text_color = diag.colour(key_str, value_str, menu.news_text_color)?;
                 }
                 "JukeboxProgressBarColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { menu.jukebox_progress_bar_color = c; }
+                    menu.jukebox_progress_bar_color = diag.colour(key_str, value_str, menu.jukebox_progress_bar_color)?;
                 }
                 "AudioVisualizerColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { menu.audio_visualizer_color = c; }
+                    menu.audio_visualizer_color = diag.colour(key_str, value_str, menu.audio_visualizer_color)?;
                 }
                 "AudioVisualizerOpacity" => {
-                    menu.audio_visualizer_opacity = value_str.parse().unwrap_or(0.85);
+                    menu.audio_visualizer_opacity = diag.number(key_str, value_str, menu.audio_visualizer_opacity)?;
                 }
                 "NoteVisualizerOpacity" => {
-                    menu.note_visualizer_opacity = value_str.parse().unwrap_or(0.60);
+                    menu.note_visualizer_opacity = diag.number(key_str, value_str, menu.note_visualizer_opacity)?;
                 }
-                _ => {}
+                _ => diag.unknown_key(key_str, value_str)?,
             }
         }
 
@@ -172,8 +177,8 @@ impl MainMenu {
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 impl MainMenu {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
-    pub fn wasm_from_str(content: &str) -> Result<MainMenu, String> {
-        Self::from_str(content).map_err(|e| e.to_string())
+    pub fn wasm_from_str(content: &str) -> Result<MainMenu, ParseError> {
+        Self::from_str(content)
     }
 
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
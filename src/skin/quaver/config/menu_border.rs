@@ -4,6 +4,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::common::color::Rgba;
 use crate::utils::serde::{add_key_value, parse_key_value_eq};
+use crate::error::{ParseDiagnostics, ParseError};
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Clone, Debug, Merge)]
@@ -30,7 +31,11 @@ impl Default for MenuBorder {
 }
 
 impl MenuBorder {
-    pub fn from_str(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
         let mut border = Self::default();
 
         for line in content.lines() {
@@ -41,18 +46,18 @@ impl MenuBorder {
             let (key_str, value_str) = parse_key_value_eq(line).unwrap_or_default();
             match key_str {
                 "BackgroundLineColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { border.background_line_color = c; }
+                    border.background_line_color = diag.colour(key_str, value_str, border.background_line_color)?;
                 }
                 "ForegroundLineColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { border.foreground_line_color = c; }
+                    border.foreground_line_color = diag.colour(key_str, value_str, border.foreground_line_color)?;
                 }
                 "ButtonTextColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { border.button_text_color = c; }
+                    border.button_text_color = diag.colour(key_str, value_str, border.button_text_color)?;
                 }
                 "ButtonTextHoveredColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { border.button_text_hovered_color = c; }
+                    border.button_text_hovered_color = diag.colour(key_str, value_str, border.button_text_hovered_color)?;
                 }
-                _ => {}
+                _ => diag.unknown_key(key_str, value_str)?,
             }
         }
 
@@ -83,8 +88,8 @@ impl MenuBorder {
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 impl MenuBorder {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
-    pub fn wasm_from_str(content: &str) -> Result<MenuBorder, String> {
-        Self::from_str(content).map_err(|e| e.to_string())
+    pub fn wasm_from_str(content: &str) -> Result<MenuBorder, ParseError> {
+        Self::from_str(content)
     }
 
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
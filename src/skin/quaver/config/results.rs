@@ -4,6 +4,7 @@ use wasm_bindgen::prelude::*;
 
 use crate::utils;
 use crate::utils::serde::{add_key_value, add_key_value_if_not_default, parse_key_value_eq};
+use crate::error::{ParseDiagnostics, ParseError};
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Clone, Debug, PartialEq)]
@@ -58,7 +59,11 @@ impl Default for Results {
 }
 
 impl Results {
-    pub fn from_str(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
         let mut results = Self::default();
 
         for line in content.lines() {
@@ -72,9 +77,9 @@ impl Results {
                     results.results_background_type = ResultsBackgroundType::from_str(value_str);
                 }
                 "ResultsBackgroundFilterAlpha" => {
-                    results.results_background_filter_alpha = value_str.parse().unwrap_or(1.0);
+                    results.results_background_filter_alpha = diag.number(key_str, value_str, results.results_background_filter_alpha)?;
                 }
-                _ => {}
+                _ => diag.unknown_key(key_str, value_str)?,
             }
         }
 
@@ -103,8 +108,8 @@ impl Results {
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 impl Results {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
-    pub fn wasm_from_str(content: &str) -> Result<Results, String> {
-        Self::from_str(content).map_err(|e| e.to_string())
+    pub fn wasm_from_str(content: &str) -> Result<Results, ParseError> {
+        Self::from_str(content)
     }
 
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
@@ -5,7 +5,8 @@ use wasm_bindgen::prelude::*;
 use crate::common::color::Rgba;
 use crate::common::vector::Vector2;
 use crate::utils;
-use crate::utils::serde::{add_key_value, add_key_value_if_not_default, parse_bool, parse_key_value_eq, serialize_bool};
+use crate::utils::serde::{add_key_value, add_key_value_if_not_default, parse_key_value_eq, serialize_bool};
+use crate::error::{ParseDiagnostics, ParseError};
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Clone, Debug, Merge)]
@@ -97,7 +98,11 @@ impl Default for SongSelect {
 }
 
 impl SongSelect {
-    pub fn from_str(content: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn from_str(content: &str) -> Result<Self, ParseError> {
+        Self::from_str_with(content, &mut ParseDiagnostics::default())
+    }
+
+    pub fn from_str_with(content: &str, diag: &mut ParseDiagnostics) -> Result<Self, ParseError> {
         let mut ss = Self::default();
 
         for line in content.lines() {
@@ -108,81 +113,79 @@ impl SongSelect {
             let (key_str, value_str) = parse_key_value_eq(line).unwrap_or_default();
             match key_str {
                 "LeaderboardScoreColorEven" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_score_color_even = c; }
+                    ss.leaderboard_score_color_even = diag.colour(key_str, value_str, ss.leaderboard_score_color_even)?;
                 }
                 "LeaderboardScoreColorOdd" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_score_color_odd = c; }
+                    ss.leaderboard_score_color_odd = diag.colour(key_str, value_str, ss.leaderboard_score_color_odd)?;
                 }
                 "LeaderboardScoreRankColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_score_rank_color = c; }
+                    ss.leaderboard_score_rank_color = diag.colour(key_str, value_str, ss.leaderboard_score_rank_color)?;
                 }
                 "LeaderboardScoreRatingColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_score_rating_color = c; }
+                    ss.leaderboard_score_rating_color = diag.colour(key_str, value_str, ss.leaderboard_score_rating_color)?;
                 }
                 "LeaderboardScoreAccuracyColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_score_accuracy_color = c; }
+                    ss.leaderboard_score_accuracy_color = diag.colour(key_str, value_str, ss.leaderboard_score_accuracy_color)?;
                 }
                 "LeaderboardScoreUsernameSelfColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_score_username_self_color = c; }
+                    ss.leaderboard_score_username_self_color = diag.colour(key_str, value_str, ss.leaderboard_score_username_self_color)?;
                 }
                 "LeaderboardScoreUsernameOtherColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_score_username_other_color = c; }
+                    ss.leaderboard_score_username_other_color = diag.colour(key_str, value_str, ss.leaderboard_score_username_other_color)?;
                 }
                 "LeaderboardTitleColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_title_color = c; }
+                    ss.leaderboard_title_color = diag.colour(key_str, value_str, ss.leaderboard_title_color)?;
                 }
                 "LeaderboardRankingTitleColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_ranking_title_color = c; }
+                    ss.leaderboard_ranking_title_color = diag.colour(key_str, value_str, ss.leaderboard_ranking_title_color)?;
                 }
                 "LeaderboardDropdownColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_dropdown_color = c; }
+                    ss.leaderboard_dropdown_color = diag.colour(key_str, value_str, ss.leaderboard_dropdown_color)?;
                 }
                 "LeaderboardStatusTextColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.leaderboard_status_text_color = c; }
+                    ss.leaderboard_status_text_color = diag.colour(key_str, value_str, ss.leaderboard_status_text_color)?;
                 }
                 "PersonalBestTitleColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.personal_best_title_color = c; }
+                    ss.personal_best_title_color = diag.colour(key_str, value_str, ss.personal_best_title_color)?;
                 }
                 "PersonalBestTrophyColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.personal_best_trophy_color = c; }
+                    ss.personal_best_trophy_color = diag.colour(key_str, value_str, ss.personal_best_trophy_color)?;
                 }
                 "PersonalBestRankColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.personal_best_rank_color = c; }
+                    ss.personal_best_rank_color = diag.colour(key_str, value_str, ss.personal_best_rank_color)?;
                 }
                 "NoPersonalBestColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.no_personal_best_color = c; }
+                    ss.no_personal_best_color = diag.colour(key_str, value_str, ss.no_personal_best_color)?;
                 }
                 "MapsetPanelSongTitleColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.mapset_panel_song_title_color = c; }
+                    ss.mapset_panel_song_title_color = diag.colour(key_str, value_str, ss.mapset_panel_song_title_color)?;
                 }
                 "MapsetPanelSongArtistColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.mapset_panel_song_artist_color = c; }
+                    ss.mapset_panel_song_artist_color = diag.colour(key_str, value_str, ss.mapset_panel_song_artist_color)?;
                 }
                 "MapsetPanelCreatorColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.mapset_panel_creator_color = c; }
+                    ss.mapset_panel_creator_color = diag.colour(key_str, value_str, ss.mapset_panel_creator_color)?;
                 }
                 "MapsetPanelByColor" => {
-                    if let Ok(c) = Rgba::from_str(value_str) { ss.mapset_panel_by_color = c; }
+                    ss.mapset_panel_by_color = diag.colour(key_str, value_str, ss.mapset_panel_by_color)?;
                 }
                 "MapsetPanelBannerSize" => {
                     // Format: "width,height"
-                    let parts: Vec<&str> = value_str.splitn(2, ',').collect();
-                    if parts.len() == 2 {
-                        if let (Ok(w), Ok(h)) = (parts[0].trim().parse(), parts[1].trim().parse()) {
-                            ss.mapset_panel_banner_size = Vector2 { x: w, y: h };
-                        }
-                    }
+                    let size = value_str.split_once(',').and_then(|(w, h)| {
+                        Some(Vector2 { x: w.trim().parse().ok()?, y: h.trim().parse().ok()? })
+                    });
+                    ss.mapset_panel_banner_size = diag.accept(key_str, value_str, size.ok_or(()), "width,height", ss.mapset_panel_banner_size)?;
                 }
                 "MapsetPanelHoveringAlpha" => {
-                    ss.mapset_panel_hovering_alpha = value_str.parse().unwrap_or(0.35);
+                    ss.mapset_panel_hovering_alpha = diag.number(key_str, value_str, ss.mapset_panel_hovering_alpha)?;
                 }
                 "MapBackgroundBrightness" => {
-                    ss.map_background_brightness = value_str.parse().unwrap_or(15);
+                    ss.map_background_brightness = diag.number(key_str, value_str, ss.map_background_brightness)?;
                 }
                 "DisplayMapBackground" => {
-                    ss.display_map_background = parse_bool(value_str);
+                    ss.display_map_background = diag.bool(key_str, value_str, ss.display_map_background)?;
                 }
-                _ => {}
+                _ => diag.unknown_key(key_str, value_str)?,
             }
         }
 
@@ -248,8 +251,8 @@ impl SongSelect {
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 impl SongSelect {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = fromStr))]
-    pub fn wasm_from_str(content: &str) -> Result<SongSelect, String> {
-        Self::from_str(content).map_err(|e| e.to_string())
+    pub fn wasm_from_str(content: &str) -> Result<SongSelect, ParseError> {
+        Self::from_str(content)
     }
 
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(js_name = toString))]
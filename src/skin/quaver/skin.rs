@@ -9,6 +9,9 @@ use crate::converting::quaver::{from_generic_mania, to_generic_mania};
 use crate::quaver::config::Keymode;
 use crate::quaver::QuaSkinIni;
 use crate::sample::SampleStore;
+use crate::error::ConvertError;
+use crate::options::ConversionOptions;
+use crate::report::ConversionReport;
 use crate::skin::generic::GenericManiaSkin;
 use crate::texture::TextureStore;
 use crate::traits::SkinConfig;
@@ -44,12 +47,16 @@ impl<'a> ManiaSkin<'a> for QuaSkin {
     type ToParams = ();
     type FromReturn = Self;
 
-    fn to_generic_mania(&self, _params: Self::ToParams) -> Result<GenericManiaSkin, Box<dyn std::error::Error>> {
-        to_generic_mania(self)
+    fn to_generic_mania_with(&self, _params: Self::ToParams, options: &ConversionOptions) -> Result<(GenericManiaSkin, ConversionReport), ConvertError> {
+        let mut report = ConversionReport::new();
+        let skin = to_generic_mania(self, options, &mut report)?;
+        Ok((skin, report))
     }
 
-    fn from_generic_mania(skin: &GenericManiaSkin) -> Result<Self::FromReturn, Box<dyn std::error::Error>> {
-        from_generic_mania(skin)
+    fn from_generic_mania_with(skin: &GenericManiaSkin, options: &ConversionOptions) -> Result<(Self::FromReturn, ConversionReport), ConvertError> {
+        let mut report = ConversionReport::new();
+        let skin = from_generic_mania(skin, options, &mut report)?;
+        Ok((skin, report))
     }
 
     fn get_keymode(&self, keymode: u8) -> Option<&Keymode> {
@@ -73,14 +80,30 @@ impl<'a> ManiaSkin<'a> for QuaSkin {
 #[wasm_bindgen]
 impl QuaSkin {
     #[wasm_bindgen(js_name = toGenericMania)]
-    pub fn to_generic_mania_wasm(&self) -> Result<GenericManiaSkin, JsValue> {
-        self.to_generic_mania(())
+    pub fn to_generic_mania_wasm(&self, options: Option<ConversionOptions>) -> Result<GenericManiaSkin, JsValue> {
+        self.to_generic_mania_with_options((), &options.unwrap_or_default())
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
     #[wasm_bindgen(js_name = fromGenericMania)]
-    pub fn from_generic_mania_wasm(skin: &GenericManiaSkin) -> Result<QuaSkin, JsValue> {
-        Self::from_generic_mania(skin)
+    pub fn from_generic_mania_wasm(skin: &GenericManiaSkin, options: Option<ConversionOptions>) -> Result<QuaSkin, JsValue> {
+        Self::from_generic_mania_with_options(skin, &options.unwrap_or_default())
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// `[skin, report]`
+    #[wasm_bindgen(js_name = toGenericManiaWithReport)]
+    pub fn to_generic_mania_with_report_wasm(&self, options: Option<ConversionOptions>) -> Result<js_sys::Array, JsValue> {
+        self.to_generic_mania_with((), &options.unwrap_or_default())
+            .map(|(skin, report)| crate::utils::wasm::with_report(skin, report))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// `[skin, report]`
+    #[wasm_bindgen(js_name = fromGenericManiaWithReport)]
+    pub fn from_generic_mania_with_report_wasm(skin: &GenericManiaSkin, options: Option<ConversionOptions>) -> Result<js_sys::Array, JsValue> {
+        Self::from_generic_mania_with(skin, &options.unwrap_or_default())
+            .map(|(skin, report)| crate::utils::wasm::with_report(skin, report))
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
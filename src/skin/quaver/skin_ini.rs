@@ -3,11 +3,12 @@ use merge::Merge;
 use wasm_bindgen::prelude::*;
 
 use std::str::FromStr;
-use crate::ini::from_ini;
+use crate::ini::{from_ini_with, IniSource};
 use crate::quaver::config::{General, Keymode, MainMenu, MenuBorder, Results, SongSelect};
 use crate::quaver::{dynamic_assets, static_assets};
 use crate::traits::SkinConfig;
 use crate::{ConstTypeEnum, StringPattern, utils};
+use crate::error::{ParseDiagnostics, ParseError, ParseMode, ParseWarning};
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 #[derive(Clone, Debug, Default, Merge)]
@@ -28,10 +29,15 @@ pub struct QuaSkinIni {
     #[cfg_attr(target_arch = "wasm32", wasm_bindgen(getter_with_clone))]
     #[merge(strategy = utils::merge::any::overwrite)]
     pub shared_keymode: Option<Keymode>,
+    /// the file this was parsed from, `to_string` only rewrites the lines that changed since.
+    /// set to `None` to write a clean file
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(skip))]
+    #[merge(skip)]
+    pub source: Option<IniSource>,
 }
 
-impl ToString for QuaSkinIni {
-    fn to_string(&self) -> String {
+impl QuaSkinIni {
+    fn to_typed_string(&self) -> String {
         let mut result = String::new();
 
         result.push_str("[General]\n");
@@ -64,10 +70,20 @@ impl ToString for QuaSkinIni {
     }
 }
 
-impl FromStr for QuaSkinIni {
-    type Err = Box<dyn std::error::Error>;
+impl ToString for QuaSkinIni {
+    fn to_string(&self) -> String {
+        let generated = self.to_typed_string();
+        match &self.source {
+            Some(source) => source.render(&generated),
+            None => generated,
+        }
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl QuaSkinIni {
+    /// `from_str` is lenient and drops the warnings
+    pub fn from_str_with(s: &str, mode: ParseMode) -> Result<(Self, Vec<ParseWarning>), ParseError> {
+        let mut diag = ParseDiagnostics::new(mode);
         let mut general = General::default();
         let mut main_menu = MainMenu::default();
         let mut menu_border = MenuBorder::default();
@@ -76,19 +92,19 @@ impl FromStr for QuaSkinIni {
         let mut keymodes: Vec<Keymode> = Vec::new();
         let mut shared_keymode: Option<Keymode> = None;
 
-        from_ini(s, |section, content| {
+        from_ini_with(s, &mut diag, |section, content, diag| {
             match section {
-                "General" => general = General::from_str(content)?,
-                "MainMenu" => main_menu = MainMenu::from_str(content)?,
-                "MenuBorder" => menu_border = MenuBorder::from_str(content)?,
-                "SongSelect" => song_select = SongSelect::from_str(content)?,
-                "Results" => results = Results::from_str(content)?,
+                "General" => general = General::from_str_with(content, diag)?,
+                "MainMenu" => main_menu = MainMenu::from_str_with(content, diag)?,
+                "MenuBorder" => menu_border = MenuBorder::from_str_with(content, diag)?,
+                "SongSelect" => song_select = SongSelect::from_str_with(content, diag)?,
+                "Results" => results = Results::from_str_with(content, diag)?,
                 s if &s.to_uppercase() == "KEYSSHARED" || &s.to_uppercase() == "SHAREDK" => {
-                    let km = Keymode::from_str(content)?;
+                    let km = Keymode::from_str_with(content, diag)?;
                     shared_keymode = Some(km);
                 },
                 s if s.to_lowercase().ends_with('k') && s.len() > 1 => {
-                    let mut km = Keymode::from_str(content)?;
+                    let mut km = Keymode::from_str_with(content, diag)?;
                     if let Ok(n) = section.to_lowercase().trim_end_matches('k').parse::<u8>() {
                         km.keymode = n;
                         keymodes.push(km);
@@ -99,7 +115,18 @@ impl FromStr for QuaSkinIni {
             Ok(())
         })?;
 
-        Ok(QuaSkinIni { general, main_menu, menu_border, song_select, results, keymodes, shared_keymode })
+        let mut skin_ini = QuaSkinIni { general, main_menu, menu_border, song_select, results, keymodes, shared_keymode, source: None };
+        skin_ini.source = Some(IniSource::new(s, &skin_ini.to_typed_string()));
+
+        Ok((skin_ini, diag.warnings))
+    }
+}
+
+impl FromStr for QuaSkinIni {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(s, ParseMode::Lenient).map(|(skin_ini, _)| skin_ini)
     }
 }
 
@@ -146,8 +173,15 @@ impl QuaSkinIni {
     }
 
     #[wasm_bindgen(js_name = fromStr)]
-    pub fn from_str_wasm(ini_str: &str) -> Result<Self, JsError> {
-        Self::from_str(ini_str).map_err(|e| JsError::new(&e.to_string()))
+    pub fn from_str_wasm(ini_str: &str) -> Result<Self, ParseError> {
+        Self::from_str(ini_str)
+    }
+
+    /// `[skinIni, warnings]`
+    #[wasm_bindgen(js_name = fromStrWith)]
+    pub fn from_str_with_wasm(ini_str: &str, mode: ParseMode) -> Result<js_sys::Array, ParseError> {
+        let (skin_ini, warnings) = Self::from_str_with(ini_str, mode)?;
+        Ok(utils::wasm::with_warnings(skin_ini, warnings))
     }
 
     #[wasm_bindgen(js_name = toString)]
@@ -0,0 +1,88 @@
+use std::sync::{Arc, RwLock};
+
+use crate::{
+    BinaryArcExt, Store, StringPattern,
+    error::ConvertError,
+    etterna::{Button, Keymode},
+    image_proc::proc::{rotate_90_deg_ccw, rotate_90_deg_cw},
+    numeric_enum, texture::Texture,
+    utils::skin::{SheetNaming, find_sheet, split_sheet},
+};
+
+numeric_enum! {
+    pub enum EtternaDimensions: u32 {
+        X = 854,
+        Y = 480,
+        ArrowSize = 64,
+        HitPosition = 96, // receptors sit 144px below the centre of the screen on downscroll
+    }
+}
+
+pub struct SpriteResolver<'a, S: Store<Texture>> {
+    textures: &'a mut S,
+}
+
+impl<'a, S: Store<Texture>> SpriteResolver<'a, S> {
+    pub fn new(textures: &'a mut S) -> Self {
+        Self { textures }
+    }
+
+    /// `name` or `_name`, stepmania loads both and noteskins often hide their sprites behind the underscore
+    fn sprite_name(&self, name: &str) -> String {
+        if find_sheet(&*self.textures, name, SheetNaming::ColumnsFirst).is_none() {
+            let hidden = format!("_{name}");
+            if find_sheet(&*self.textures, &hidden, SheetNaming::ColumnsFirst).is_some() {
+                return hidden;
+            }
+        }
+
+        name.to_string()
+    }
+
+    /// finds a sprite by its exact name or as a "{name} {cols}x{rows}" sheet, with or without a leading `_`
+    pub fn find_sheet(&self, name: &str) -> Option<(Arc<RwLock<Texture>>, u32, u32)> {
+        find_sheet(&*self.textures, &self.sprite_name(name), SheetNaming::ColumnsFirst)
+    }
+
+    pub fn get_frames(&self, name: &str) -> (Vec<Arc<RwLock<Texture>>>, u32, u32) {
+        split_sheet(&*self.textures, &self.sprite_name(name), SheetNaming::ColumnsFirst)
+    }
+
+    /// first frame of a sprite, for sheets where every frame is a snap color this is the 4th snap
+    pub fn get_sprite(&self, name: &str) -> Option<Arc<RwLock<Texture>>> {
+        self.get_frames(name).0.into_iter().next()
+    }
+
+    /// the sprite of `button` if the noteskin ships one, otherwise the `Down` sprite rotated the way `NoteSkin.lua` would.
+    /// rotated sprites are stored under the button's name so other keymodes reuse them
+    pub fn get_rotated(&mut self, pattern: &StringPattern, button: Button) -> Result<Option<Arc<RwLock<Texture>>>, ConvertError> {
+        let name = Keymode::get_button_asset(pattern, button).to_string();
+        if let Some(sprite) = self.get_sprite(&name) {
+            return Ok(Some(sprite));
+        }
+
+        let down_name = Keymode::get_button_asset(pattern, Button::Down).to_string();
+        let Some(data) = self.get_sprite(&down_name).and_then(|down| down.get_data()) else {
+            return Ok(None);
+        };
+
+        let rotated = self.textures.insert(Texture::with_data(name, data));
+        match button.quarter_turns_cw() {
+            1 => rotate_90_deg_cw(&rotated)?,
+            2 => {
+                rotate_90_deg_cw(&rotated)?;
+                rotate_90_deg_cw(&rotated)?;
+            }
+            3 => rotate_90_deg_ccw(&rotated)?,
+            _ => {}
+        }
+
+        Ok(Some(rotated))
+    }
+
+    /// the sprite of `button` or the `Down` one as is, for parts that are never rotated (hold bodies, caps, mines)
+    pub fn get_unrotated(&self, pattern: &StringPattern, button: Button) -> Option<Arc<RwLock<Texture>>> {
+        self.get_sprite(&Keymode::get_button_asset(pattern, button))
+            .or_else(|| self.get_sprite(&Keymode::get_button_asset(pattern, Button::Down)))
+    }
+}
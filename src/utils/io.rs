@@ -17,6 +17,20 @@ pub fn normalize(str: &str) -> String {
     path_to_unix(str).to_lowercase()
 }
 
+/// characters windows doesn't allow in file names, plus both path separators
+const RESERVED_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+/// `name` made safe to join onto an export folder, separators and reserved characters become `_`,
+/// leading and trailing dots are trimmed so `.` and `..` can't escape the folder and nothing ends up hidden.
+/// can be empty
+pub fn safe_file_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_control() || RESERVED_CHARS.contains(&c) { '_' } else { c })
+        .collect();
+    name.trim_matches(|c: char| c == '.' || c.is_whitespace()).to_string()
+}
+
 #[inline]
 pub fn get_extension(path: &str) -> String {
     Path::new(path)
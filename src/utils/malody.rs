@@ -0,0 +1,20 @@
+use std::sync::{Arc, RwLock};
+
+use crate::{
+    Store, numeric_enum, texture::Texture,
+    utils::skin::{SheetNaming, split_sheet},
+};
+
+numeric_enum! {
+    pub enum MalodyDimensions: u32 {
+        X = 1280,
+        Y = 720,
+        MaxResolution = 4096,
+    }
+}
+
+/// splits an effect into frames, effects are either a single "{name}" texture
+/// or a "{name}@{rows}x{cols}" sprite sheet
+pub fn get_frames<S: Store<Texture>>(textures: &S, name: &str) -> (Vec<Arc<RwLock<Texture>>>, u32, u32) {
+    split_sheet(textures, name, SheetNaming::RowsFirst)
+}
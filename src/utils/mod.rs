@@ -4,6 +4,8 @@ pub mod string;
 pub mod osu;
 pub mod quaver;
 pub mod fluxis;
+pub mod etterna;
+pub mod malody;
 pub mod math;
 pub mod skin;
 pub mod merge;
@@ -1,4 +1,6 @@
-use crate::numeric_enum;
+use std::sync::{Arc, RwLock};
+
+use crate::{BinaryArcExt, Store, numeric_enum, texture::{Texture, TextureStore}, utils::io::normalize};
 
 numeric_enum! {
     pub enum OsuDimensions: f32 {
@@ -8,6 +10,63 @@ numeric_enum! {
         ReceptorHeight = 108.0,
         ReceptorScale = 1.6,
         ReceptorScale2x = 3.2,
-        ColumnScaleFromGeneric = 0.7143
+        ColumnScaleFromGeneric = 0.7143,
+        MaxResolution = 4096.0
     }
-}
\ No newline at end of file
+}
+
+/// the `n` of `{base}-{n}`, how osu! numbers the frames of an animation
+pub fn frame_number(base: &str, path: &str) -> Option<u32> {
+    normalize(path).strip_prefix(&normalize(base))?.strip_prefix('-')?.parse().ok()
+}
+
+/// `{base}-0`, `{base}-1`, ... up to the first missing frame, osu! uses them over `base` when there are any
+pub fn get_animation(textures: &TextureStore, base: &str) -> Vec<Arc<RwLock<Texture>>> {
+    let mut frames: Vec<(u32, Arc<RwLock<Texture>>)> = textures
+        .get_shared_all(|t| frame_number(base, &t.path).is_some())
+        .into_iter()
+        .filter_map(|(path, tex)| Some((frame_number(base, &path)?, tex)))
+        .collect();
+    frames.sort_by_key(|(n, _)| *n);
+
+    let animation: Vec<_> = frames
+        .into_iter()
+        .enumerate()
+        .take_while(|(i, (n, _))| *i as u32 == *n)
+        .map(|(_, (_, tex))| tex)
+        .collect();
+
+    if animation.is_empty() {
+        textures.get_shared(base).into_iter().collect()
+    } else {
+        animation
+    }
+}
+
+/// stores every frame as `{base}-{n}` and returns the `base` to write into skin.ini,
+/// `base` is the first frame's path without its frame number or `@RxC` sheet size
+pub fn store_animation(textures: &mut TextureStore, frames: &[Arc<RwLock<Texture>>]) -> Option<String> {
+    let first = frames.first()?.get_path();
+    if frames.len() == 1 {
+        return Some(first);
+    }
+
+    let base = match first.rsplit_once('-') {
+        Some((base, n)) if n.parse::<u32>().is_ok() => base,
+        _ => first.as_str(),
+    };
+    let base = match base.rsplit_once('@') {
+        Some((base, size)) if size.split_once('x').is_some_and(|(r, c)| r.parse::<u32>().is_ok() && c.parse::<u32>().is_ok()) => base,
+        _ => base,
+    }
+    .to_string();
+
+    for (i, frame) in frames.iter().enumerate() {
+        let path = format!("{base}-{i}");
+        if frame.get_path() != path {
+            textures.copy_from_data(&path, frame.state_ref(|state| state.clone()));
+        }
+    }
+
+    Some(base)
+}
@@ -81,8 +81,9 @@ impl<'a, 'p, S: Store<Texture>> TextureResolver<'a, 'p, S> {
         let sheet_clone = sheet.clone();
 
         self.frame_processor.process_once(&sheet_tex, move |tex| {
-            if let Some((rows, cols)) = sheet_clone.get_sheet_size() {
-                let data = tex.get_data().unwrap();
+            if let Some((rows, cols)) = sheet_clone.get_sheet_size()
+                && let Some(data) = tex.get_data()
+            {
                 let raw_frames = if trimmed {
                     extract_from_sheet_trimmed(&data, rows, cols)
                 } else {
@@ -154,7 +155,7 @@ impl<'a, 'p, S: Store<Texture>> TextureResolver<'a, 'p, S> {
                     f: impl FnOnce(&[&RgbaImage]),
                 ) {
                     if let Some((first, rest)) = frames.split_first() {
-                        first.with_image(|img| {
+                        first.image_ref(|img| {
                             let img_unsafe_ref = unsafe { &*(img as *const RgbaImage) };
                             refs.push(img_unsafe_ref);
                             stack_locks(rest, refs, f);
@@ -166,11 +167,9 @@ impl<'a, 'p, S: Store<Texture>> TextureResolver<'a, 'p, S> {
 
                 let mut images_refs = Vec::with_capacity(frames.len());
                 stack_locks(&frames, &mut images_refs, |refs| {
-                    let base_tex = Texture::with_data(
-                        base_tex_name.to_string(),
-                        extract_grayscale_base(refs, Some(&colors), FilterType::Hamming),
-                    );
-                    base_arc = Some(textures.insert(base_tex));
+                    if let Some(base) = extract_grayscale_base(refs, Some(&colors), FilterType::Hamming) {
+                        base_arc = Some(textures.insert(Texture::with_data(base_tex_name.to_string(), base)));
+                    }
                 });
             }
 
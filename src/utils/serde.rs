@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
 pub mod serialize_rgb {
     use serde::Serializer;
     use crate::common::color::Rgba;
@@ -10,6 +14,20 @@ pub mod serialize_rgb {
     }
 }
 
+/// `value.parse()` that keeps the key and value around for the error
+#[inline]
+pub fn parse_number<T: FromStr>(key: &str, value: &str) -> Result<T, ParseError> {
+    value.parse().map_err(|_| ParseError::invalid_value(key, value, "number"))
+}
+
+/// like `parse_f32_list` and friends but rejects anything that is not a number, empty items still count as 0
+pub fn try_parse_list<T: FromStr + Default>(key: &str, value: &str) -> Result<Vec<T>, ParseError> {
+    value.split(',')
+        .map(str::trim)
+        .map(|s| if s.is_empty() { Ok(T::default()) } else { parse_number(key, s) })
+        .collect()
+}
+
 #[inline]
 pub fn parse_u16_list(value: &str) -> Vec<u16> {
     value.split(',')
@@ -45,6 +63,15 @@ pub fn parse_key_value(raw_str: &str) -> Option<(&str, &str)> {
     }
 }
 
+/// drops a trailing `// comment`, skin.ini values commonly carry the hex code of a colour after them
+#[inline]
+pub fn strip_inline_comment(value: &str) -> &str {
+    match value.find("//") {
+        Some(pos) => value[..pos].trim_end(),
+        None => value,
+    }
+}
+
 #[inline]
 pub fn parse_key_value_eq(raw_str: &str) -> Option<(&str, &str)> {
     if let Some(eq_pos) = raw_str.find('=') {
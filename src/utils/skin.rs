@@ -1,8 +1,13 @@
-use std::{collections::HashSet, sync::{Arc, RwLock}, marker::PhantomData};
+use std::{collections::HashSet, sync::{Arc, PoisonError, RwLock}, marker::PhantomData};
 
-use crate::{Binary, Store, StringPattern, sample::SampleStore, texture::TextureStore, traits::{LaneType, SkinConfig}};
+use image::RgbaImage;
 
-// TODO: add method for generating mipmaps for textures (for osu)
+use crate::{
+    Binary, BinaryArcExt, BinaryState, Store, StringPattern,
+    generic::elements::SkinElement,
+    image_proc::proc::{concat_into_sheet, extract_from_sheet},
+    sample::SampleStore, texture::{Texture, TextureStore}, traits::{LaneType, SkinConfig},
+};
 
 pub fn cleanup_stores<T: SkinConfig>(config: &T, textures: Option<&mut TextureStore>, samples: Option<&mut SampleStore>) {
     if let Some(samples) = samples {
@@ -60,7 +65,7 @@ impl<'a, T: Binary + 'static, S: Store<T>> StoreRelocator<'a, T, S> {
 
     pub fn reloc_arc_lock<U: Binary>(&mut self, item: &Option<Arc<RwLock<U>>>, target_path: StringPattern) {
         if let Some(texture_arc) = item {
-            let guard = texture_arc.read().unwrap();
+            let guard = texture_arc.read().unwrap_or_else(PoisonError::into_inner);
             let path = guard.get_path();
             self.store.copy(path, &target_path);
         }
@@ -78,3 +83,107 @@ impl<'a, T: Binary + 'static, S: Store<T>> StoreRelocator<'a, T, S> {
         }
     }
 }
+
+/// how a sprite sheet's file name says how many frames it has
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SheetNaming {
+    /// `{name}@{rows}x{cols}`, used by malody
+    RowsFirst,
+    /// `{name} {cols}x{rows}`, used by stepmania and etterna, hints like `(doubleres)` after it are skipped
+    ColumnsFirst,
+}
+
+impl SheetNaming {
+    fn separator(&self) -> char {
+        match self {
+            SheetNaming::RowsFirst => '@',
+            SheetNaming::ColumnsFirst => ' ',
+        }
+    }
+
+    /// `(rows, cols)` of a sheet path
+    pub fn parse(&self, path: &str) -> Option<(u32, u32)> {
+        match self {
+            SheetNaming::RowsFirst => StringPattern::from(path.to_string()).get_sheet_size(),
+            SheetNaming::ColumnsFirst => {
+                let token = path
+                    .split_whitespace()
+                    .rev()
+                    .find(|t| !t.starts_with('(') && !t.ends_with(')'))?;
+
+                let (cols, rows) = token.split_once('x')?;
+                Some((rows.parse().ok()?, cols.parse().ok()?))
+            }
+        }
+    }
+
+    pub fn sheet_name(&self, name: &str, rows: u32, cols: u32) -> String {
+        match self {
+            SheetNaming::RowsFirst => format!("{name}@{rows}x{cols}"),
+            SheetNaming::ColumnsFirst => format!("{name} {cols}x{rows}"),
+        }
+    }
+}
+
+/// finds a sprite by its exact name or as a sheet named after it, the first one by path if there are several
+pub fn find_sheet<S: Store<Texture>>(textures: &S, name: &str, naming: SheetNaming) -> Option<(Arc<RwLock<Texture>>, u32, u32)> {
+    if let Some(tex) = textures.get_shared(name) {
+        return Some((tex, 1, 1));
+    }
+
+    let prefix = format!("{}{}", name.to_lowercase(), naming.separator());
+    let mut sheets = textures.get_shared_all(|t| t.get_path().to_lowercase().starts_with(&prefix));
+    sheets.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let (path, tex) = sheets.into_iter().next()?;
+    let (rows, cols) = naming.parse(&path).unwrap_or((1, 1));
+    Some((tex, rows, cols))
+}
+
+/// splits the sprite or sheet `find_sheet` finds into frames named `{name}-{idx}`
+pub fn split_sheet<S: Store<Texture>>(textures: &S, name: &str, naming: SheetNaming) -> (Vec<Arc<RwLock<Texture>>>, u32, u32) {
+    let Some((tex, rows, cols)) = find_sheet(textures, name, naming) else {
+        return (Vec::new(), 1, 1);
+    };
+
+    if rows * cols <= 1 {
+        return (vec![tex], 1, 1);
+    }
+
+    let Some(data) = tex.get_data() else {
+        return (vec![tex], 1, 1);
+    };
+
+    let frames = extract_from_sheet(&data, rows, cols)
+        .into_iter()
+        .enumerate()
+        .map(|(idx, img)| {
+            Arc::new(RwLock::new(Texture::new_with_state(
+                format!("{}-{}", name, idx),
+                BinaryState::Loaded(img),
+            )))
+        })
+        .collect();
+
+    (frames, rows, cols)
+}
+
+/// the loaded frames of `element` as one texture, a single frame keeps `name` as is
+pub fn join_sheet<T: SkinElement>(element: &T, name: &str, naming: SheetNaming) -> Option<Texture> {
+    let sprite_locks = element.as_texture_frames();
+    let mut sprites: Vec<&RgbaImage> = sprite_locks
+        .iter()
+        .filter_map(|g| g.state().as_loaded())
+        .collect();
+
+    match sprites.len() {
+        0 => None,
+        1 => Some(Texture::with_data(name.to_string(), sprites.remove(0).clone())),
+        _ => {
+            let rows = element.get_rows().unwrap_or(1);
+            let cols = element.get_columns().unwrap_or(1);
+            concat_into_sheet(&sprites, rows, cols)
+                .map(|sheet| Texture::with_data(naming.sheet_name(name, rows, cols), sheet))
+        }
+    }
+}
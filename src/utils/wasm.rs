@@ -2,6 +2,13 @@ use js_sys::{Array, Map, Uint8Array};
 use std::collections::HashMap;
 use wasm_bindgen::{JsError, JsValue};
 
+#[cfg(target_arch = "wasm32")]
+use crate::error::{ImportError, ParseWarning};
+#[cfg(target_arch = "wasm32")]
+use crate::report::ConversionReport;
+#[cfg(target_arch = "wasm32")]
+use crate::diff::MergeConflict;
+
 pub fn js_to_hash(files: Map) -> HashMap<String, Vec<u8>> {
     let mut map = HashMap::new();
     files.for_each(&mut |value, key| {
@@ -25,3 +32,35 @@ pub fn arr_to_strs(arr: Array) -> Result<Vec<String>, JsError> {
         .map(|v| v.as_string().ok_or_else(|| JsError::new("Expected string in array")))
         .collect()
 }
+
+/// config parse errors reach JS as a `ParseError` object with an extra `path` so the location can be read
+/// field by field, everything else as a plain `Error`
+#[cfg(target_arch = "wasm32")]
+pub fn import_error_to_js(e: ImportError) -> JsValue {
+    match e {
+        ImportError::ParseConfig { path, source } => {
+            let error = JsValue::from(*source);
+            let _ = js_sys::Reflect::set(&error, &JsValue::from_str("path"), &JsValue::from_str(&path));
+            error
+        }
+        e => JsError::new(&e.to_string()).into(),
+    }
+}
+
+/// `[config, warnings]`, what the `fromStrWith` functions hand to JS
+#[cfg(target_arch = "wasm32")]
+pub fn with_warnings(config: impl Into<JsValue>, warnings: Vec<ParseWarning>) -> Array {
+    Array::of2(&config.into(), &warnings.into_iter().map(JsValue::from).collect::<Array>())
+}
+
+/// `[skin, report]`, what the `WithReport` conversions hand to JS
+#[cfg(target_arch = "wasm32")]
+pub fn with_report(skin: impl Into<JsValue>, report: ConversionReport) -> Array {
+    Array::of2(&skin.into(), &report.into())
+}
+
+/// `[merged, conflicts]`, what `threeWayMerge` hands to JS
+#[cfg(target_arch = "wasm32")]
+pub fn with_conflicts(merged: impl Into<JsValue>, conflicts: Vec<MergeConflict>) -> Array {
+    Array::of2(&merged.into(), &conflicts.into_iter().map(JsValue::from).collect::<Array>())
+}
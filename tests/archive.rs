@@ -0,0 +1,140 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+
+#[test]
+fn osu_archive_round_trip_test() -> Result<(), Box<dyn std::error::Error>> {
+    let raw_skin_ini = read_file_to_string("./tests/configs/osu/bubbleskin.ini")?;
+    let img_bytes = read_file_to_bytes("./tests/assets/blooc.png")?;
+
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::from_bytes("mania/blooc".to_string(), &img_bytes)?);
+    let skin = OsuSkin::new(OsuSkinIni::from_str(&raw_skin_ini)?, Some(textures), None);
+
+    benchmark_closure(||
+    {
+        let archive = export::osu::skin_to_archive(&skin)?;
+        let imported = import::osu::skin_from_archive(&archive, true)?;
+
+        assert_eq!(imported.skin_ini.general.name, skin.skin_ini.general.name);
+        assert_eq!(imported.skin_ini.keymodes.len(), skin.skin_ini.keymodes.len());
+        assert!(imported.textures.contains("mania/blooc"));
+
+        export::osu::skin_to_archive_file(&imported, SKIN_PATH)?;
+        Ok(())
+    }, "Osu! archive round trip", "to finish exporting and importing", "\x1b[0;32m")?;
+
+    Ok(())
+}
+
+#[test]
+fn incremental_export_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut skin_ini = OsuSkinIni::from_str("[General]\nName: incremental\n[Mania]\nKeys: 4\n")?;
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::from_bytes("mania/blooc".to_string(), &read_file_to_bytes("./tests/assets/blooc.png")?)?);
+    textures.insert(Texture::with_data("mania/key".to_string(), image::RgbaImage::new(2, 2)));
+    let skin = OsuSkin::new(skin_ini.clone(), Some(textures.clone()), None);
+
+    let out = format!("{SKIN_PATH}/incremental");
+    let _ = std::fs::remove_dir_all(&out);
+    std::fs::create_dir_all(format!("{out}/incremental"))?;
+    std::fs::write(format!("{out}/incremental/notes.txt"), b"not part of the skin")?;
+
+    let first = export::osu::skin_to_dir_incremental(&skin, &out, false)?;
+    assert_eq!(first.added, ["mania/blooc.png", "mania/key.png", "skin.ini"]);
+    assert!(first.modified.is_empty() && first.removed.is_empty());
+
+    let again = export::osu::skin_to_dir_incremental(&skin, &out, false)?;
+    assert!(!again.has_changes());
+    assert_eq!(again.unchanged.len(), 3);
+
+    textures.insert(Texture::with_data("mania/key".to_string(), image::RgbaImage::new(4, 4)));
+    textures.remove("mania/blooc");
+    skin_ini.general.author = "someone".to_string();
+    let changed = OsuSkin::new(skin_ini, Some(textures), None);
+
+    let summary = export::osu::skin_to_dir_incremental(&changed, &out, true)?;
+    assert_eq!(summary.modified, ["mania/key.png", "skin.ini"]);
+    // only what an earlier export wrote is stale, the user's own files stay
+    assert_eq!(summary.removed, ["mania/blooc.png"]);
+    assert!(!std::path::Path::new(&format!("{out}/incremental/mania/blooc.png")).exists());
+    assert!(std::path::Path::new(&format!("{out}/incremental/notes.txt")).exists());
+
+    // names that would write into `out` itself or above it are refused
+    for name in ["", "..", " . "] {
+        let mut unnamed = changed.clone();
+        unnamed.skin_ini.general.name = name.to_string();
+        assert!(export::osu::skin_to_dir_incremental(&unnamed, &out, true).is_err(), "{name:?} was exported");
+    }
+    assert!(std::path::Path::new(&format!("{out}/incremental/notes.txt")).exists());
+
+    // archives keep what they had unless stale files are removed
+    let archive = export::osu::skin_to_archive(&skin)?;
+    let update = export::osu::update_archive(&changed, &archive, false)?;
+    assert_eq!(update.summary.modified, ["mania/key.png", "skin.ini"]);
+    assert!(update.summary.removed.is_empty());
+    assert!(import::osu::skin_from_archive(&update.archive, true)?.textures.contains("mania/blooc"));
+
+    let update = export::osu::update_archive(&changed, &archive, true)?;
+    assert_eq!(update.summary.removed, ["mania/blooc.png"]);
+    assert!(!import::osu::skin_from_archive(&update.archive, true)?.textures.contains("mania/blooc"));
+
+    Ok(())
+}
+
+#[test]
+fn incremental_export_case_test() -> Result<(), Box<dyn std::error::Error>> {
+    let skin_ini = OsuSkinIni::from_str("[General]\nName: case\n")?;
+    let out = format!("{SKIN_PATH}/incremental_case");
+    let _ = std::fs::remove_dir_all(&out);
+
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::with_data("Mania/Key".to_string(), image::RgbaImage::new(2, 2)));
+    export::osu::skin_to_dir_incremental(&OsuSkin::new(skin_ini.clone(), Some(textures), None), &out, true)?;
+
+    // on case-insensitive filesystems `Mania/Key.png` is the file that was just written
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::with_data("mania/key".to_string(), image::RgbaImage::new(4, 4)));
+    let summary = export::osu::skin_to_dir_incremental(&OsuSkin::new(skin_ini, Some(textures), None), &out, true)?;
+    assert!(summary.removed.is_empty());
+    assert_eq!(image::image_dimensions(format!("{out}/case/mania/key.png"))?, (4, 4));
+
+    Ok(())
+}
+
+#[test]
+fn archive_file_name_test() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = format!("{SKIN_PATH}/archive_names");
+    let raw_skin_ini = read_file_to_string("./tests/configs/osu/bubbleskin.ini")?;
+    let mut skin = OsuSkin::new(OsuSkinIni::from_str(&raw_skin_ini)?, None, None);
+
+    for (name, expected) in [("../../escaped", "_.._escaped.osk"), ("a/b\\c:d", "a_b_c_d.osk"), ("..", "skin.osk"), ("", "skin.osk")] {
+        skin.skin_ini.general.name = name.to_string();
+        export::osu::skin_to_archive_file(&skin, &dir)?;
+        assert!(std::path::Path::new(&dir).join(expected).is_file(), "{name:?} wasn't written as {expected}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn archive_declared_size_test() -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::Write;
+
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    writer.start_file("skin.ini", options)?;
+    writer.write_all(b"[General]\nName: liar\n")?;
+    let mut archive = writer.finish()?.into_inner();
+
+    // both headers claim the entry unpacks to 4 GiB, only what's actually there is read
+    archive[22..26].copy_from_slice(&u32::MAX.to_le_bytes());
+    let central = archive.windows(4).position(|w| w == [0x50, 0x4b, 0x01, 0x02]).ok_or("no central directory")?;
+    archive[central + 24..central + 28].copy_from_slice(&u32::MAX.to_le_bytes());
+
+    let skin = import::osu::skin_from_archive(&archive, true)?;
+    assert_eq!(skin.skin_ini.general.name, "liar");
+    Ok(())
+}
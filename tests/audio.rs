@@ -0,0 +1,76 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::io::Cursor;
+use std::str::FromStr;
+use rgskin::audio_proc::{SampleProcessing, process_sample, proc::decode, read_sample_info};
+use rgskin::options::ConversionOptions;
+use rgskin::report::ConversionIssueKind;
+use rgskin::sample::{Sample, SampleFormat};
+
+/// stereo, half a second of silence and then half a second of a quiet tone
+fn quiet_wav() -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let spec = hound::WavSpec { channels: 2, sample_rate: 8000, bits_per_sample: 16, sample_format: hound::SampleFormat::Int };
+    let mut bytes = Vec::new();
+    let mut writer = hound::WavWriter::new(Cursor::new(&mut bytes), spec)?;
+    for i in 0..8000 {
+        let sample = if i < 4000 { 0 } else { ((i as f32 * 0.1).sin() * 8000.0) as i16 };
+        writer.write_sample(sample)?;
+        writer.write_sample(sample / 2)?;
+    }
+    writer.finalize()?;
+    Ok(bytes)
+}
+
+#[test]
+fn sample_info_and_processing_test() -> Result<(), Box<dyn std::error::Error>> {
+    let wav = quiet_wav()?;
+    let info = read_sample_info(&wav)?;
+    assert_eq!(info.format, SampleFormat::Wav);
+    assert_eq!((info.channels, info.sample_rate), (2, 8000));
+    assert!((info.duration - 1.0).abs() < 0.01);
+
+    let options = SampleProcessing { trim_silence: true, normalize: true, downmix: true, ..Default::default() };
+    let processed = process_sample(&wav, &options)?;
+    let info = read_sample_info(&processed)?;
+    assert_eq!(info.channels, 1);
+    assert!((info.duration - 0.5).abs() < 0.01);
+
+    let peak = decode(&processed)?.samples.iter().fold(0.0_f32, |max, s| max.max(s.abs()));
+    assert!(peak > 0.99);
+
+    assert!(read_sample_info(b"ID3\x04\0\0").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn hit_sound_conversion_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut samples = SampleStore::new();
+    samples.insert(Sample::with_data("drum-hitnormal".to_string(), quiet_wav()?));
+    let osu_skin = OsuSkin::new(OsuSkinIni::from_str("[Mania]\nKeys: 4\n")?, None, Some(samples));
+    let generic_skin = osu_skin.to_generic_mania(())?;
+
+    let options = ConversionOptions {
+        hit_sound: SampleProcessing { trim_silence: true, ..Default::default() },
+        ..Default::default()
+    };
+    let (converted, report) = OsuSkin::from_generic_mania_with(&generic_skin, &options)?;
+    assert!(report.of_kind(ConversionIssueKind::Unprocessed).is_empty());
+
+    let trimmed = converted.samples.get_info("drum-hitnormal").ok_or("missing hit sound")?;
+    assert!((trimmed.duration - 0.5).abs() < 0.01);
+
+    // the skin it was converted from still has the untrimmed sample
+    let original = osu_skin.samples.get_info("drum-hitnormal").ok_or("missing hit sound")?;
+    assert!((original.duration - 1.0).abs() < 0.01);
+
+    let mut samples = SampleStore::new();
+    samples.insert(Sample::with_data("drum-hitnormal".to_string(), b"ID3\x04\0\0".to_vec()));
+    let generic_skin = OsuSkin::new(OsuSkinIni::from_str("[Mania]\nKeys: 4\n")?, None, Some(samples)).to_generic_mania(())?;
+    let (_, report) = OsuSkin::from_generic_mania_with(&generic_skin, &options)?;
+    assert!(report.iter().any(|i| i.kind == ConversionIssueKind::Unprocessed && i.element == "samples/drum-hitnormal"));
+
+    Ok(())
+}
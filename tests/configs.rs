@@ -3,6 +3,7 @@
 mod test_dependencies;
 use test_dependencies::*;
 use std::str::FromStr;
+use rgskin::error::{ParseErrorKind, ParseMode};
 
 #[test]
 fn osu_skin_ini_test() -> Result<(), Box<dyn std::error::Error>> {
@@ -15,6 +16,181 @@ fn osu_skin_ini_test() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn osu_skin_ini_sections_round_trip_test() -> Result<(), Box<dyn std::error::Error>> {
+    let raw_skin_ini = read_file_to_string("./tests/configs/osu/bloomoon.ini").unwrap();
+    let skin_ini = OsuSkinIni::from_str(&raw_skin_ini)?;
+
+    let reparsed = OsuSkinIni::from_str(&skin_ini.to_string())?;
+
+    assert_eq!(skin_ini.colours.combos.iter().flatten().count(), 2);
+    assert_eq!(reparsed.colours.combos, skin_ini.colours.combos);
+    assert_eq!(reparsed.colours.slider_track_override.map(|c| c.to_str_rgb()), Some("3,3,15".to_string()));
+    assert_eq!(reparsed.fonts.hit_circle_prefix, "Fonts/Hitcircle/Aven/default");
+    assert_eq!(reparsed.fonts.hit_circle_overlap, 8);
+    assert_eq!(reparsed.fonts.score_overlap, -2);
+    assert_eq!(reparsed.catch_the_beat.hyper_dash.map(|c| c.to_str_rgb()), Some("98,97,175".to_string()));
+    assert_eq!(reparsed.catch_the_beat.hyper_dash_fruit.map(|c| c.to_str_rgb()), Some("254,135,63".to_string()));
+    assert_eq!(reparsed.catch_the_beat.hyper_dash_after_image.map(|c| c.to_str_rgb()), None);
+    assert_eq!(reparsed.keymodes.len(), skin_ini.keymodes.len());
+    Ok(())
+}
+
+#[test]
+fn osu_skin_ini_sparse_sections_test() -> Result<(), Box<dyn std::error::Error>> {
+    let skin_ini = OsuSkinIni::from_str("[General]\nName: sparse\n\n[Colours]\nCombo1: 255,0,0\nCombo5: 0,0,255\n")?;
+    let mut typed = skin_ini.clone();
+    typed.source = None;
+    let written = typed.to_string();
+
+    assert!(written.contains("Combo1: 255,0,0") && written.contains("Combo5: 0,0,255"));
+    assert!(!written.contains("Combo2") && !written.contains("Combo3") && !written.contains("Combo4"));
+    assert!(!written.contains("[Fonts]"));
+    assert_eq!(OsuSkinIni::from_str(&written)?.colours.combos, skin_ini.colours.combos);
+    Ok(())
+}
+
+#[test]
+fn osu_skin_ini_lossless_test() -> Result<(), Box<dyn std::error::Error>> {
+    for path in ["./tests/configs/osu/bubbleskin.ini", "./tests/configs/osu/bloomoon.ini", "./tests/configs/osu/fnf.ini"] {
+        let raw_skin_ini = read_file_to_string(path).unwrap();
+        let mut skin_ini = OsuSkinIni::from_str(&raw_skin_ini)?;
+        assert_eq!(skin_ini.to_string(), raw_skin_ini, "{path} did not round trip unchanged");
+
+        skin_ini.general.name = "Renamed".to_string();
+        skin_ini.keymodes[0].column_start += 1.0;
+        let edited = skin_ini.to_string();
+
+        let changed: Vec<(&str, &str)> = raw_skin_ini.lines().zip(edited.lines()).filter(|(a, b)| a != b).collect();
+        assert_eq!(raw_skin_ini.lines().count(), edited.lines().count(), "{path} gained or lost lines");
+        assert_eq!(changed.len(), 2, "{path} changed more than the edited lines: {changed:?}");
+        assert!(changed[0].1.trim_start().starts_with("Name: Renamed"));
+    }
+    Ok(())
+}
+
+#[test]
+fn osu_skin_ini_keymode_removal_test() -> Result<(), Box<dyn std::error::Error>> {
+    let raw_skin_ini = read_file_to_string("./tests/configs/osu/fnf.ini").unwrap();
+    let mut skin_ini = OsuSkinIni::from_str(&raw_skin_ini)?;
+
+    // the sections are matched by `Keys`, so reordering them changes nothing
+    skin_ini.keymodes.reverse();
+    assert_eq!(skin_ini.to_string(), raw_skin_ini);
+
+    skin_ini.keymodes.reverse();
+    let removed = skin_ini.keymodes.remove(0);
+    let edited = skin_ini.to_string();
+    let reparsed = OsuSkinIni::from_str(&edited)?;
+
+    assert!(reparsed.keymodes.iter().all(|k| k.keymode != removed.keymode));
+    assert_eq!(reparsed.keymodes.len(), skin_ini.keymodes.len());
+    for (kept, written) in skin_ini.keymodes.iter().zip(&reparsed.keymodes) {
+        assert_eq!(kept.to_str(), written.to_str());
+    }
+
+    // everything but the removed section is left as written
+    let removed_lines: Vec<&str> = raw_skin_ini.lines().filter(|line| !edited.lines().any(|l| l == *line)).collect();
+    assert!(edited.lines().all(|line| raw_skin_ini.lines().any(|l| l == line)));
+    assert!(removed_lines.iter().any(|line| line.trim() == format!("Keys: {}", removed.keymode)));
+    Ok(())
+}
+
+#[test]
+fn quaver_skin_ini_lossless_test() -> Result<(), Box<dyn std::error::Error>> {
+    let raw_skin_ini = read_file_to_string("./tests/configs/qua/fumocirc.ini").unwrap();
+    let mut skin_ini = QuaSkinIni::from_str(&raw_skin_ini)?;
+    assert_eq!(skin_ini.to_string(), raw_skin_ini);
+
+    skin_ini.general.name = "Renamed".to_string();
+    let edited = skin_ini.to_string();
+
+    let changed: Vec<(&str, &str)> = raw_skin_ini.lines().zip(edited.lines()).filter(|(a, b)| a != b).collect();
+    assert_eq!(raw_skin_ini.lines().count(), edited.lines().count());
+    assert_eq!(changed, vec![("Name = Fumo Circles2", "Name = Renamed")]);
+
+    skin_ini.source = None;
+    assert!(!skin_ini.to_string().contains(';'));
+    Ok(())
+}
+
+#[test]
+fn osu_skin_ini_parse_error_test() {
+    let raw_skin_ini = "[General]\nName: broken\n\n[Mania]\nKeys: 4\n\n[Mania]\n// comment\nKeys: 7\nColumnWidth: 30,abc,30\n";
+    let error = OsuSkinIni::from_str_with(raw_skin_ini, ParseMode::Strict).unwrap_err();
+
+    assert_eq!(error.line, Some(10));
+    assert_eq!(error.section.as_deref(), Some("Mania"));
+    assert_eq!(error.keymode, Some(7));
+    assert_eq!(error.key.as_deref(), Some("ColumnWidth"));
+    assert_eq!(error.value.as_deref(), Some("abc"));
+    assert_eq!(error.to_string(), "line 10, [Mania] Keys=7, ColumnWidth: expected number, got 'abc'");
+}
+
+#[test]
+fn osu_skin_ini_lenient_test() {
+    let raw_skin_ini = "[General]\nName: lenient\nSliderStyle: 2\nName: lenient again\n\n[Mania]\nKeys: 4\nColumnWidth: 30,30,30\nSpecialStyle: 5\nHitPosition: abc\n";
+
+    let (skin_ini, warnings) = OsuSkinIni::from_str_with(raw_skin_ini, ParseMode::Lenient).unwrap();
+    let kinds: Vec<ParseErrorKind> = warnings.iter().map(|w| w.kind).collect();
+
+    assert_eq!(kinds, [
+        ParseErrorKind::DuplicateKey,
+        ParseErrorKind::UnknownKey,
+        ParseErrorKind::OutOfRange,
+        ParseErrorKind::InvalidValue,
+        ParseErrorKind::WrongListLength,
+    ]);
+    assert_eq!(warnings[0].line, Some(4));
+    assert_eq!(warnings[1].key.as_deref(), Some("SliderStyle"));
+    assert_eq!(warnings[4].to_string(), "line 8, [Mania] Keys=4, ColumnWidth: expected 4 values for 4 keys, got '30,30,30'");
+
+    assert_eq!(skin_ini.general.name, "lenient again");
+    assert_eq!(skin_ini.keymodes[0].special_style, 0);
+    assert_eq!(skin_ini.keymodes[0].hit_position, osu::Keymode::default().hit_position);
+
+    let error = OsuSkinIni::from_str_with(raw_skin_ini, ParseMode::Strict).unwrap_err();
+    assert_eq!(error.kind, ParseErrorKind::DuplicateKey);
+    assert_eq!(error, warnings[0]);
+}
+
+#[test]
+fn quaver_skin_ini_lenient_test() {
+    let raw_skin_ini = "[General]\nName = lenient\n\n[4K]\nColumnSize = wide\nColumnColor0 = 255,255,255\n";
+
+    let (skin_ini, warnings) = QuaSkinIni::from_str_with(raw_skin_ini, ParseMode::Lenient).unwrap();
+
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].to_string(), "line 5, [4K], ColumnSize: expected number, got 'wide'");
+    assert_eq!(warnings[1].kind, ParseErrorKind::OutOfRange);
+    assert_eq!(skin_ini.keymodes[0].column_size, quaver::Keymode::default().column_size);
+
+    assert!(QuaSkinIni::from_str_with(raw_skin_ini, ParseMode::Strict).is_err());
+}
+
+#[test]
+fn skin_json_lenient_test() {
+    let raw_skin_json = r##"{ "info": { "name": "lenient", "colour": "#FFFFFF" }, "4k": { "column_width": 120 }, "12k": {}, "overrides": { "anything/goes": "here" } }"##;
+
+    let (skin_json, warnings) = SkinJson::from_str_with(raw_skin_json, ParseMode::Lenient).unwrap();
+
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].to_string(), "[info], colour: unknown key, got '#FFFFFF'");
+    assert_eq!(warnings[1].kind, ParseErrorKind::OutOfRange);
+    assert_eq!(skin_json.keymodes.iter().find(|k| k.keymode == 4).map(|k| k.column_width), Some(120));
+
+    assert!(SkinJson::from_str_with(raw_skin_json, ParseMode::Strict).is_err());
+}
+
+#[test]
+fn json_parse_error_test() {
+    let error = ConfJson::from_str("{\n  \"meta\": {\n    \"name\": 4\n  }\n}").unwrap_err();
+
+    assert_eq!(error.line, Some(3));
+    assert!(error.column.is_some());
+    assert!(!error.message.contains(" at line "));
+}
+
 #[test]
 fn quaver_skin_ini_test() -> Result<(), Box<dyn std::error::Error>> {
     benchmark_closure(||
@@ -24,4 +200,36 @@ fn quaver_skin_ini_test() -> Result<(), Box<dyn std::error::Error>> {
         Ok(())
     }, "Quaver skin ini", "to finish skin ini parsing", "\x1b[0;33m")?;
     Ok(())
-}
\ No newline at end of file
+}
+#[test]
+fn etterna_metrics_test() -> Result<(), Box<dyn std::error::Error>> {
+    benchmark_closure(||
+    {
+        let raw_metrics = read_file_to_string("./tests/configs/etterna/metrics.ini").unwrap();
+        let _metrics = EtternaMetrics::from_str(&raw_metrics)?;
+        Ok(())
+    }, "Etterna metrics", "to finish metrics parsing", "\x1b[0;33m")?;
+    Ok(())
+}
+
+#[test]
+fn etterna_metrics_unknown_sections_test() -> Result<(), Box<dyn std::error::Error>> {
+    let raw_metrics = read_file_to_string("./tests/configs/etterna/metrics.ini")?;
+    let metrics = EtternaMetrics::from_str(&raw_metrics)?;
+    assert!(metrics.other_sections.contains_key("ReceptorArrow"));
+
+    let reparsed = EtternaMetrics::from_str(&metrics.to_string())?;
+    assert_eq!(reparsed.other_sections, metrics.other_sections);
+    Ok(())
+}
+
+#[test]
+fn malody_conf_json_test() -> Result<(), Box<dyn std::error::Error>> {
+    benchmark_closure(||
+    {
+        let raw_conf = read_file_to_string("./tests/configs/malody/conf.json").unwrap();
+        let _conf = ConfJson::from_str(&raw_conf)?;
+        Ok(())
+    }, "Malody conf.json", "to finish conf parsing", "\x1b[0;33m")?;
+    Ok(())
+}
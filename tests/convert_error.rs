@@ -0,0 +1,49 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::sync::{Arc, RwLock};
+use fast_image_resize::FilterType;
+use image::RgbaImage;
+use std::str::FromStr;
+use rgskin::error::ConvertError;
+use rgskin::fluxis::FluXisLayout;
+use rgskin::image_proc::proc::{concat_into_sheet, extract_from_sheet, resize_width};
+
+#[test]
+fn fluxis_invalid_layout_test() {
+    let skin = FluXisSkin::new(SkinJson::default(), None, None);
+    let layout = FluXisLayout::new("empty".to_string(), "nobody".to_string());
+
+    let Err(err) = skin.to_generic_mania(Some(&layout)) else {
+        panic!("converted with an empty layout");
+    };
+    assert!(matches!(err, ConvertError::InvalidLayout { .. }));
+    assert_eq!(err.to_string(), "invalid layout: gameplay has no Combo component");
+}
+
+#[test]
+fn image_processing_error_test() {
+    let texture = Arc::new(RwLock::new(Texture::new_with_state("empty".to_string(), BinaryState::Empty)));
+    let err = resize_width(&texture, 32, FilterType::Hamming).unwrap_err();
+    assert!(matches!(err, ConvertError::ImageProcessing { ref path, .. } if path == "empty"));
+
+    let small = RgbaImage::new(4, 4);
+    let large = RgbaImage::new(8, 8);
+    assert!(concat_into_sheet(&[&small, &large], 1, 2).is_none());
+    assert!(concat_into_sheet(&[&RgbaImage::new(0, 4)], 1, 1).is_none());
+    assert!(extract_from_sheet(&large, 0, 2).is_empty());
+}
+
+#[test]
+fn osu_undecodable_receptor_test() -> Result<(), Box<dyn std::error::Error>> {
+    let skin_ini = OsuSkinIni::from_str("[Mania]\nKeys: 4\nKeyImage0: broken\n")?;
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::with_unloaded_data("broken".to_string(), vec![0, 1, 2, 3]));
+
+    let Err(err) = OsuSkin::new(skin_ini, Some(textures), None).to_generic_mania(()) else {
+        panic!("converted a receptor that can't be decoded");
+    };
+    assert!(matches!(err, ConvertError::ImageProcessing { ref path, .. } if path == "broken"));
+    Ok(())
+}
@@ -0,0 +1,99 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use image::{Rgba, RgbaImage};
+use rgskin::diff::ChangeKind;
+use rgskin::generic::elements::NormalNote;
+
+fn texture(path: &str, shade: u8) -> Texture {
+    Texture::with_data(path.to_string(), RgbaImage::from_pixel(4, 4, Rgba([shade, shade, shade, 255])))
+}
+
+#[test]
+fn config_diff_test() -> Result<(), Box<dyn std::error::Error>> {
+    let before = OsuSkinIni::from_str("[General]\nName: Old\n\n[Mania]\nKeys: 4\nColumnWidth: 30,30,30,30\n")?;
+    // a comment and different spacing aren't changes
+    let after = OsuSkinIni::from_str("// updated\n[General]\nName:New\n\n[Mania]\nKeys: 4\nColumnWidth: 30,40,30,30\n\n[Mania]\nKeys: 7\n")?;
+
+    let diff = before.diff(&after);
+    let name = diff.get("General.Name").ok_or("missing name change")?;
+    assert_eq!(name.kind, ChangeKind::Modified);
+    assert_eq!((name.before.as_deref(), name.after.as_deref()), (Some("Old"), Some("New")));
+    // 30 is the default width, so it isn't written out
+    let width = diff.get("4K.ColumnWidth").ok_or("missing width change")?;
+    assert_eq!(width.to_string(), "modified 4K.ColumnWidth: default -> '30,40,30,30'");
+    assert_eq!(diff.get("7K.Keys").map(|c| c.kind), Some(ChangeKind::Added));
+    assert!(before.diff(&before.clone()).is_empty());
+
+    let before = QuaSkinIni::from_str("[General]\nName = Old\n\n[4K]\nColumnSize = 100\n")?;
+    let after = QuaSkinIni::from_str("[General]\nName = Old\n\n[4K]\nColumnSize = 120\n")?;
+    let diff = before.diff(&after);
+    assert_eq!(diff.len(), 1);
+    assert_eq!(diff.to_string(), "modified 4K.ColumnSize: '100' -> '120'");
+
+    let before = SkinJson::from_str(r#"{ "info": { "name": "Old" } }"#)?;
+    let after = SkinJson::from_str(r#"{ "info": { "name": "New" } }"#)?;
+    assert_eq!(before.diff(&after).get("info.name").and_then(|c| c.after), Some("New".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn store_diff_test() {
+    let mut before = TextureStore::new();
+    before.insert(texture("note", 10));
+    before.insert(texture("key", 10));
+    before.insert(texture("stage", 10));
+
+    let mut after = TextureStore::new();
+    after.insert(texture("note", 10));
+    after.insert(texture("key", 20));
+    after.insert(texture("judgement", 10));
+
+    let diff = before.diff(&after);
+    let elements: Vec<(ChangeKind, &str)> = diff.iter().map(|c| (c.kind, c.element.as_str())).collect();
+    assert_eq!(elements, [
+        (ChangeKind::Modified, "textures/key"),
+        (ChangeKind::Removed, "textures/stage"),
+        (ChangeKind::Added, "textures/judgement"),
+    ]);
+
+    let json = diff.to_json();
+    assert!(json.starts_with(r#"[{"kind":"modified","element":"textures/key","before":""#));
+
+    let mut samples = SampleStore::new();
+    samples.insert(Sample::from_bytes_direct("hit".to_string(), vec![1, 2, 3]));
+    let mut changed = SampleStore::new();
+    changed.insert(Sample::from_bytes_direct("hit".to_string(), vec![1, 2, 4]));
+    assert_eq!(samples.diff(&changed).get("samples/hit").map(|c| c.kind), Some(ChangeKind::Modified));
+}
+
+#[test]
+fn generic_diff_test() -> Result<(), Box<dyn std::error::Error>> {
+    let skin_ini = OsuSkinIni::from_str("[Mania]\nKeys: 4\nNoteImage0: note\n\n[Mania]\nKeys: 7\n")?;
+    let mut textures = TextureStore::new();
+    textures.insert(texture("note", 10));
+
+    let before = OsuSkin::new(skin_ini, Some(textures), None).to_generic_mania(())?;
+    let mut after = before.clone();
+    after.keymodes.retain(|k| k.keymode != 7);
+
+    let keymode = after.keymodes.iter_mut().find(|k| k.keymode == 4).ok_or("missing 4K")?;
+    keymode.normal_notes[0] = NormalNote::new(Some(Arc::new(RwLock::new(texture("note", 20)))));
+    keymode.layout.column_widths[1] *= 2.0;
+
+    let diff = before.diff(&after);
+    let note = diff.get("4K.normal_notes[0]").ok_or("missing note change")?;
+    assert_eq!(note.kind, ChangeKind::Modified);
+    // same path, so the content hash tells them apart
+    assert!(note.before.as_deref().is_some_and(|b| b.starts_with("note (")));
+    assert_eq!(diff.get("4K.column_widths[1]").map(|c| c.kind), Some(ChangeKind::Modified));
+    assert_eq!(diff.get("7K").map(|c| c.kind), Some(ChangeKind::Removed));
+    assert!(!diff.iter().any(|c| c.element.starts_with("7K.")));
+    assert_eq!(diff.len(), 3);
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::io::{Cursor, Read};
+use std::str::FromStr;
+use image::{Rgba, RgbaImage};
+use rgskin::sample::{Sample, SampleFormat};
+use rgskin::texture::TextureFormat;
+
+#[test]
+fn texture_format_decode_test() -> Result<(), Box<dyn std::error::Error>> {
+    let img = RgbaImage::from_pixel(4, 3, Rgba([200, 40, 40, 255]));
+
+    for format in [TextureFormat::Png, TextureFormat::Jpeg, TextureFormat::WebP, TextureFormat::Bmp, TextureFormat::Gif, TextureFormat::Tga] {
+        let bytes = format.encode(&img)?;
+        assert_eq!(TextureFormat::detect(&bytes), Some(format));
+
+        let texture = Texture::from_bytes("texture".to_string(), &bytes)?;
+        assert_eq!(texture.format, Some(format));
+        assert_eq!(texture.get_data().map(|img| img.dimensions()), Some((4, 3)));
+    }
+
+    assert_eq!(TextureFormat::from_extension("JPEG"), Some(TextureFormat::Jpeg));
+    assert_eq!(TextureFormat::detect(b"not an image"), None);
+
+    Ok(())
+}
+
+#[test]
+fn texture_format_export_test() -> Result<(), Box<dyn std::error::Error>> {
+    let jpeg = TextureFormat::Jpeg.encode(&RgbaImage::from_pixel(16, 9, Rgba([10, 20, 30, 255])))?;
+
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::with_unloaded_data("bg".to_string(), jpeg.clone()));
+    textures.insert(Texture::with_data("note".to_string(), RgbaImage::from_pixel(4, 4, Rgba([255, 0, 0, 255]))));
+    textures.encode_format = TextureFormat::WebP;
+    let skin = OsuSkin::new(OsuSkinIni::from_str("[General]\nName: formats\n")?, Some(textures), None);
+
+    let archive = export::osu::skin_to_archive(&skin)?;
+    let mut zip = zip::ZipArchive::new(Cursor::new(&archive))?;
+
+    let mut bg = Vec::new();
+    zip.by_name("bg.jpg")?.read_to_end(&mut bg)?;
+    assert_eq!(bg, jpeg);
+
+    let mut note = Vec::new();
+    zip.by_name("note.webp")?.read_to_end(&mut note)?;
+    assert_eq!(TextureFormat::detect(&note), Some(TextureFormat::WebP));
+
+    let imported = import::osu::skin_from_archive(&archive, true)?;
+    let bg = imported.textures.get_shared("bg").ok_or("missing bg")?;
+    assert_eq!(bg.read().map_err(|e| e.to_string())?.format, Some(TextureFormat::Jpeg));
+
+    Ok(())
+}
+
+#[test]
+fn texture_passthrough_test() -> Result<(), Box<dyn std::error::Error>> {
+    let jpeg = TextureFormat::Jpeg.encode(&RgbaImage::from_pixel(16, 9, Rgba([10, 20, 30, 255])))?;
+
+    let mut textures = TextureStore::new();
+    let looked_at = textures.insert(Texture::from_bytes("looked_at".to_string(), &jpeg)?);
+    let edited = textures.insert(Texture::from_bytes("edited".to_string(), &jpeg)?);
+    textures.encode_format = TextureFormat::WebP;
+
+    assert_eq!(looked_at.image_ref(|img| img.dimensions()), Some((16, 9)));
+    edited.image_mut(|img| img.put_pixel(0, 0, Rgba([255, 255, 255, 255])));
+    assert!(edited.read().map_err(|e| e.to_string())?.original_bytes().is_none());
+
+    let skin = OsuSkin::new(OsuSkinIni::from_str("[General]\nName: passthrough\n")?, Some(textures), None);
+    let mut zip = zip::ZipArchive::new(Cursor::new(export::osu::skin_to_archive(&skin)?))?;
+
+    let mut bytes = Vec::new();
+    zip.by_name("looked_at.jpg")?.read_to_end(&mut bytes)?;
+    assert_eq!(bytes, jpeg);
+
+    let mut bytes = Vec::new();
+    zip.by_name("edited.webp")?.read_to_end(&mut bytes)?;
+    assert_eq!(TextureFormat::detect(&bytes), Some(TextureFormat::WebP));
+
+    Ok(())
+}
+
+#[test]
+fn sample_format_export_test() -> Result<(), Box<dyn std::error::Error>> {
+    let ogg = b"OggS\0\x02rest of the stream".to_vec();
+    let wav = b"RIFF\x24\0\0\0WAVEfmt ".to_vec();
+    let mp3 = b"ID3\x04\0\0\0\0\0\0".to_vec();
+
+    assert_eq!(SampleFormat::detect(&ogg), Some(SampleFormat::Ogg));
+    assert_eq!(SampleFormat::detect(&wav), Some(SampleFormat::Wav));
+    assert_eq!(SampleFormat::detect(&mp3), Some(SampleFormat::Mp3));
+    assert_eq!(SampleFormat::detect(&[0xFF, 0xFB, 0x90]), Some(SampleFormat::Mp3));
+    assert_eq!(SampleFormat::detect(b"fLaC\0"), Some(SampleFormat::Flac));
+
+    let mut samples = SampleStore::new();
+    samples.insert(Sample::with_data("hit".to_string(), ogg.clone()));
+    samples.insert(Sample::with_data("miss".to_string(), mp3.clone()));
+    samples.insert(Sample::with_data("unknown".to_string(), b"????".to_vec()));
+    assert_eq!(samples.get_format("hit"), Some(SampleFormat::Ogg));
+    assert_eq!(samples.get_format("unknown"), None);
+    assert_eq!(samples.paths_with_format(SampleFormat::Mp3), vec!["miss".to_string()]);
+
+    let skin = OsuSkin::new(OsuSkinIni::from_str("[General]\nName: samples\n")?, None, Some(samples));
+    let archive = export::osu::skin_to_archive(&skin)?;
+    let mut zip = zip::ZipArchive::new(Cursor::new(&archive))?;
+
+    let mut hit = Vec::new();
+    zip.by_name("hit.ogg")?.read_to_end(&mut hit)?;
+    assert_eq!(hit, ogg);
+    assert!(zip.by_name("miss.mp3").is_ok());
+    assert!(zip.by_name("unknown.wav").is_ok());
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+use image::RgbaImage;
+use rgskin::lint::{LintConfig, LintRule, Severity};
+
+fn texture(path: &str, width: u32, height: u32) -> Texture {
+    Texture::with_data(path.to_string(), RgbaImage::new(width, height))
+}
+
+#[test]
+fn osu_lint_test() {
+    let raw_skin_ini = "[Mania]\nKeys: 4\nColumnWidth: 30,30,30\nKeyImage0: mania-key1\nKeyImage1: missing-key\n";
+    let skin_ini = OsuSkinIni::from_str(raw_skin_ini).unwrap();
+
+    let mut textures = TextureStore::new();
+    textures.insert(texture("mania-key1", 32, 32));
+    textures.insert(texture("mania-key1@2x", 64, 64));
+    textures.insert(texture("mania-stage-left", 5000, 16));
+
+    let skin = OsuSkin::new(skin_ini, Some(textures), None);
+    let diagnostics = skin.lint();
+    let rules: Vec<LintRule> = diagnostics.iter().map(|d| d.rule).collect();
+
+    assert_eq!(rules, [
+        LintRule::ColumnCount,
+        LintRule::MissingTexture,
+        LintRule::OversizedTexture,
+        LintRule::MissingHd,
+    ]);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(diagnostics[0].element, "4K.column_width");
+    assert_eq!(diagnostics[1].element, "4K.receptor_images[1]");
+    assert_eq!(diagnostics[2].to_string(), "warning [oversized-texture] textures/mania-stage-left: 5000x16 is larger than 4096px (downscale to at most 4096px on the longest side)");
+    assert_eq!(diagnostics[3].element, "textures/mania-stage-left");
+
+    let config = LintConfig::osu()
+        .without_rule(LintRule::MissingHd)
+        .with_rule(LintRule::MissingTexture, Severity::Error);
+    let diagnostics = skin.lint_with(&config);
+
+    assert_eq!(diagnostics.len(), 3);
+    assert_eq!(diagnostics[1].rule, LintRule::MissingTexture);
+    assert_eq!(diagnostics[1].severity, Severity::Error);
+}
+
+#[test]
+fn quaver_lint_test() {
+    let mut textures = TextureStore::new();
+    textures.insert(texture("4k/notes/note-hitobject-1@2x4", 64, 31));
+    textures.insert(texture("4k/notes/note-hitobject-2@2x4", 64, 32));
+
+    let skin = QuaSkin::new(QuaSkinIni::default(), Some(textures), None);
+    let diagnostics = skin.lint();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].rule, LintRule::SheetSize);
+    assert_eq!(diagnostics[0].element, "textures/4k/notes/note-hitobject-1@2x4");
+    assert_eq!(diagnostics[0].suggestion, "resize to 64x32");
+}
+
+#[test]
+fn fluxis_lint_test() {
+    let mut textures = TextureStore::new();
+    textures.insert(texture("gameplay/background", 4097, 4097));
+
+    let skin = FluXisSkin::new(SkinJson::default(), Some(textures), None);
+    let diagnostics = skin.lint();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].rule, LintRule::OversizedTexture);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+}
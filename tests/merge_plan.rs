@@ -0,0 +1,66 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+use image::{Rgba, RgbaImage};
+use rgskin::error::MergePlanError;
+use rgskin::generic::ElementGroup;
+
+const SKIN_INI: &str = "[Mania]\nKeys: 4\nNoteImage0: note\nKeyImage0: key\n\n[Mania]\nKeys: 7\nNoteImage0: note\n";
+
+fn osu_skin(shade: u8) -> Result<OsuSkin, Box<dyn std::error::Error>> {
+    let mut textures = TextureStore::new();
+    for path in ["note", "key"] {
+        textures.insert(Texture::with_data(path.to_string(), RgbaImage::from_pixel(4, 4, Rgba([shade, shade, shade, 255]))));
+    }
+    Ok(OsuSkin::new(OsuSkinIni::from_str(SKIN_INI)?, Some(textures), None))
+}
+
+fn shade(texture: Option<&std::sync::Arc<std::sync::RwLock<Texture>>>) -> Option<(String, u8)> {
+    let texture = texture?;
+    let path = texture.get_path();
+    texture.image_ref(|img| (path, img.get_pixel(0, 0).0[0]))
+}
+
+#[test]
+fn merge_plan_test() -> Result<(), Box<dyn std::error::Error>> {
+    let a = osu_skin(10)?.to_generic_mania(())?;
+    let b = osu_skin(20)?.to_generic_mania(())?;
+
+    let plan = MergePlan::new(0)
+        .with_keymode(7, 1)
+        .with_keymode_element(4, ElementGroup::Receptors, 1);
+    let merged = GenericManiaSkin::merge_with_plan(&[&a, &b], &plan)?;
+
+    let keymode = |k: u8| merged.keymodes.iter().find(|km| km.keymode == k).ok_or(format!("missing {k}K"));
+    assert_eq!(shade(keymode(4)?.normal_notes[0].texture.as_ref()), Some(("note".to_string(), 10)));
+    // `note` is already taken by a's note, so b's gets a new path
+    assert_eq!(shade(keymode(7)?.normal_notes[0].texture.as_ref()), Some(("note_1".to_string(), 20)));
+    assert_eq!(shade(keymode(4)?.receptor_up[0].texture.as_ref()), Some(("key_1".to_string(), 20)));
+    assert!(merged.textures.contains("note_1"));
+    assert_eq!(a.textures.len(), b.textures.len());
+
+    // the same content keeps its path
+    let a_again = osu_skin(10)?.to_generic_mania(())?;
+    let merged = GenericManiaSkin::merge_with_plan(&[&a, &a_again], &MergePlan::new(0).with_element(ElementGroup::Notes, 1))?;
+    assert_eq!(merged.textures.len(), a.textures.len());
+
+    let osu = OsuSkin::merge_with_plan(&[&osu_skin(10)?, &osu_skin(20)?], &MergePlan::new(1).with_keymode(4, 0))?;
+    assert_eq!(osu.skin_ini.keymodes.len(), 2);
+
+    assert!(matches!(
+        GenericManiaSkin::merge_with_plan(&[&a], &MergePlan::new(0).with_keymode(4, 2)),
+        Err(MergePlanError::MissingSource { index: 2, sources: 1 })
+    ));
+    assert!(matches!(
+        GenericManiaSkin::merge_with_plan(&[&a], &MergePlan::new(0).with_keymode_element(4, ElementGroup::Judgements, 0)),
+        Err(MergePlanError::NotPerKeymode { group: ElementGroup::Judgements })
+    ));
+    assert!(matches!(
+        GenericManiaSkin::merge_with_plan(&[&a, &b], &MergePlan::new(0).with_keymode(5, 1)),
+        Err(MergePlanError::MissingKeymode { source: 1, keymode: 5 })
+    ));
+
+    Ok(())
+}
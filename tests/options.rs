@@ -0,0 +1,69 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use image::{Rgba, RgbaImage};
+use rgskin::generic::elements::NormalNotesSnapColored;
+use rgskin::options::ConversionOptions;
+use rgskin::report::ConversionIssueKind;
+
+fn osu_skin() -> Result<OsuSkin, Box<dyn std::error::Error>> {
+    let skin_ini = OsuSkinIni::from_str("[Mania]\nKeys: 4\nNoteImage0T: tail\n")?;
+
+    let mut tail = RgbaImage::new(4, 2);
+    for x in 0..4 {
+        tail.put_pixel(x, 0, Rgba([255, 0, 0, 255]));
+    }
+
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::with_data("tail".to_string(), tail));
+    textures.insert(Texture::with_data("big".to_string(), RgbaImage::new(200, 100)));
+    Ok(OsuSkin::new(skin_ini, Some(textures), None))
+}
+
+#[test]
+fn to_generic_options_test() -> Result<(), Box<dyn std::error::Error>> {
+    let generic_skin = osu_skin()?.to_generic_mania(())?;
+    let tail = generic_skin.textures.get_shared("tail").ok_or("missing tail")?;
+    assert_eq!(tail.image_ref(|img| img.get_pixel(0, 1).0), Some([255, 0, 0, 255]));
+
+    let options = ConversionOptions { flip_ln_tails: false, max_texture_size: Some(50), ..Default::default() };
+    let (generic_skin, report) = osu_skin()?.to_generic_mania_with((), &options)?;
+    let tail = generic_skin.textures.get_shared("tail").ok_or("missing tail")?;
+    assert_eq!(tail.image_ref(|img| img.get_pixel(0, 0).0), Some([255, 0, 0, 255]));
+
+    let big = generic_skin.textures.get_shared("big").ok_or("missing big")?;
+    assert_eq!(big.image_ref(|img| img.dimensions()), Some((50, 25)));
+    let resized = report.of_kind(ConversionIssueKind::Resized);
+    assert!(resized.iter().any(|i| i.element == "textures/big" && i.detail == "200x100 to 50x25"));
+
+    Ok(())
+}
+
+#[test]
+fn from_generic_options_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut generic_skin = osu_skin()?.to_generic_mania(())?;
+    let keymode = generic_skin.keymodes.iter_mut().find(|k| k.keymode == 4).ok_or("missing 4K")?;
+    keymode.layout.x_offset = 0.25;
+    keymode.use_snap_color = true;
+    let snap = Texture::with_data("snap".to_string(), RgbaImage::from_pixel(8, 8, Rgba([0, 0, 255, 255])));
+    keymode.normal_notes_snap_colored = Some(NormalNotesSnapColored::new(vec![Arc::new(RwLock::new(snap))], None, Some(1), Some(1), Vec::new()));
+
+    let default_skin = OsuSkin::from_generic_mania(&generic_skin)?;
+    let default_keymode = default_skin.get_keymode(4).ok_or("missing 4K")?;
+    assert!(default_keymode.normal_note_images.iter().all(|p| p != "baked_snap_n_4k"));
+
+    let options = ConversionOptions { aspect_ratio: 4.0 / 3.0, bake_snap_colors: true, ..Default::default() };
+    let (osu_skin, report) = OsuSkin::from_generic_mania_with(&generic_skin, &options)?;
+    let osu_keymode = osu_skin.get_keymode(4).ok_or("missing 4K")?;
+
+    assert!(osu_keymode.column_start < default_keymode.column_start);
+    assert!(report.iter().any(|i| i.element == "4K.x_offset" && i.detail == "column start is calculated for a 1.33:1 screen"));
+    assert!(osu_keymode.normal_note_images.iter().all(|p| p == "baked_snap_n_4k"));
+    assert!(osu_keymode.long_note_head_images.iter().all(|p| p == "baked_snap_n_4k"));
+    assert!(osu_skin.textures.contains("baked_snap_n_4k"));
+
+    Ok(())
+}
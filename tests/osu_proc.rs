@@ -2,7 +2,7 @@
 
 mod test_dependencies;
 use test_dependencies::*;
-use rgskin::{image_proc::{generate_stage_background, to_osu_column_draw}, utils::io::join_paths_unix, Binary, Store};
+use rgskin::{image_proc::{generate_stage_background, to_osu_column_draw, ResizeFilter}, utils::io::join_paths_unix, Binary, Store};
 
 #[test]
 fn osu_mania_receptor_draw_test() -> Result<(), Box<dyn std::error::Error>> {
@@ -16,7 +16,7 @@ fn osu_mania_receptor_draw_test() -> Result<(), Box<dyn std::error::Error>> {
 
     benchmark_closure(||
     {
-        to_osu_column_draw(&texture_ref, 40)?;
+        to_osu_column_draw(&texture_ref, 40, ResizeFilter::Hamming.into())?;
         Ok(())
     }, "Osu! mania receptor draw", "to finish processing", "\x1b[0;34m")?;
 
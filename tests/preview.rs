@@ -0,0 +1,85 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use image::{Rgba, RgbaImage};
+use rgskin::error::ConvertError;
+
+const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+const GREEN: Rgba<u8> = Rgba([0, 255, 0, 255]);
+const BLUE: Rgba<u8> = Rgba([0, 0, 255, 255]);
+const YELLOW: Rgba<u8> = Rgba([255, 255, 0, 255]);
+const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+fn generic_skin() -> Result<GenericManiaSkin, Box<dyn std::error::Error>> {
+    let mut ini = String::from("[Mania]\nKeys: 7\n");
+    for i in 0..7 {
+        ini.push_str(&format!("KeyImage{i}: key\nKeyImage{i}D: keyd\nNoteImage{i}: note\nNoteImage{i}H: note\nNoteImage{i}L: body\nNoteImage{i}T: note\n"));
+    }
+    let skin_ini = OsuSkinIni::from_str(&ini)?;
+
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::with_data("key".to_string(), RgbaImage::from_pixel(30, 30, BLUE)));
+    textures.insert(Texture::with_data("note".to_string(), RgbaImage::from_pixel(30, 10, RED)));
+    textures.insert(Texture::with_data("body".to_string(), RgbaImage::from_pixel(30, 4, GREEN)));
+    textures.insert(Texture::with_data("keyd".to_string(), RgbaImage::from_pixel(30, 30, YELLOW)));
+
+    Ok(OsuSkin::new(skin_ini, Some(textures), None).to_generic_mania(())?)
+}
+
+#[test]
+fn generic_preview_test() -> Result<(), Box<dyn std::error::Error>> {
+    let skin = generic_skin()?;
+    let img = skin.generate_preview(7, 640, 480)?;
+    assert_eq!(img.dimensions(), (640, 480));
+
+    let count = |color: Rgba<u8>| img.pixels().filter(|p| **p == color).count();
+    assert!(count(RED) > 0);
+    assert!(count(GREEN) > 0);
+    assert!(count(BLUE) > 0);
+
+    // the stage is centred, so both corners stay black
+    assert_eq!(*img.get_pixel(0, 0), Rgba([0, 0, 0, 255]));
+    assert_eq!(*img.get_pixel(639, 0), Rgba([0, 0, 0, 255]));
+
+    Ok(())
+}
+
+#[test]
+fn generic_preview_missing_keymode_test() -> Result<(), Box<dyn std::error::Error>> {
+    let skin = generic_skin()?;
+    assert!(matches!(skin.generate_preview(9, 640, 480), Err(ConvertError::MissingKeymode { keymode: 9 })));
+
+    Ok(())
+}
+
+#[test]
+fn generic_animated_preview_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut skin = generic_skin()?;
+    let keymode = skin.keymodes.iter_mut().find(|k| k.keymode == 7).ok_or("missing 7K")?;
+    let light = Texture::with_data("light".to_string(), RgbaImage::from_pixel(30, 30, WHITE));
+    keymode.hit_lighting_normal.frames = vec![Arc::new(RwLock::new(light))];
+
+    let apng = skin.generate_animated_preview(7, 160, 120)?;
+    assert_eq!(apng, skin.generate_animated_preview(7, 160, 120)?);
+
+    let mut reader = png::Decoder::new(std::io::Cursor::new(apng)).read_info()?;
+    assert_eq!(reader.info().animation_control().map(|a| a.num_frames), Some(60));
+
+    let mut frames = Vec::new();
+    let mut buf = vec![0; reader.output_buffer_size().ok_or("frame too large")?];
+    while frames.len() < 60 {
+        reader.next_frame(&mut buf)?;
+        frames.push(RgbaImage::from_raw(160, 120, buf.clone()).ok_or("bad frame")?);
+    }
+
+    let has = |color: Rgba<u8>| frames.iter().any(|f| f.pixels().any(|p| *p == color));
+    assert!(has(YELLOW));
+    assert!(has(WHITE));
+    assert!(has(RED));
+    assert!(frames[0] != frames[10]);
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use image::RgbaImage;
+use rgskin::generic::elements::NormalMine;
+use rgskin::report::ConversionIssueKind;
+
+#[test]
+fn osu_report_test() -> Result<(), Box<dyn std::error::Error>> {
+    let raw_skin_ini = "[Mania]\nKeys: 4\nColumnWidth: 30,30,30,30\nColumnLineWidth: 2,2,2,2,2\nNoteImage0: missing-note\n";
+    let skin_ini = OsuSkinIni::from_str(raw_skin_ini)?;
+
+    let osu_skin = OsuSkin::new(skin_ini, None, None);
+    let (mut generic_skin, report) = osu_skin.to_generic_mania_with_report(())?;
+
+    let blanks = report.of_kind(ConversionIssueKind::BlankSubstituted);
+    let note = blanks.iter().find(|i| i.element == "4K.normal_notes[0]").ok_or("missing blank note")?;
+    assert_eq!(note.detail, "'missing-note' not found");
+    assert!(report.iter().any(|i| i.kind == ConversionIssueKind::Dropped && i.element == "4K.column_line_width"));
+
+    let mine = Texture::with_data("mine".to_string(), RgbaImage::new(8, 8));
+    let keymode = generic_skin.keymodes.iter_mut().find(|k| k.keymode == 4).ok_or("missing 4K")?;
+    keymode.normal_mines = vec![NormalMine::new(Some(Arc::new(RwLock::new(mine))))];
+    keymode.layout.column_widths[0] *= 2.0;
+
+    let (_, report) = OsuSkin::from_generic_mania_with_report(&generic_skin)?;
+    assert!(report.iter().any(|i| i.kind == ConversionIssueKind::Dropped && i.element == "4K.normal_mines"));
+    assert!(!report.iter().any(|i| i.element == "4K.column_widths"));
+
+    let (_, report) = QuaSkin::from_generic_mania_with_report(&generic_skin)?;
+    let widths = report.iter().find(|i| i.element == "4K.column_widths").ok_or("missing column widths")?;
+    assert_eq!(widths.kind, ConversionIssueKind::Approximated);
+    assert!(report.to_string().contains("dropped 4K.normal_mines: not converted to quaver"));
+
+    Ok(())
+}
+
+#[test]
+fn generic_report_test() -> Result<(), Box<dyn std::error::Error>> {
+    let osu_skin = OsuSkin::new(OsuSkinIni::default(), None, None);
+    let generic_skin = osu_skin.to_generic_mania(())?;
+
+    let (_, report) = GenericManiaSkin::from_generic_mania_with_report(&generic_skin)?;
+    assert!(report.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn osu_report_unplaced_hud_test() -> Result<(), Box<dyn std::error::Error>> {
+    // etterna noteskins centre the stage and place no hud, there is nothing to lose going to osu
+    let noteskin = EtternaNoteskin::new(EtternaMetrics::default(), None, None);
+    let generic_skin = noteskin.to_generic_mania(())?;
+
+    let (_, report) = OsuSkin::from_generic_mania_with_report(&generic_skin)?;
+    assert!(report.is_empty(), "{report}");
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::sync::Arc;
+use image::{Rgba, RgbaImage};
+use rgskin::texture::TextureFormat;
+
+fn encoded(width: u32, height: u32, format: TextureFormat) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    Ok(format.encode(&RgbaImage::from_pixel(width, height, Rgba([10, 20, 30, 255])))?)
+}
+
+#[test]
+fn lazy_decode_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut textures = TextureStore::new();
+    let texture = textures.insert(Texture::with_unloaded_data("note".to_string(), encoded(4, 2, TextureFormat::Png)?));
+    assert!(texture.is_unloaded());
+
+    let handles: Vec<_> = (0..4).map(|_| {
+        let texture = Arc::clone(&texture);
+        std::thread::spawn(move || texture.image_ref(|img| img.dimensions()))
+    }).collect();
+    for handle in handles {
+        assert_eq!(handle.join().map_err(|_| "decode thread panicked")?, Some((4, 2)));
+    }
+    assert!(texture.is_loaded());
+    assert_eq!(texture.with_image(|img| img.get_pixel(0, 0).0), [10, 20, 30, 255]);
+
+    // bytes that aren't an image stay as they are
+    let broken = textures.insert(Texture::with_unloaded_data("broken".to_string(), vec![1, 2, 3]));
+    assert_eq!(broken.image_ref(|img| img.dimensions()), None);
+    assert!(broken.is_unloaded());
+
+    Ok(())
+}
+
+#[test]
+fn memory_budget_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut textures = TextureStore::new();
+    let jpeg = encoded(8, 8, TextureFormat::Jpeg)?;
+    for path in ["a", "b", "c"] {
+        textures.insert(Texture::with_unloaded_data(path.to_string(), jpeg.clone()));
+    }
+    // loaded textures keep the jpeg they were decoded from next to their pixels
+    let loaded = 8 * 8 * 4 + jpeg.len();
+    textures.memory_budget = Some(2 * loaded);
+
+    for path in ["a", "b", "a"] {
+        assert_eq!(textures.with_image(path, |img| img.dimensions()), Some((8, 8)));
+    }
+    assert_eq!(textures.loaded_bytes(), 2 * loaded);
+    assert_eq!(textures.evictions(), 0);
+
+    // b was used longest ago
+    textures.with_image("c", |_| ()).ok_or("missing c")?;
+    assert_eq!(textures.evictions(), 1);
+    assert_eq!(textures.loaded_bytes(), 2 * loaded);
+    let b = textures.get("b").ok_or("missing b")?;
+    assert_eq!(b.get_unloaded_data(), Some(jpeg.clone()));
+    assert!(textures.get("a").ok_or("missing a")?.is_loaded());
+
+    // unchanged textures go back to their original bytes, changed ones are encoded losslessly
+    textures.get("a").ok_or("missing a")?.image_mut(|img| img.put_pixel(0, 0, Rgba([255, 255, 255, 255])));
+    textures.memory_budget = Some(0);
+    assert_eq!(textures.trim_to_budget(), 2);
+    assert_eq!(textures.evictions(), 3);
+    assert_eq!(textures.loaded_bytes(), 0);
+
+    let a = textures.get("a").ok_or("missing a")?;
+    assert_eq!(a.read().map_err(|e| e.to_string())?.format, Some(TextureFormat::Png));
+    assert_eq!(a.image_ref(|img| img.get_pixel(0, 0).0), Some([255, 255, 255, 255]));
+    assert_eq!(textures.get("c").ok_or("missing c")?.get_unloaded_data(), Some(jpeg));
+
+    Ok(())
+}
+
+#[test]
+fn content_hash_eviction_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut textures = TextureStore::new();
+    let a = textures.insert(Texture::with_unloaded_data("a".to_string(), encoded(8, 8, TextureFormat::Jpeg)?));
+    let unloaded = a.read().map_err(|e| e.to_string())?.content_hash();
+    assert!(unloaded.is_some());
+    assert_eq!(textures.with_image("a", |img| img.dimensions()), Some((8, 8)));
+    assert_eq!(a.read().map_err(|e| e.to_string())?.content_hash(), unloaded);
+
+    // a changed texture is evicted as a png, the hash follows the pixels and not the bytes
+    a.image_mut(|img| img.put_pixel(0, 0, Rgba([255, 255, 255, 255])));
+    let changed = a.read().map_err(|e| e.to_string())?.content_hash();
+    let copy = a.clone_image().ok_or("missing a")?;
+    textures.memory_budget = Some(0);
+    assert_eq!(textures.trim_to_budget(), 1);
+    assert_eq!(a.read().map_err(|e| e.to_string())?.content_hash(), changed);
+
+    let mut loaded = TextureStore::new();
+    loaded.insert(Texture::with_data("a".to_string(), copy));
+    assert!(textures.diff(&loaded).is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+use image::{Rgba, RgbaImage};
+
+fn texture(path: &str, shade: u8) -> Texture {
+    Texture::with_data(path.to_string(), RgbaImage::from_pixel(4, 4, Rgba([shade, shade, shade, 255])))
+}
+
+fn store(textures: &[(&str, u8)]) -> TextureStore {
+    let mut store = TextureStore::new();
+    for (path, shade) in textures {
+        store.insert(texture(path, *shade));
+    }
+    store
+}
+
+#[test]
+fn config_three_way_merge_test() -> Result<(), Box<dyn std::error::Error>> {
+    let base = OsuSkinIni::from_str("[General]\nName: Base\n\n[Mania]\nKeys: 4\nHitPosition: 400\n")?;
+    let ours = OsuSkinIni::from_str("// ours\n[General]\nName: Ours\n\n[Mania]\nKeys: 4\nHitPosition: 420\n")?;
+    let theirs = OsuSkinIni::from_str("[General]\nName: Base\n\n[Mania]\nKeys: 4\nHitPosition: 440\nColumnWidth: 40,40,40,40\n\n[Mania]\nKeys: 7\n")?;
+
+    let (merged, conflicts) = ours.three_way_merge(&base, &theirs)?;
+    assert_eq!(merged.general.name, "Ours");
+    assert_eq!(merged.keymodes.iter().map(|k| k.keymode).collect::<Vec<_>>(), [4, 7]);
+    assert_eq!(merged.keymodes[0].column_width, [40.0; 4]);
+    assert_eq!(merged.keymodes[0].hit_position, 420);
+    assert!(merged.to_string().starts_with("// ours\n[General]\nName: Ours\n"));
+
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].to_string(), "conflict 4K.HitPosition: base '400', ours '420', theirs '440'");
+
+    let (merged, _) = ours.three_way_merge_with(&base, &theirs, &["4K.HitPosition".to_string()])?;
+    assert_eq!(merged.keymodes[0].hit_position, 440);
+
+    let base = SkinJson::from_str(r#"{ "info": { "name": "Base", "creator": "Base" } }"#)?;
+    let ours = SkinJson::from_str(r#"{ "info": { "name": "Ours", "creator": "Base" } }"#)?;
+    let theirs = SkinJson::from_str(r#"{ "info": { "name": "Base", "creator": "Theirs" } }"#)?;
+    let (merged, conflicts) = ours.three_way_merge(&base, &theirs)?;
+    assert!(conflicts.is_empty());
+    assert_eq!((merged.info.name.as_str(), merged.info.creator.as_str()), ("Ours", "Theirs"));
+
+    Ok(())
+}
+
+#[test]
+fn store_three_way_merge_test() -> Result<(), Box<dyn std::error::Error>> {
+    let base = store(&[("both", 1), ("theirs", 1), ("removed", 1), ("same", 1)]);
+    let ours = store(&[("both", 2), ("theirs", 1), ("same", 3)]);
+    let theirs = store(&[("both", 3), ("theirs", 2), ("removed", 1), ("same", 3), ("added", 1)]);
+
+    let (merged, conflicts) = ours.three_way_merge(&base, &theirs)?;
+    let shade = |path: &str| merged.with_image(path, |img| img.get_pixel(0, 0).0[0]);
+
+    let mut paths = merged.get_paths();
+    paths.sort();
+    assert_eq!(paths, ["added", "both", "same", "theirs"]);
+    assert_eq!((shade("both"), shade("theirs"), shade("same")), (Some(2), Some(2), Some(3)));
+
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].element, "textures/both");
+
+    let (merged, _) = ours.three_way_merge_with(&base, &theirs, &["textures/both".to_string()])?;
+    assert_eq!(merged.with_image("both", |img| img.get_pixel(0, 0).0[0]), Some(3));
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+use rgskin::generic::elements::SkinElement;
+
+fn build_noteskin() -> Result<EtternaNoteskin, Box<dyn std::error::Error>> {
+    let raw_metrics = read_file_to_string("./tests/configs/etterna/metrics.ini")?;
+
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::from_bytes("Down Tap Note".to_string(), &read_file_to_bytes("./tests/assets/k_down.png")?)?);
+    textures.insert(Texture::from_bytes("Down Receptor".to_string(), &read_file_to_bytes("./tests/assets/mrkc.png")?)?);
+
+    let mut noteskin = EtternaNoteskin::new(EtternaMetrics::from_str(&raw_metrics)?, Some(textures), None);
+    noteskin.name = "EtternaTest".to_string();
+    Ok(noteskin)
+}
+
+#[test]
+pub fn etterna_to_osu_test() -> Result<(), Box<dyn std::error::Error>> {
+    let noteskin = build_noteskin()?;
+
+    benchmark_closure(||
+    {
+        let generic_skin = noteskin.to_generic_mania(())?;
+        let keymode = generic_skin.get_keymode(4).ok_or("missing 4K")?;
+
+        // left arrows are the down sprite turned a quarter, so width and height swap
+        let left = keymode.normal_notes[0].texture.clone_data().ok_or("missing left note")?;
+        let down = keymode.normal_notes[1].texture.clone_data().ok_or("missing down note")?;
+        assert_eq!(left.dimensions(), (down.height(), down.width()));
+
+        let osu_from_generic = OsuSkin::from_generic_mania(&generic_skin)?;
+        export::osu::skin_to_dir(&osu_from_generic, SKIN_PATH)?;
+        Ok(())
+    }, "Etterna to Osu! mania", "to finish converting", "\x1b[0;32m")?;
+
+    Ok(())
+}
+
+#[test]
+pub fn etterna_round_trip_test() -> Result<(), Box<dyn std::error::Error>> {
+    let noteskin = build_noteskin()?;
+
+    benchmark_closure(||
+    {
+        let generic_skin = noteskin.to_generic_mania(())?;
+        let etterna_from_generic = EtternaNoteskin::from_generic_mania(&generic_skin)?;
+
+        assert_eq!(etterna_from_generic.name, noteskin.name);
+        assert!(etterna_from_generic.textures.contains("Down Tap Note"));
+        assert!(!etterna_from_generic.textures.contains("Left Tap Note"));
+
+        export::etterna::skin_to_dir(&etterna_from_generic, SKIN_PATH)?;
+        Ok(())
+    }, "Etterna round trip", "to finish converting", "\x1b[0;32m")?;
+
+    Ok(())
+}
+
+#[test]
+pub fn etterna_hidden_sprites_test() -> Result<(), Box<dyn std::error::Error>> {
+    let raw_metrics = read_file_to_string("./tests/configs/etterna/metrics.ini")?;
+
+    // stepmania loads `_Down Tap Note` the same as `Down Tap Note`
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::from_bytes("_Down Tap Note".to_string(), &read_file_to_bytes("./tests/assets/k_down.png")?)?);
+    textures.insert(Texture::from_bytes("_Down Receptor".to_string(), &read_file_to_bytes("./tests/assets/mrkc.png")?)?);
+    let noteskin = EtternaNoteskin::new(EtternaMetrics::from_str(&raw_metrics)?, Some(textures), None);
+
+    let generic_skin = noteskin.to_generic_mania(())?;
+    let keymode = generic_skin.get_keymode(4).ok_or("missing 4K")?;
+    assert_eq!(keymode.normal_notes[1].get_path().as_deref(), Some("_Down Tap Note"));
+    assert_eq!(keymode.receptor_up[1].get_path().as_deref(), Some("_Down Receptor"));
+
+    Ok(())
+}
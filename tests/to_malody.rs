@@ -0,0 +1,53 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+mod test_dependencies;
+use test_dependencies::*;
+use std::str::FromStr;
+
+#[test]
+pub fn osu_to_malody_test() -> Result<(), Box<dyn std::error::Error>> {
+    benchmark_closure(||
+    {
+        let osu_skin = import::osu::skin_from_dir("./tests/skins/Baller", false)?;
+        let generic_skin = osu_skin.to_generic_mania(())?;
+        let malody_from_generic = MalodySkin::from_generic_mania(&generic_skin)?;
+        export::malody::skin_to_dir(&malody_from_generic, SKIN_PATH)?;
+        Ok(())
+    }, "Osu! mania to Malody", "to finish converting", "\x1b[0;32m")?;
+
+    Ok(())
+}
+
+#[test]
+pub fn malody_to_osu_test() -> Result<(), Box<dyn std::error::Error>> {
+    let raw_conf = read_file_to_string("./tests/configs/malody/conf.json")?;
+    let conf_json = ConfJson::from_str(&raw_conf)?;
+
+    let mut textures = TextureStore::new();
+    textures.insert(Texture::from_bytes("key/shared/primary/note".to_string(), &read_file_to_bytes("./tests/assets/k_down.png")?)?);
+    textures.insert(Texture::from_bytes("key/4k/note-blue".to_string(), &read_file_to_bytes("./tests/assets/mrkc.png")?)?);
+
+    let malody_skin = MalodySkin::new(conf_json, Some(textures), None);
+
+    benchmark_closure(||
+    {
+        let generic_skin = malody_skin.to_generic_mania(())?;
+        let keymode = generic_skin.get_keymode(4).ok_or("missing 4K")?;
+
+        // outer lanes have no texture of their own and use the shared primary one,
+        // inner lanes are overridden in conf.json
+        assert_eq!(keymode.normal_notes[0].texture.get_path().as_deref(), Some("key/shared/primary/note"));
+        assert_eq!(keymode.normal_notes[1].texture.get_path().as_deref(), Some("key/4k/note-blue"));
+
+        let osu_from_generic = OsuSkin::from_generic_mania(&generic_skin)?;
+        export::osu::skin_to_dir(&osu_from_generic, SKIN_PATH)?;
+
+        // converting back gives every lane its own texture
+        let malody_from_generic = MalodySkin::from_generic_mania(&generic_skin)?;
+        assert!(malody_from_generic.textures.contains("key/4k/note-1"));
+        assert!(malody_from_generic.textures.contains("key/4k/note-2"));
+        Ok(())
+    }, "Malody to Osu! mania", "to finish converting", "\x1b[0;32m")?;
+
+    Ok(())
+}
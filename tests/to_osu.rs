@@ -30,3 +30,86 @@ pub fn quaver_to_osu_test() -> Result<(), Box<dyn std::error::Error>> {
     
     Ok(())
 }
+
+#[test]
+pub fn osu_animated_lighting_test() -> Result<(), Box<dyn std::error::Error>> {
+    use std::str::FromStr;
+    use std::sync::{Arc, RwLock};
+    use image::RgbaImage;
+
+    let skin_ini = OsuSkinIni::from_str("[Mania]\nKeys: 4\nLightFramePerSecond: 30\n")?;
+    let mut textures = TextureStore::new();
+    for i in [2, 0, 1, 4] {
+        textures.insert(Texture::with_data(format!("lightingN-{i}"), RgbaImage::new(8, 8)));
+    }
+
+    let osu_skin = OsuSkin::new(skin_ini, Some(textures), None);
+    let mut generic_skin = osu_skin.to_generic_mania(())?;
+    let keymode = generic_skin.keymodes.iter_mut().find(|k| k.keymode == 4).ok_or("missing 4K")?;
+
+    let frames: Vec<String> = keymode.hit_lighting_normal.frames.iter().map(|f| f.get_path()).collect();
+    assert_eq!(frames, ["lightingN-0", "lightingN-1", "lightingN-2"]);
+    assert_eq!(keymode.hit_lighting_normal.fps, Some(30.0));
+
+    keymode.hit_lighting_hold.frames = (0..2)
+        .map(|i| Arc::new(RwLock::new(Texture::with_data(format!("hold@1x2-{i}"), RgbaImage::new(8, 8)))))
+        .collect();
+    keymode.hit_lighting_hold.fps = Some(30.0);
+
+    let osu_from_generic = OsuSkin::from_generic_mania(&generic_skin)?;
+    let osu_keymode = osu_from_generic.skin_ini.keymodes.iter().find(|k| k.keymode == 4).ok_or("missing 4K")?;
+    assert_eq!(osu_keymode.lighting_n, "lightingN");
+    assert_eq!(osu_keymode.lighting_l, "hold");
+    assert_eq!(osu_keymode.light_frame_per_second, 30);
+    assert!(osu_from_generic.textures.contains("lightingN-2"));
+    assert!(osu_from_generic.textures.contains("hold-0") && osu_from_generic.textures.contains("hold-1"));
+
+    Ok(())
+}
+
+#[test]
+pub fn osu_mipmap_export_test() -> Result<(), Box<dyn std::error::Error>> {
+    use std::path::Path;
+    use image::RgbaImage;
+    use rgskin::image_proc::ResizeFilter;
+    use rgskin::osu::{MipmapMode, OsuExportOptions};
+
+    let mut skin_ini = OsuSkinIni::default();
+    skin_ini.general.name = "mipmaps".to_string();
+    let mut textures = TextureStore::new();
+    // SD, gets a doubled @2x
+    textures.insert(Texture::with_data("mania-key1".to_string(), RgbaImage::new(32, 16)));
+    // HD, gets a halved SD in its own format
+    let mut jpg = Vec::new();
+    image::RgbImage::new(40, 40).write_to(&mut std::io::Cursor::new(&mut jpg), image::ImageFormat::Jpeg)?;
+    textures.insert(Texture::from_bytes("mania-note1@2x".to_string(), &jpg)?);
+    // both, the SD one is written as is instead of being generated
+    textures.insert(Texture::with_data("mania-key2".to_string(), RgbaImage::new(10, 10)));
+    textures.insert(Texture::with_data("mania-key2@2x".to_string(), RgbaImage::new(64, 64)));
+    let osu_skin = OsuSkin::new(skin_ini, Some(textures), None);
+
+    let options = OsuExportOptions::new(MipmapMode::HdAndSd, ResizeFilter::Lanczos3);
+    export::osu::skin_to_dir_with(&osu_skin, SKIN_PATH, &options)?;
+    let skin_path = Path::new(SKIN_PATH).join("mipmaps");
+    assert_eq!(image::image_dimensions(skin_path.join("mania-key1@2x.png"))?, (64, 32));
+    assert_eq!(image::image_dimensions(skin_path.join("mania-key1.png"))?, (32, 16));
+    assert_eq!(image::image_dimensions(skin_path.join("mania-note1@2x.jpg"))?, (40, 40));
+    assert_eq!(image::image_dimensions(skin_path.join("mania-note1.jpg"))?, (20, 20));
+    assert!(!skin_path.join("mania-note1.png").exists());
+    assert_eq!(image::image_dimensions(skin_path.join("mania-key2@2x.png"))?, (64, 64));
+    assert_eq!(image::image_dimensions(skin_path.join("mania-key2.png"))?, (10, 10));
+
+    let archive = export::osu::skin_to_archive_with(&osu_skin, &options.with_mipmaps(MipmapMode::HdOnly))?;
+    let hd_only = import::osu::skin_from_archive(&archive, true)?;
+    assert!(hd_only.textures.contains("mania-key1"));
+    assert!(hd_only.textures.get_mipmap("mania-key1", 0).is_none());
+
+    // an @2x import stays HD, exporting it again doesn't double it
+    let reexported = export::osu::skin_to_archive_with(&hd_only, &options)?;
+    let reimported = import::osu::skin_from_archive(&reexported, true)?;
+    let key1 = reimported.textures.get_shared("mania-key1").ok_or("missing mania-key1")?;
+    assert_eq!(key1.clone_image().map(|img| img.dimensions()), Some((64, 32)));
+    assert_eq!(reimported.textures.get_mipmap("mania-key1", 0).map(|img| img.dimensions()), Some((32, 16)));
+
+    Ok(())
+}